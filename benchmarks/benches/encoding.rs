@@ -0,0 +1,67 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use halo2_proofs::dev::MockProver;
+use halo2_proofs::halo2curves::bn256;
+use halo2_proofs::halo2curves::bn256::Fr;
+use num::{BigInt, Num};
+
+use korrekt::circuit_analyzer::analyzer::Analyzer;
+use korrekt::io::analyzer_io_type;
+use korrekt::sample_circuits;
+
+/// Measures `analyze_underconstrained` on a `BITS`-bit decomposition, with variables declared lazily and grouped
+/// by region ahead of the constraints. Criterion compares each run with the previous one, so a regression of
+/// either encoding is reported by `cargo bench`.
+fn bench_encodings<const BITS: usize>(c: &mut Criterion) {
+    let k = 11;
+    let circuit =
+        sample_circuits::bit_decomposition::general_bit_decomp::BitDecompositonUnderConstrained::<
+            Fr,
+            BITS,
+        >::default();
+    let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+
+    let modulus = bn256::fr::MODULUS_STR;
+    let without_prefix = modulus.trim_start_matches("0x");
+    let prime = BigInt::from_str_radix(without_prefix, 16)
+        .unwrap()
+        .to_string();
+
+    let mut group = c.benchmark_group("analyze_underconstrained");
+    group.sample_size(10);
+    for grouped in [false, true] {
+        let declarations = if grouped { "grouped" } else { "lazy" };
+        group.bench_with_input(
+            BenchmarkId::new(declarations, BITS),
+            &grouped,
+            |b, grouped| {
+                b.iter(|| {
+                    let mut analyzer = Analyzer::from(&circuit).with_quiet(true);
+                    if *grouped {
+                        analyzer = analyzer.with_grouped_declarations();
+                    }
+                    let instance_cols =
+                        analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+                    let analyzer_input = analyzer_io_type::AnalyzerInput {
+                        verification_method: analyzer_io_type::VerificationMethod::Random,
+                        verification_input: analyzer_io_type::VerificationInput {
+                            instances_string: instance_cols,
+                            iterations: 1,
+                        },
+                    };
+                    analyzer
+                        .analyze_underconstrained(analyzer_input, prover.fixed.clone(), &prime)
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_encodings::<2>,
+    bench_encodings::<8>,
+    bench_encodings::<32>
+);
+criterion_main!(benches);
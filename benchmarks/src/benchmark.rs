@@ -2,6 +2,7 @@ use halo2_proofs::dev::MockProver;
 use halo2_proofs::halo2curves::bn256;
 use halo2_proofs::halo2curves::bn256::Fr;
 use num::{BigInt, Num};
+use std::fs;
 use std::time::Instant;
 
 use korrekt;
@@ -45,7 +46,10 @@ pub fn run_benchmark() {
 ///
 /// This function performs an underconstrained benchmark test for a specified size indicated by the `BITS` generic constant.
 /// It creates a `BitDecompositonUnderConstrained` circuit using the provided size and runs various operations on it.
-/// The benchmark includes creating a mock prover, analyzing the circuit, and measuring the elapsed time.
+/// The benchmark includes creating a mock prover, analyzing the circuit, and measuring the elapsed time together with
+/// the size of the emitted SMT file, so that changes to the encoding show up in both dimensions. The analysis runs
+/// twice, with variables declared lazily and grouped by region ahead of the constraints, to compare solve times, and
+/// panics if the two encodings reach different verdicts. `cargo bench` measures the same analyses with criterion.
 ///
 /// # Generic Parameters
 ///
@@ -66,15 +70,6 @@ pub fn run_underconstrained_benchmark_for_specified_size<const BITS: usize>() {
         >::default();
     let public_input = Fr::from(3);
     let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![vec![public_input]]).unwrap();
-    let mut analyzer = analyzer::Analyzer::from(&circuit);
-    let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
-    let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
-        verification_method: analyzer_io_type::VerificationMethod::Random,
-        verification_input: analyzer_io_type::VerificationInput {
-            instances_string: instance_cols,
-            iterations: 1,
-        },
-    };
 
     let modulus = bn256::fr::MODULUS_STR;
     let without_prefix = modulus.trim_start_matches("0x");
@@ -82,12 +77,47 @@ pub fn run_underconstrained_benchmark_for_specified_size<const BITS: usize>() {
         .unwrap()
         .to_string();
 
-    let start = Instant::now();
-    let _result = analyzer.analyze_underconstrained(analyzer_input, prover.fixed, &prime);
-    let duration = start.elapsed();
+    let mut verdicts = vec![];
+    for grouped in [false, true] {
+        let mut analyzer = analyzer::Analyzer::from(&circuit).with_quiet(true);
+        if grouped {
+            analyzer = analyzer.with_grouped_declarations();
+        }
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: analyzer_io_type::VerificationMethod::Random,
+            verification_input: analyzer_io_type::VerificationInput {
+                instances_string: instance_cols,
+                iterations: 1,
+            },
+        };
+
+        let start = Instant::now();
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed.clone(), &prime)
+            .unwrap()
+            .output_status;
+        let duration = start.elapsed();
+        let smt_size = fs::metadata("src/output/out.smt2")
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
 
-    println!(
-        "{} bits: Time elapsed for analyze_underconstrained() is: {:?}",
-        BITS, duration
+        println!(
+            "{} bits ({} declarations): Time elapsed for analyze_underconstrained() is: {:?}, SMT file size: {} bytes, verdict: {:?}",
+            BITS,
+            if grouped { "grouped" } else { "lazy" },
+            duration,
+            smt_size,
+            output_status
+        );
+        verdicts.push(output_status);
+    }
+    // Both encodings describe the same constraints, a different verdict is a bug in one of them.
+    assert!(
+        verdicts[0].eq(&verdicts[1]),
+        "{} bits: lazy and grouped declarations disagree ({:?} and {:?})",
+        BITS,
+        verdicts[0],
+        verdicts[1]
     );
 }
@@ -1,10 +1,10 @@
 use halo2_proofs::{
     arithmetic::FieldExt as Field,
-    plonk::{Advice, Any, Column, Expression, Selector},
+    plonk::{Advice, Any, Column, Expression, Instance, Selector},
     poly::Rotation,
 };
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 // abstract interpretation of expressions
 
@@ -47,6 +47,379 @@ pub fn extract_columns<F: Field>(expr: &Expression<F>) -> HashSet<(Column<Any>,
     recursion(&mut set, expr);
     set
 }
+/// Extracts instance columns and rotations from an expression.
+///
+/// This is the counterpart of `extract_columns` for public inputs: it collects every `Expression::Instance`
+/// column and its rotation used within the expression.
+pub fn extract_instance_columns<F: Field>(
+    expr: &Expression<F>,
+) -> HashSet<(Column<Any>, Rotation)> {
+    fn recursion<F: Field>(dst: &mut HashSet<(Column<Any>, Rotation)>, expr: &Expression<F>) {
+        match expr {
+            Expression::Instance(instance_query) => {
+                let column = Column {
+                    index: instance_query.column_index,
+                    column_type: Instance {},
+                };
+                dst.insert((column.into(), instance_query.rotation));
+            }
+            Expression::Sum(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Product(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Negated(expr) => recursion(dst, expr),
+            Expression::Scaled(expr, _) => recursion(dst, expr),
+            _ => (),
+        }
+    }
+    let mut set = HashSet::new();
+    recursion(&mut set, expr);
+    set
+}
+/// Extracts the simple selectors used within an expression.
+pub fn extract_selectors<F: Field>(expr: &Expression<F>) -> HashSet<Selector> {
+    fn recursion<F: Field>(dst: &mut HashSet<Selector>, expr: &Expression<F>) {
+        match expr {
+            Expression::Selector(selector) => {
+                dst.insert(*selector);
+            }
+            Expression::Sum(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Product(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Negated(expr) => recursion(dst, expr),
+            Expression::Scaled(expr, _) => recursion(dst, expr),
+            _ => (),
+        }
+    }
+    let mut set = HashSet::new();
+    recursion(&mut set, expr);
+    set
+}
+/// Extracts constants from an expression.
+///
+/// This function traverses an expression tree and collects the values of `Expression::Constant` nodes
+/// together with the factors of `Expression::Scaled` nodes. Values are returned in traversal order and may repeat.
+pub fn extract_constants<F: Field>(expr: &Expression<F>) -> Vec<F> {
+    fn recursion<F: Field>(dst: &mut Vec<F>, expr: &Expression<F>) {
+        match expr {
+            Expression::Constant(value) => dst.push(*value),
+            Expression::Sum(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Product(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Negated(expr) => recursion(dst, expr),
+            Expression::Scaled(expr, scale) => {
+                dst.push(*scale);
+                recursion(dst, expr);
+            }
+            _ => (),
+        }
+    }
+    let mut constants = vec![];
+    recursion(&mut constants, expr);
+    constants
+}
+/// Extracts the multiplicative factors of an expression.
+///
+/// Products are flattened and negations and scalings are looked through, so `-(s * (f * (a - b)))` has the factors
+/// `s`, `f` and `a - b`. Any other expression is its own single factor.
+pub fn extract_factors<F: Field>(expr: &Expression<F>) -> Vec<&Expression<F>> {
+    fn recursion<'a, F: Field>(dst: &mut Vec<&'a Expression<F>>, expr: &'a Expression<F>) {
+        match expr {
+            Expression::Product(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Negated(expr) => recursion(dst, expr),
+            Expression::Scaled(expr, _) => recursion(dst, expr),
+            _ => dst.push(expr),
+        }
+    }
+    let mut factors = vec![];
+    recursion(&mut factors, expr);
+    factors
+}
+/// Canonicalizes an expression into a sum of monomials.
+///
+/// Each monomial maps its sorted wires to its coefficient, and monomials with a zero coefficient are dropped, so
+/// two expressions denoting the same polynomial (e.g. `a - a` and `0`) canonicalize to the same map. Wires are
+/// named after the query: `adv0[1]` for advice column 0 at the next row, `fix0[0]`, `inst0[0]` and `sel0` for
+/// selector 0.
+pub fn canonicalize<F: Field>(expr: &Expression<F>) -> BTreeMap<Vec<String>, F> {
+    let mut monomials = BTreeMap::new();
+    match expr {
+        Expression::Constant(a) => {
+            monomials.insert(vec![], *a);
+        }
+        Expression::Selector(selector) => {
+            monomials.insert(vec![format!("sel{}", selector.0)], F::one());
+        }
+        Expression::Fixed(fixed_query) => {
+            let name = format!(
+                "fix{}[{}]",
+                fixed_query.column_index, fixed_query.rotation.0
+            );
+            monomials.insert(vec![name], F::one());
+        }
+        Expression::Advice(advice_query) => {
+            let name = format!(
+                "adv{}[{}]",
+                advice_query.column_index, advice_query.rotation.0
+            );
+            monomials.insert(vec![name], F::one());
+        }
+        Expression::Instance(instance_query) => {
+            let name = format!(
+                "inst{}[{}]",
+                instance_query.column_index, instance_query.rotation.0
+            );
+            monomials.insert(vec![name], F::one());
+        }
+        Expression::Negated(expr) => {
+            for (wires, coefficient) in canonicalize(expr) {
+                monomials.insert(wires, -coefficient);
+            }
+        }
+        Expression::Sum(left, right) => {
+            monomials = canonicalize(left);
+            for (wires, coefficient) in canonicalize(right) {
+                *monomials.entry(wires).or_insert_with(F::zero) += coefficient;
+            }
+        }
+        Expression::Product(left, right) => {
+            let right = canonicalize(right);
+            for (left_wires, left_coefficient) in canonicalize(left) {
+                for (right_wires, right_coefficient) in right.iter() {
+                    let mut wires = left_wires.clone();
+                    wires.extend(right_wires.iter().cloned());
+                    wires.sort();
+                    *monomials.entry(wires).or_insert_with(F::zero) +=
+                        left_coefficient * right_coefficient;
+                }
+            }
+        }
+        Expression::Scaled(expr, scale) => {
+            for (wires, coefficient) in canonicalize(expr) {
+                monomials.insert(wires, coefficient * scale);
+            }
+        }
+    }
+    monomials.retain(|_, coefficient| *coefficient != F::zero());
+    monomials
+}
+/// Extracts the fixed queries of an expression, as column indices and rotations.
+pub fn extract_fixed_queries<F: Field>(expr: &Expression<F>) -> BTreeSet<(usize, i32)> {
+    fn recursion<F: Field>(dst: &mut BTreeSet<(usize, i32)>, expr: &Expression<F>) {
+        match expr {
+            Expression::Fixed(fixed_query) => {
+                dst.insert((fixed_query.column_index, fixed_query.rotation.0));
+            }
+            Expression::Sum(left, right) | Expression::Product(left, right) => {
+                recursion(dst, left);
+                recursion(dst, right);
+            }
+            Expression::Negated(expr) => recursion(dst, expr),
+            Expression::Scaled(expr, _) => recursion(dst, expr),
+            _ => (),
+        }
+    }
+    let mut set = BTreeSet::new();
+    recursion(&mut set, expr);
+    set
+}
+/// Substitutes the fixed queries of an expression with constants.
+///
+/// `value` maps the column index and rotation of each fixed query to its value; the substitution fails with `None`
+/// as soon as one of the values is unknown.
+pub fn substitute_fixed<F: Field>(
+    expr: &Expression<F>,
+    value: &dyn Fn(usize, i32) -> Option<F>,
+) -> Option<Expression<F>> {
+    Some(match expr {
+        Expression::Fixed(fixed_query) => {
+            Expression::Constant(value(fixed_query.column_index, fixed_query.rotation.0)?)
+        }
+        Expression::Negated(expr) => Expression::Negated(Box::new(substitute_fixed(expr, value)?)),
+        Expression::Sum(left, right) => Expression::Sum(
+            Box::new(substitute_fixed(left, value)?),
+            Box::new(substitute_fixed(right, value)?),
+        ),
+        Expression::Product(left, right) => Expression::Product(
+            Box::new(substitute_fixed(left, value)?),
+            Box::new(substitute_fixed(right, value)?),
+        ),
+        Expression::Scaled(expr, scale) => {
+            Expression::Scaled(Box::new(substitute_fixed(expr, value)?), *scale)
+        }
+        _ => expr.clone(),
+    })
+}
+/// Computes the degree of an expression in the witness.
+///
+/// This mirrors `Expression::degree`, except that selectors and fixed columns count as constants: only advice and
+/// instance queries contribute to the degree. A gate `s*(a+b-c)` therefore has witness degree 1.
+pub fn witness_degree<F: Field>(expr: &Expression<F>) -> usize {
+    match expr {
+        Expression::Advice(_) | Expression::Instance(_) => 1,
+        Expression::Sum(left, right) => witness_degree(left).max(witness_degree(right)),
+        Expression::Product(left, right) => witness_degree(left) + witness_degree(right),
+        Expression::Negated(expr) => witness_degree(expr),
+        Expression::Scaled(expr, _) => witness_degree(expr),
+        _ => 0,
+    }
+}
+/// A split of a gate polynomial, computing one of its subexpressions `e` in a helper advice column `h`.
+#[derive(Debug)]
+pub struct SplitSuggestion<'a, F: Field> {
+    /// The subexpression `e` moved to the helper column.
+    pub subexpression: &'a Expression<F>,
+    /// The degree of the polynomial once `e` is replaced with `h`.
+    pub remaining_degree: usize,
+    /// The degree of the gate `q*(h-e)` constraining the helper column, with its own selector `q`.
+    pub helper_degree: usize,
+}
+/// Computes the degree of an expression once `target`, one of its subexpressions, is replaced with a column query.
+fn degree_replacing<F: Field>(expr: &Expression<F>, target: &Expression<F>) -> usize {
+    if std::ptr::eq(expr, target) {
+        return 1;
+    }
+    match expr {
+        Expression::Sum(left, right) => {
+            degree_replacing(left, target).max(degree_replacing(right, target))
+        }
+        Expression::Product(left, right) => {
+            degree_replacing(left, target) + degree_replacing(right, target)
+        }
+        Expression::Negated(expr) => degree_replacing(expr, target),
+        Expression::Scaled(expr, _) => degree_replacing(expr, target),
+        _ => expr.degree(),
+    }
+}
+/// Suggests where to split an expression with a helper column to lower its degree.
+///
+/// Every proper subexpression of degree at least 2 is a candidate split point. A candidate is kept if both the
+/// remaining polynomial and the helper gate have a lower degree than the expression, and the kept candidate of
+/// highest degree is suggested (the first one in pre-order on ties). `None` means no single helper column lowers
+/// the degree.
+pub fn suggest_split<F: Field>(expr: &Expression<F>) -> Option<SplitSuggestion<'_, F>> {
+    fn recursion<'a, F: Field>(dst: &mut Vec<&'a Expression<F>>, expr: &'a Expression<F>) {
+        match expr {
+            Expression::Sum(left, right) | Expression::Product(left, right) => {
+                dst.push(left);
+                recursion(dst, left);
+                dst.push(right);
+                recursion(dst, right);
+            }
+            Expression::Negated(inner) | Expression::Scaled(inner, _) => {
+                dst.push(inner);
+                recursion(dst, inner);
+            }
+            _ => (),
+        }
+    }
+    let mut subexpressions = vec![];
+    recursion(&mut subexpressions, expr);
+
+    let degree = expr.degree();
+    let mut best: Option<SplitSuggestion<F>> = None;
+    for subexpression in subexpressions {
+        if subexpression.degree() < 2 {
+            continue;
+        }
+        let suggestion = SplitSuggestion {
+            subexpression,
+            remaining_degree: degree_replacing(expr, subexpression),
+            helper_degree: subexpression.degree() + 1,
+        };
+        let lowers = suggestion.remaining_degree.max(suggestion.helper_degree) < degree;
+        let higher = best.as_ref().map_or(true, |best| {
+            subexpression.degree() > best.subexpression.degree()
+        });
+        if lowers && higher {
+            best = Some(suggestion);
+        }
+    }
+    best
+}
+/// Detects an expression pinning an advice cell to a constant.
+///
+/// Selector and constant factors are dropped, and the remaining factor must be linear in a single advice query, as
+/// in `s * (a - 5)` or `s * 3 * a`: wherever the selectors are enabled, the query equals the returned constant.
+/// Returns the advice column index, the rotation of the query and the constant.
+pub fn pinned_advice<F: Field>(expr: &Expression<F>) -> Option<(usize, i32, F)> {
+    // A zero constant or scale makes the expression zero, whatever the query: it pins nothing.
+    if canonicalize(expr).is_empty() {
+        return None;
+    }
+    let mut factors = extract_factors(expr)
+        .into_iter()
+        .filter(|factor| !matches!(factor, Expression::Selector(_) | Expression::Constant(_)));
+    let factor = factors.next()?;
+    if factors.next().is_some() {
+        return None;
+    }
+    let (column, rotation) = match extract_columns(factor).into_iter().collect::<Vec<_>>()[..] {
+        [(column, rotation)] => (column.index(), rotation.0),
+        _ => return None,
+    };
+    let monomials = canonicalize(factor);
+    let linear = *monomials.get(&vec![format!("adv{}[{}]", column, rotation)])?;
+    let constant = monomials.get(&vec![]).copied().unwrap_or_else(F::zero);
+    if monomials.len() != 1 + usize::from(constant != F::zero()) {
+        return None;
+    }
+    Some((column, rotation, -constant * linear.invert().unwrap()))
+}
+/// Folds an expression that does not depend on the witness into its value.
+///
+/// Selectors in `selectors` evaluate to 1 and the others to 0. The result is `None` if the value depends on a cell,
+/// except for products with a zero factor, which are 0 whatever the other factor.
+///
+pub fn eval_constant<F: Field>(expr: &Expression<F>, selectors: &HashSet<Selector>) -> Option<F> {
+    match expr {
+        Expression::Constant(value) => Some(*value),
+        Expression::Selector(selector) => match selectors.contains(selector) {
+            true => Some(F::one()),
+            false => Some(F::zero()),
+        },
+        Expression::Fixed { .. } | Expression::Advice { .. } | Expression::Instance { .. } => None,
+        Expression::Negated(expr) => eval_constant(expr, selectors).map(|value| -value),
+        Expression::Sum(left, right) => {
+            Some(eval_constant(left, selectors)? + eval_constant(right, selectors)?)
+        }
+        Expression::Product(left, right) => {
+            let left = eval_constant(left, selectors);
+            let right = eval_constant(right, selectors);
+            match (left, right) {
+                (Some(left), Some(right)) => Some(left * right),
+                (Some(zero), None) | (None, Some(zero)) if bool::from(zero.is_zero()) => {
+                    Some(F::zero())
+                }
+                _ => None,
+            }
+        }
+        Expression::Scaled(expr, scale) => {
+            if scale.is_zero().into() {
+                Some(F::zero())
+            } else {
+                eval_constant(expr, selectors).map(|value| value * *scale)
+            }
+        }
+    }
+}
 /// Evaluates an abstract expression and returns the abstract result.
 ///
 /// This function evaluates an abstract expression and returns an abstract result based on the provided selectors.
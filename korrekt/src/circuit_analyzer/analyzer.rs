@@ -1,31 +1,47 @@
-use anyhow::{Context, Result, Ok};
+use anyhow::{anyhow, Context, Ok, Result};
 use halo2_proofs::{
     arithmetic::FieldExt as Field,
     circuit::layouter::RegionColumn,
     dev::CellValue,
-    plonk::{Circuit, ConstraintSystem, Expression},
+    plonk::{
+        Any, Circuit, Column, Constraint, ConstraintSystem, Expression, Selector, VirtualCells,
+    },
+    poly::Rotation,
 };
+use num::{bigint::Sign, BigInt};
 use std::{
-    collections::{HashMap, HashSet},
-    fs,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt, fs,
     fs::File,
     fs::OpenOptions,
+    hash::Hash,
+    io::Write,
+    panic::Location,
     path::Path,
-    process::Command,
 };
 
 use crate::circuit_analyzer::{
     abstract_expr::{self, AbsResult},
+    field_encoder::{DefaultFieldEncoder, FieldEncoder},
     layouter,
+    name_formatter::{self, CellKind, CellLocation, CellRef, DefaultNameFormatter, NameFormatter},
+    shape::AnalyticalShape,
+};
+use crate::export::lean;
+use crate::io::analyzer_io::{
+    format_model_with_limit, output_result, retrieve_user_input_for_underconstrained,
+    ModelPrintLimit,
 };
-use crate::io::analyzer_io::{output_result, retrieve_user_input_for_underconstrained};
 use crate::io::analyzer_io_type::{
-    AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, VerificationMethod,
+    AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, Finding, FindingLog,
+    VerificationMethod,
 };
 use crate::smt_solver::{
     smt,
     smt::Printer,
     smt_parser::{self, ModelResult, Satisfiability},
+    smt_validator,
+    solver::{CommandSolver, Solver},
 };
 use layouter::AnalyticLayouter;
 
@@ -33,8 +49,229 @@ use layouter::AnalyticLayouter;
 pub struct Analyzer<F: Field> {
     pub cs: ConstraintSystem<F>,
     pub layouter: layouter::AnalyticLayouter<F>,
-    pub log: Vec<String>,
+    pub log: FindingLog,
     pub counter: u32,
+    pub gate_locations: HashMap<String, &'static Location<'static>>,
+    pub solver: Box<dyn Solver>,
+    pub quiet: bool,
+    pub properties: Vec<Property>,
+    pub witness_bounds: BTreeMap<usize, u32>,
+    pub output_columns: BTreeSet<usize>,
+    pub counterexample: Option<(ModelResult, ModelResult)>,
+    pub disabled_gates: HashSet<String>,
+    pub checkpoint_path: Option<String>,
+    pub blocking_clauses: Vec<String>,
+    pub resumed_blocking_clauses: Vec<String>,
+    pub resume: bool,
+    pub early_stop_patience: Option<u128>,
+    pub boundary_values_first: bool,
+    pub iterations_used: u128,
+    pub name_formatter: Box<dyn NameFormatter>,
+    pub field_encoder: Box<dyn FieldEncoder<F>>,
+    pub model_print_limit: Option<ModelPrintLimit>,
+    pub lookup_encoding_limit: u128,
+    pub region_prefix: Option<String>,
+    pub verdict_policy: Option<VerdictPolicy>,
+    pub grouped_declarations: bool,
+    pub degree_annotations: bool,
+    pub inline_copies: bool,
+    pub copy_representatives: HashMap<CellLocation, (CellLocation, String)>,
+    pub parameters: BTreeSet<String>,
+    pub witness_suffix: String,
+}
+/// A hook mapping the verdict of a solve to the status the analysis should conclude, see
+/// `Analyzer::with_verdict_policy`.
+pub struct VerdictPolicy(pub Box<dyn Fn(Satisfiability) -> Option<AnalyzerOutputStatus>>);
+
+impl fmt::Debug for VerdictPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VerdictPolicy")
+    }
+}
+/// Names cells after the representative of their copy class (see `Analyzer::with_inlined_copies`), followed by the
+/// suffix telling apart the copies of the circuit encoded by `Analyzer::distinguishable`.
+#[derive(Debug)]
+struct InlinedNames<'a> {
+    names: &'a dyn NameFormatter,
+    representatives: &'a HashMap<CellLocation, (CellLocation, String)>,
+    suffix: &'a str,
+}
+
+impl NameFormatter for InlinedNames<'_> {
+    fn cell_name(&self, cell: &CellRef) -> String {
+        let name = match self.representatives.get(&cell.location()) {
+            Some((location, region_name)) => self.names.cell_name(&CellRef {
+                kind: location.kind,
+                region: location.region,
+                region_name,
+                column: location.column,
+                row: location.row,
+            }),
+            None => self.names.cell_name(cell),
+        };
+        format!("{}{}", name, self.suffix)
+    }
+}
+/// A cell of the `fixed` matrix, whose value is either known or a parameter of the circuit.
+#[derive(Debug, Clone)]
+pub enum FixedCell<F: Field> {
+    /// A known cell, as recorded by `MockProver`.
+    Value(CellValue<F>),
+    /// A parameter, encoded as a free SMT variable of this name.
+    Symbolic(String),
+}
+
+impl<F: Field> From<CellValue<F>> for FixedCell<F> {
+    fn from(value: CellValue<F>) -> Self {
+        FixedCell::Value(value)
+    }
+}
+/// The `fixed` matrix of a circuit, by column then row, whose cells may be symbolic (see `FixedCell`).
+///
+/// Analyzing a circuit with symbolic fixed cells covers every instantiation of the circuit family at once: the
+/// solver may pick any value for the parameters. A matrix recorded by `MockProver` converts into a concrete one.
+///
+#[derive(Debug, Clone)]
+pub struct FixedMatrix<F: Field>(pub Vec<Vec<FixedCell<F>>>);
+
+impl<F: Field> From<Vec<Vec<CellValue<F>>>> for FixedMatrix<F> {
+    fn from(fixed: Vec<Vec<CellValue<F>>>) -> Self {
+        FixedMatrix(
+            fixed
+                .into_iter()
+                .map(|column| column.into_iter().map(FixedCell::from).collect())
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field> FixedMatrix<F> {
+    /// Makes the cell of `column` at `row` a parameter named `name`.
+    ///
+    pub fn with_symbolic(mut self, column: usize, row: usize, name: &str) -> Self {
+        self.0[column][row] = FixedCell::Symbolic(name.to_owned());
+        self
+    }
+
+    /// Returns the known values of the matrix, for the static passes.
+    ///
+    /// Static passes reason about the values of fixed cells, so this fails if the matrix holds parameters instead
+    /// of reporting about an arbitrary instantiation of the circuit.
+    ///
+    pub fn to_concrete(&self) -> Result<Vec<Vec<CellValue<F>>>> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(column, cells)| {
+                cells
+                    .iter()
+                    .map(|cell| match cell {
+                        FixedCell::Value(value) => Ok(*value),
+                        FixedCell::Symbolic(name) => Err(anyhow!(
+                            "Fixed column {} holds the parameter {:?}, which static passes cannot analyze!",
+                            column,
+                            name
+                        )),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the SMT identifiers of the parameters.
+    fn parameters(&self) -> BTreeSet<String> {
+        self.0
+            .iter()
+            .flatten()
+            .filter_map(|cell| match cell {
+                FixedCell::Symbolic(name) => Some(name_formatter::to_identifier(name)),
+                FixedCell::Value(_) => None,
+            })
+            .collect()
+    }
+}
+/// Returns the canonical decimal representation of a field element.
+pub(crate) fn field_to_decimal<F: Field>(value: &F) -> String {
+    BigInt::from_bytes_le(Sign::Plus, value.to_repr().as_ref()).to_string()
+}
+/// The default maximum size of the lookup encoding, see `Analyzer::with_lookup_encoding_limit`.
+pub const DEFAULT_LOOKUP_ENCODING_LIMIT: u128 = 1 << 24;
+/// The public inputs tried first by `Analyzer::with_boundary_values_first`, `-1` being the largest field element.
+pub const BOUNDARY_VALUES: &[&str] = &["0", "1", "-1"];
+/// The default degree above which `Analyzer::analyze_high_degree_gates` suggests splitting gates.
+pub const DEFAULT_TARGET_DEGREE: usize = 3;
+/// The default constraint density below which `Analyzer::analyze_sparse_regions` reports regions.
+pub const DEFAULT_DENSITY_THRESHOLD: f64 = 0.5;
+/// A soundness property of a circuit.
+///
+/// `relation` is an SMT-LIB boolean term over cell variables (e.g. `(= A-0-2-0 (as ff3 F))`) that every witness
+/// accepted by the circuit is expected to satisfy.
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub name: String,
+    pub relation: String,
+}
+/// Progress of an underconstrained analysis.
+///
+/// `iteration` is the last completed iteration of `uniqueness_assertion` and `blocking_clauses` holds the
+/// disjunctions, one per completed iteration, preventing the solver from returning the models already examined.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub iteration: u128,
+    pub blocking_clauses: Vec<String>,
+}
+impl Checkpoint {
+    /// Reads a checkpoint written by `save`.
+    ///
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path).context("Failed to read checkpoint file!")?;
+        let mut lines = contents.lines();
+        let iteration = lines
+            .next()
+            .and_then(|line| line.strip_prefix("iteration "))
+            .context("Invalid checkpoint file!")?
+            .parse()
+            .context("Invalid checkpoint iteration!")?;
+        Ok(Checkpoint {
+            iteration,
+            blocking_clauses: lines.map(str::to_owned).collect(),
+        })
+    }
+    /// Writes the checkpoint as an `iteration N` line followed by one blocking clause per line.
+    ///
+    pub fn save(&self, path: &str) -> Result<()> {
+        let mut contents = format!("iteration {}\n", self.iteration);
+        for clause in self.blocking_clauses.iter() {
+            contents.push_str(clause);
+            contents.push('\n');
+        }
+        fs::write(path, contents).context("Failed to write checkpoint file!")
+    }
+}
+/// A model of the circuit, as returned by `Analyzer::solved_model`.
+///
+/// `assignments` holds the value of every cell of the model, sorted by location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolvedModel<F: Field> {
+    pub assignments: Vec<(CellLocation, F)>,
+}
+/// Whether the table of a lookup argument is made of fixed columns only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookupTableKind {
+    Fixed,
+    Dynamic,
+}
+/// Structure of a lookup argument, as summarized by `Analyzer::lookup_summary`.
+///
+/// `table_columns` holds the indices of the columns queried by the table expressions, in order. `selector` is the
+/// simple selector multiplying every input expression, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LookupInfo {
+    pub index: usize,
+    pub input_count: usize,
+    pub table_columns: Vec<usize>,
+    pub table_kind: LookupTableKind,
+    pub selector: Option<Selector>,
 }
 #[derive(Debug)]
 pub enum NodeType {
@@ -48,6 +285,23 @@ pub enum NodeType {
     Scaled,
     Poly,
 }
+impl NodeType {
+    /// Returns true if terms of this type are self-delimiting in SMT-LIB.
+    ///
+    /// Variables and constants (`A-0-0-0`, `(as ff1 F)`) can be used as operands as they are. The other node types
+    /// are applications returned without their outer parentheses (`ff.add a b`), which must be wrapped before
+    /// being used as an operand or asserted.
+    pub fn is_atomic(&self) -> bool {
+        match self {
+            NodeType::Constant | NodeType::Advice | NodeType::Instance | NodeType::Fixed => true,
+            NodeType::Negated
+            | NodeType::Mult
+            | NodeType::Add
+            | NodeType::Scaled
+            | NodeType::Poly => false,
+        }
+    }
+}
 #[derive(Debug)]
 pub enum Operation {
     Equal,
@@ -73,12 +327,588 @@ impl<F: Field, C: Circuit<F>> From<&C> for Analyzer<F> {
         Analyzer {
             cs,
             layouter,
-            log: vec![],
+            log: FindingLog::default(),
             counter: 0,
+            gate_locations: HashMap::new(),
+            solver: Box::new(CommandSolver::cvc5()),
+            quiet: false,
+            properties: vec![],
+            witness_bounds: BTreeMap::new(),
+            output_columns: BTreeSet::new(),
+            counterexample: None,
+            disabled_gates: HashSet::new(),
+            checkpoint_path: None,
+            blocking_clauses: vec![],
+            resumed_blocking_clauses: vec![],
+            resume: false,
+            early_stop_patience: None,
+            boundary_values_first: false,
+            iterations_used: 0,
+            name_formatter: Box::new(DefaultNameFormatter),
+            field_encoder: Box::new(DefaultFieldEncoder),
+            model_print_limit: None,
+            lookup_encoding_limit: DEFAULT_LOOKUP_ENCODING_LIMIT,
+            region_prefix: None,
+            verdict_policy: None,
+            grouped_declarations: false,
+            degree_annotations: false,
+            inline_copies: false,
+            copy_representatives: HashMap::new(),
+            parameters: BTreeSet::new(),
+            witness_suffix: String::new(),
         }
     }
 }
 impl<'b, F: Field> Analyzer<F> {
+    /// Registers the source locations at which gates were created.
+    ///
+    /// halo2 gates only carry a name. Circuits that capture `Location::caller()` (e.g. from a `#[track_caller]`
+    /// wrapper around `create_gate`) can pass the resulting map, keyed by gate name, so that findings about a gate
+    /// point at its definition.
+    ///
+    pub fn with_gate_locations(
+        mut self,
+        gate_locations: HashMap<String, &'static Location<'static>>,
+    ) -> Self {
+        self.gate_locations = gate_locations;
+        self
+    }
+
+    /// Replaces the SMT solver used by the underconstrained analysis (`cvc5` by default).
+    ///
+    pub fn with_solver(mut self, solver: impl Solver + 'static) -> Self {
+        self.solver = Box::new(solver);
+        self
+    }
+
+    /// Suppresses everything the analyses print to stdout, e.g. when only the exit code is of interest.
+    ///
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Adds a custom gate to the constraint system after construction.
+    ///
+    /// The gate is created through `ConstraintSystem::create_gate`, so it is visible to every subsequent analysis.
+    /// Findings of previous analyses are discarded with `reset_findings`, as they no longer describe the circuit.
+    ///
+    pub fn add_gate<C: Into<Constraint<F>>, Iter: IntoIterator<Item = C>>(
+        &mut self,
+        name: &'static str,
+        constraints: impl FnOnce(&mut VirtualCells<'_, F>) -> Iter,
+    ) {
+        self.cs.create_gate(name, constraints);
+        self.reset_findings();
+    }
+
+    /// Discards the findings of previous analyses and resets their counter.
+    ///
+    /// Call this after mutating `self.cs` or `self.layouter` directly, as earlier findings no longer describe the
+    /// circuit.
+    ///
+    pub fn reset_findings(&mut self) {
+        self.log.clear();
+        self.counter = 0;
+    }
+
+    /// Restricts the cells of an advice column to `[0, 2^bits)` in the underconstrained analysis.
+    ///
+    /// Without bounds the solver may pick any field element for the witness, which makes counterexamples hard to
+    /// read. Bounding columns known to hold small values (e.g. 64-bit limbs) keeps both witnesses plausible.
+    /// Bounds are also the domains against which `analyze_non_constraining_lookups` compares lookup tables.
+    ///
+    pub fn with_witness_bound(mut self, column: usize, bits: u32) -> Self {
+        self.witness_bounds.insert(column, bits);
+        self
+    }
+
+    /// Declares the advice columns holding the outputs of the circuit in the underconstrained analysis.
+    ///
+    /// By default the analysis looks for a second witness differing from the first in any cell. With output
+    /// columns, it only looks for one differing in a cell of these columns, for the same public inputs: witnesses
+    /// only differing in intermediate cells are not counterexamples.
+    ///
+    pub fn with_output_columns(mut self, output_columns: BTreeSet<usize>) -> Self {
+        self.output_columns = output_columns;
+        self
+    }
+
+    /// Mutes gates, by name, in the analyses.
+    ///
+    /// Disabled gates are left out of the SMT encoding and skipped by the static passes, e.g. to silence a gate that
+    /// has already been audited. Structural queries (`constants`, naming collisions, ...) still see them.
+    ///
+    pub fn with_disabled_gates(mut self, disabled_gates: HashSet<String>) -> Self {
+        self.disabled_gates = disabled_gates;
+        self
+    }
+
+    /// Saves the progress of the underconstrained analysis to `path` after each iteration.
+    ///
+    /// With `resume`, an existing checkpoint at `path` is reloaded first: the analysis continues after the last
+    /// completed iteration and the models examined before are kept blocked, so they are not examined again.
+    ///
+    pub fn with_checkpoint(mut self, path: &str, resume: bool) -> Self {
+        self.checkpoint_path = Some(path.to_owned());
+        self.resume = resume;
+        self
+    }
+
+    /// Loads the blocking clauses saved by `save_blocking_clauses` or `with_checkpoint`, e.g. before an analysis was
+    /// interrupted.
+    ///
+    /// The next underconstrained analysis asserts them before its first iteration, so the models they block are not
+    /// examined again. Unlike `with_checkpoint`, no iteration count is restored: the analysis runs all its iterations
+    /// on top of the loaded clauses.
+    ///
+    pub fn load_blocking_clauses(&mut self, path: &str) -> Result<()> {
+        let checkpoint = Checkpoint::load(path).context("Failed to load blocking clauses!")?;
+        self.resumed_blocking_clauses = checkpoint.blocking_clauses;
+        Ok(())
+    }
+
+    /// Saves the blocking clauses of the last underconstrained analysis to `path` as a `Checkpoint`.
+    ///
+    /// The clauses loaded with `load_blocking_clauses` are saved as well, followed by one clause per model examined.
+    ///
+    pub fn save_blocking_clauses(&self, path: &str) -> Result<()> {
+        let checkpoint = Checkpoint {
+            iteration: self.blocking_clauses.len() as u128,
+            blocking_clauses: self.blocking_clauses.clone(),
+        };
+        checkpoint
+            .save(path)
+            .context("Failed to save blocking clauses!")
+    }
+
+    /// Stops the `Random` iterations of the underconstrained analysis early.
+    ///
+    /// The witness behavior of a model is summarized by classifying each non-instance variable as 0, 1 or any other
+    /// value. Once `patience` consecutive models reveal no behavior that was not seen before, further public inputs
+    /// are unlikely to find a counterexample and the analysis stops. The number of iterations actually run is
+    /// stored in `self.iterations_used`.
+    ///
+    pub fn with_early_stop(mut self, patience: u128) -> Self {
+        self.early_stop_patience = Some(patience);
+        self
+    }
+
+    /// Starts the `Random` iterations of the underconstrained analysis with boundary public inputs.
+    ///
+    /// Edge values are more likely to reveal bugs than the inputs picked by the solver. With this option, iteration
+    /// `i` first looks for a model where every public input is `BOUNDARY_VALUES[i - 1]`, as long as there are
+    /// boundary values left. A boundary value admitting no witness is skipped, and the remaining iterations are
+    /// left to the solver.
+    ///
+    pub fn with_boundary_values_first(mut self) -> Self {
+        self.boundary_values_first = true;
+        self
+    }
+
+    /// Limits the models printed by the underconstrained analysis (see `ModelPrintLimit`).
+    ///
+    /// Models are printed in full by default. The limit only applies to the terminal: `self.counterexample` keeps
+    /// complete models.
+    ///
+    pub fn with_model_print_limit(
+        mut self,
+        max_assignments: usize,
+        max_value_width: usize,
+    ) -> Self {
+        self.model_print_limit = Some(ModelPrintLimit {
+            max_assignments,
+            max_value_width,
+        });
+        self
+    }
+
+    /// Sets the maximum size of the lookup encoding (`DEFAULT_LOOKUP_ENCODING_LIMIT` by default).
+    ///
+    /// Each lookup is encoded as a disjunction over the table rows at every row of every region, so the encoding grows
+    /// as `rows × table_rows` (see `lookup_encoding_size`). Encoding a circuit above the limit fails early instead of
+    /// exhausting memory.
+    ///
+    pub fn with_lookup_encoding_limit(mut self, limit: u128) -> Self {
+        self.lookup_encoding_limit = limit;
+        self
+    }
+
+    /// Restricts the SMT encoding to the regions whose names start with `prefix`.
+    ///
+    /// Gates, lookups and copy constraints of the other regions are left out, so the cells they would constrain are
+    /// free. This is meant to audit a single gadget in isolation, see `analyze_gadget`.
+    ///
+    pub fn with_region_prefix(mut self, prefix: &str) -> Self {
+        self.region_prefix = Some(prefix.to_owned());
+        self
+    }
+
+    /// Declares the variables of the underconstrained analysis in a single block, grouped by region, ahead of the
+    /// constraints instead of where they are first used (see `smt::write_start_grouped`).
+    ///
+    pub fn with_grouped_declarations(mut self) -> Self {
+        self.grouped_declarations = true;
+        self
+    }
+
+    /// Annotates the assertion of each gate polynomial in the SMT encoding with its degree in the witness (see
+    /// `abstract_expr::witness_degree`), as a `; degree {d} (gate "{name}", polynomial {i})` comment line ahead of
+    /// it. The annotations can be post-processed to tune solvers.
+    ///
+    pub fn with_degree_annotations(mut self) -> Self {
+        self.degree_annotations = true;
+        self
+    }
+
+    /// Streams the findings to `writer` as newline-delimited JSON (NDJSON) while the analysis proceeds.
+    ///
+    /// Each finding is written as a single line (see `Finding::to_json`) and flushed as soon as a pass logs it, so
+    /// a consumer can tail the stream of long runs, e.g. with `std::io::stderr()` as `writer`. The findings are
+    /// still collected in the `self.log` vector.
+    ///
+    pub fn with_ndjson_findings(mut self, writer: impl Write + 'static) -> Self {
+        self.log.stream_to(writer);
+        self
+    }
+
+    /// Inlines the copy constraints in the SMT encoding.
+    ///
+    /// Each class of cells connected by copy constraints is encoded as a single variable, named after the
+    /// representative of the class (see `copy_classes`), and no equality is asserted between its cells. This
+    /// produces the smallest formula; models only assign the representatives.
+    ///
+    pub fn with_inlined_copies(mut self) -> Self {
+        self.inline_copies = true;
+        self
+    }
+
+    /// Maps the cells connected by the copy constraints of the selected regions to the representative of their
+    /// class, with its region name.
+    ///
+    /// A class bound to public inputs is represented by its first instance cell, so that the instance variable is
+    /// kept in the encoding; any other class by its first cell.
+    ///
+    pub fn copy_classes(&self) -> HashMap<CellLocation, (CellLocation, String)> {
+        fn root(
+            parents: &HashMap<CellLocation, CellLocation>,
+            location: CellLocation,
+        ) -> CellLocation {
+            match parents.get(&location) {
+                Some(parent) => root(parents, *parent),
+                None => location,
+            }
+        }
+        let copies = self
+            .layouter
+            .regions
+            .iter()
+            .flat_map(|region| region.advice_eq_table.iter().chain(region.eq_table.iter()))
+            .chain(self.layouter.eq_table.iter());
+        let mut parents = HashMap::new();
+        let mut members = BTreeSet::new();
+        for (left, right) in copies {
+            if !self.copy_selected(left, right) {
+                continue;
+            }
+            let (left, right) = match (
+                name_formatter::parse_default_name(left),
+                name_formatter::parse_default_name(right),
+            ) {
+                (Some(left), Some(right)) => (left, right),
+                _ => continue,
+            };
+            // Cells copied to fixed cells are pinned instead, see `encode_fixed_copies`.
+            if left.kind == CellKind::Fixed || right.kind == CellKind::Fixed {
+                continue;
+            }
+            members.insert(left);
+            members.insert(right);
+            let (left, right) = (root(&parents, left), root(&parents, right));
+            if left != right {
+                parents.insert(left, right);
+            }
+        }
+
+        let mut classes: BTreeMap<CellLocation, Vec<CellLocation>> = BTreeMap::new();
+        for member in members {
+            classes
+                .entry(root(&parents, member))
+                .or_default()
+                .push(member);
+        }
+        let mut representatives = HashMap::new();
+        for members in classes.values() {
+            let representative = *members
+                .iter()
+                .min_by_key(|member| (member.kind != CellKind::Instance, **member))
+                .unwrap();
+            let region_name = representative
+                .region
+                .and_then(|region| self.layouter.regions.get(region))
+                .map_or("", |region| region.name.as_str());
+            for member in members {
+                representatives.insert(*member, (representative, region_name.to_owned()));
+            }
+        }
+        representatives
+    }
+
+    /// Returns the scheme naming cells in the SMT encoding, taking inlined copies into account.
+    fn cell_names(&self) -> InlinedNames<'_> {
+        InlinedNames {
+            names: self.name_formatter.as_ref(),
+            representatives: &self.copy_representatives,
+            suffix: &self.witness_suffix,
+        }
+    }
+
+    /// Sets a policy applied to the verdict of each solve of the underconstrained analysis.
+    ///
+    /// When the policy maps a verdict to a status, the analysis stops and concludes that status. Otherwise the
+    /// verdict is handled as usual: `sat` and `unsat` drive the analysis, and `unknown` is an error. For instance,
+    /// security audits may conclude `Underconstrained` whenever the solver gives up.
+    ///
+    pub fn with_verdict_policy(
+        mut self,
+        policy: impl Fn(Satisfiability) -> Option<AnalyzerOutputStatus> + 'static,
+    ) -> Self {
+        self.verdict_policy = Some(VerdictPolicy(Box::new(policy)));
+        self
+    }
+
+    /// Applies the verdict policy to the verdict of a solve, see `with_verdict_policy`.
+    fn apply_verdict_policy(&self, sat: Satisfiability) -> Result<Option<AnalyzerOutputStatus>> {
+        if let Some(status) = self
+            .verdict_policy
+            .as_ref()
+            .and_then(|policy| (policy.0)(sat))
+        {
+            return Ok(Some(status));
+        }
+        if matches!(sat, Satisfiability::Unknown) {
+            return Err(anyhow!(
+                "The solver could not decide the satisfiability of the formula!"
+            ));
+        }
+        Ok(None)
+    }
+
+    /// Returns whether the region `region_no` is encoded, see `with_region_prefix`.
+    fn region_selected(&self, region_no: usize) -> bool {
+        match &self.region_prefix {
+            Some(prefix) => self
+                .layouter
+                .regions
+                .get(region_no)
+                .map_or(false, |region| region.name.starts_with(prefix.as_str())),
+            None => true,
+        }
+    }
+
+    /// Returns whether a copy constraint is encoded, i.e. whether the regions of both of its cells are selected.
+    fn copy_selected(&self, left: &str, right: &str) -> bool {
+        // Public inputs belong to no region and are shared by all of them.
+        [left, right].iter().all(|name| {
+            name_formatter::parse_default_name(name)
+                .and_then(|location| location.region)
+                .map_or(true, |region| self.region_selected(region))
+        })
+    }
+
+    /// Replaces the scheme naming cells in the SMT encoding (`DefaultNameFormatter` by default).
+    ///
+    pub fn with_name_formatter(mut self, name_formatter: impl NameFormatter + 'static) -> Self {
+        self.name_formatter = Box::new(name_formatter);
+        self
+    }
+
+    /// Replaces the syntax of the constants in the SMT encoding (`DefaultFieldEncoder` by default).
+    ///
+    pub fn with_field_encoder(mut self, field_encoder: impl FieldEncoder<F> + 'static) -> Self {
+        self.field_encoder = Box::new(field_encoder);
+        self
+    }
+
+    /// Returns the SMT identifier of a cell, named by `names`.
+    fn cell_identifier(names: &dyn NameFormatter, cell: &CellRef) -> String {
+        name_formatter::to_identifier(&names.cell_name(cell))
+    }
+
+    /// Returns the SMT identifier of a cell named with the default scheme, as the layouter does.
+    fn rename_cell(&self, name: &str) -> String {
+        match name_formatter::parse_default_name(name) {
+            Some(location) => self.location_identifier(&location),
+            None => name.to_owned(),
+        }
+    }
+
+    /// Returns the SMT identifier of the cell at `location`.
+    fn location_identifier(&self, location: &CellLocation) -> String {
+        let region_name = location
+            .region
+            .and_then(|region| self.layouter.regions.get(region))
+            .map_or("", |region| region.name.as_str());
+        Self::cell_identifier(
+            &self.cell_names(),
+            &CellRef {
+                kind: location.kind,
+                region: location.region,
+                region_name,
+                column: location.column,
+                row: location.row,
+            },
+        )
+    }
+
+    /// Maps the SMT identifiers of the cells of the encoding to their locations.
+    ///
+    /// This covers the advice, fixed and instance cells queried by the gates at every row of each region, and the
+    /// cells of the copy constraints recorded by the layouter. Instance cells are located by their absolute row.
+    ///
+    pub fn cell_locations(&self) -> BTreeMap<String, CellLocation> {
+        let queries: Vec<(CellKind, usize, i32)> = self
+            .cs
+            .advice_queries
+            .iter()
+            .map(|(column, rotation)| (CellKind::Advice, column.index(), rotation.0))
+            .chain(
+                self.cs
+                    .fixed_queries
+                    .iter()
+                    .map(|(column, rotation)| (CellKind::Fixed, column.index(), rotation.0)),
+            )
+            .chain(
+                self.cs
+                    .instance_queries
+                    .iter()
+                    .map(|(column, rotation)| (CellKind::Instance, column.index(), rotation.0)),
+            )
+            .collect();
+
+        let mut locations = vec![];
+        for (region_no, region) in self.layouter.regions.iter().enumerate() {
+            for (kind, column, rotation) in queries.iter() {
+                for row in 0..region.row_count as i32 {
+                    locations.push(match kind {
+                        CellKind::Instance => CellLocation {
+                            kind: *kind,
+                            region: None,
+                            column: *column,
+                            row: region.row_offset as i32 + row + rotation,
+                        },
+                        _ => CellLocation {
+                            kind: *kind,
+                            region: Some(region_no),
+                            column: *column,
+                            row: row + rotation,
+                        },
+                    });
+                }
+            }
+            let copies = region.eq_table.iter().chain(region.advice_eq_table.iter());
+            for (left, right) in copies {
+                locations.extend(name_formatter::parse_default_name(left));
+                locations.extend(name_formatter::parse_default_name(right));
+            }
+        }
+        for (left, right) in self.layouter.eq_table.iter() {
+            locations.extend(name_formatter::parse_default_name(left));
+            locations.extend(name_formatter::parse_default_name(right));
+        }
+
+        // With inlined copies, only the representative of each copy class is encoded.
+        locations
+            .into_iter()
+            .filter(|location| {
+                self.copy_representatives
+                    .get(location)
+                    .map_or(true, |(representative, _)| representative == location)
+            })
+            .map(|location| (self.location_identifier(&location), location))
+            .collect()
+    }
+
+    /// Maps the SMT identifiers of the cells of the columns registered with `with_witness_bound` to their bounds.
+    ///
+    fn witness_bounds_by_cell(&self) -> BTreeMap<String, u32> {
+        self.cell_locations()
+            .into_iter()
+            .filter(|(_, location)| location.kind == CellKind::Advice)
+            .filter_map(|(name, location)| {
+                self.witness_bounds
+                    .get(&location.column)
+                    .map(|bits| (name, *bits))
+            })
+            .collect()
+    }
+
+    /// Returns the names of the cells of the output columns, or `None` when no output column is declared.
+    fn output_cells(&self) -> Option<BTreeSet<String>> {
+        if self.output_columns.is_empty() {
+            return None;
+        }
+        Some(
+            self.cell_locations()
+                .into_iter()
+                .filter(|(_, location)| {
+                    location.kind == CellKind::Advice
+                        && self.output_columns.contains(&location.column)
+                })
+                .map(|(name, _)| name)
+                .collect(),
+        )
+    }
+
+    /// Joins a model with the locations of its cells.
+    ///
+    /// Variables that are not cells (e.g. the bits of range checks) are left out. The assignments are sorted by
+    /// location.
+    ///
+    pub fn solved_model(&self, model: &ModelResult) -> Result<SolvedModel<F>> {
+        let locations = self.cell_locations();
+        let mut assignments = vec![];
+        for variable in model.result.values() {
+            if let Some(location) = locations.get(&variable.name) {
+                let value = F::from_str_vartime(&variable.value.element)
+                    .with_context(|| format!("Invalid value for {}!", variable.name))?;
+                assignments.push((*location, value));
+            }
+        }
+        assignments.sort_by_key(|(location, _)| *location);
+        Ok(SolvedModel { assignments })
+    }
+
+    /// Registers a soundness property to be checked by `analyze_properties`.
+    ///
+    pub fn add_property(&mut self, name: &str, relation: &str) {
+        self.properties.push(Property {
+            name: name.to_owned(),
+            relation: relation.to_owned(),
+        });
+    }
+
+    /// Returns the ` (defined at file:line:column)` suffix for a gate, or an empty string if its location is unknown.
+    fn gate_location(&self, gate_name: &str) -> String {
+        match self.gate_locations.get(gate_name) {
+            Some(location) => format!(" (defined at {})", location),
+            None => String::new(),
+        }
+    }
+
+    /// Prints the number of findings of a static pass, e.g. `3 unused gates`, and returns its output.
+    fn finish_pass(
+        &self,
+        output_status: AnalyzerOutputStatus,
+        count: usize,
+        findings: &str,
+    ) -> Result<AnalyzerOutput> {
+        if !self.quiet {
+            println!("Finished analysis: {} {} found.", count, findings);
+        }
+        Ok(AnalyzerOutput { output_status })
+    }
+
     /// Detects unused custom gates
     ///
     /// This function iterates through the gates in the constraint system (`self.cs`) and checks if each gate is used.
@@ -90,6 +920,9 @@ impl<'b, F: Field> Analyzer<F> {
         let mut count = 0;
         let mut used;
         for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
             used = false;
 
             // is this gate identically zero over regions?
@@ -104,212 +937,2332 @@ impl<'b, F: Field> Analyzer<F> {
                 }
             }
 
-            if !used {
+            if !used {
+                count += 1;
+                let location = self.gate_location(gate.name());
+                self.log.log_finding(AnalyzerType::UnusedGates, format!("unused gate: \"{}\"{} (consider removing the gate or checking selectors in regions)", gate.name(), location));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::UnusedCustomGates,
+            count,
+            "unused gates",
+        )
+    }
+
+    /// Detects unused columns
+    ///
+    /// This function iterates through the advice queries in the constraint system (`self.cs`) and checks if each column is used.
+    /// A column is considered unused if it does not appear in any of the polynomials within the gates of the constraint system.
+    /// If an unused column is found, it is logged in the `self.log` vector.
+    /// Finally, the function prints the total number of unused columns found.
+    ///
+    pub fn analyze_unused_columns(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut used;
+        for (column, rotation) in self.cs.advice_queries.iter().cloned() {
+            used = false;
+
+            for gate in self.cs.gates.iter() {
+                if self.disabled_gates.contains(gate.name()) {
+                    continue;
+                }
+                for poly in gate.polynomials() {
+                    let advices = abstract_expr::extract_columns(poly);
+                    if advices.contains(&(column.into(), rotation)) {
+                        used = true;
+                    }
+                }
+            }
+
+            if !used {
+                count += 1;
+                self.log.log_finding(
+                    AnalyzerType::UnusedColumns,
+                    format!("unused column: {:?}", column),
+                );
+            }
+        }
+        self.finish_pass(AnalyzerOutputStatus::UnusedColumns, count, "unused columns")
+    }
+
+    /// Detect assigned but unconstrained cells:
+    /// (does it occur in a not-identially zero polynomial in the region?)
+    /// (if not almost certainly a bug)
+    pub fn analyze_unconstrained_cells(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for region in self.layouter.regions.iter() {
+            let selectors = HashSet::from_iter(region.selectors().into_iter());
+            let mut used;
+            for (reg_column, rotation) in region.columns.iter().cloned() {
+                used = false;
+
+                match reg_column {
+                    RegionColumn::Selector(_) => continue,
+                    RegionColumn::Column(column) => {
+                        for gate in self.cs.gates.iter() {
+                            if self.disabled_gates.contains(gate.name()) {
+                                continue;
+                            }
+                            for poly in gate.polynomials() {
+                                let advices = abstract_expr::extract_columns(poly);
+                                let eval = abstract_expr::eval_abstract(poly, &selectors);
+
+                                if eval != AbsResult::Zero && advices.contains(&(column, rotation))
+                                {
+                                    used = true;
+                                }
+                            }
+                        }
+                    }
+                };
+
+                if !used {
+                    count += 1;
+                    self.log.log_finding(AnalyzerType::UnconstrainedCells, format!("unconstrained cell in \"{}\" region: {:?} (rotation: {:?}) -- very likely a bug.", region.name,  reg_column, rotation));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::UnconstrainedCells,
+            count,
+            "unconstrained cells",
+        )
+    }
+
+    /// Detects cells referenced by gates but never assigned
+    ///
+    /// This is the inverse of `analyze_unconstrained_cells`. For every row of every region, the gates that do not
+    /// evaluate to zero under the selectors enabled on that row are active. Each advice cell they query (the row
+    /// shifted by the query rotation) is looked up in the cells assigned by the region. Cells that fall inside the
+    /// region but were never assigned are logged in the `self.log` vector: the prover can choose their value freely,
+    /// which usually means an assignment is missing. Rotations leaving the region are not reported.
+    ///
+    pub fn analyze_unassigned_cells(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for region in self.layouter.regions.iter() {
+            let mut selectors_by_row: BTreeMap<i32, HashSet<Selector>> = BTreeMap::new();
+            for (reg_column, rotation) in region.columns.iter() {
+                if let RegionColumn::Selector(selector) = reg_column {
+                    selectors_by_row
+                        .entry(rotation.0)
+                        .or_default()
+                        .insert(*selector);
+                }
+            }
+
+            let mut reported = BTreeSet::new();
+            for (row, selectors) in selectors_by_row.iter() {
+                for gate in self.cs.gates.iter() {
+                    if self.disabled_gates.contains(gate.name()) {
+                        continue;
+                    }
+                    for poly in gate.polynomials() {
+                        if abstract_expr::eval_abstract(poly, selectors) == AbsResult::Zero {
+                            continue;
+                        }
+                        for (column, rotation) in abstract_expr::extract_columns(poly) {
+                            let offset = row + rotation.0;
+                            if offset < 0 || offset >= region.row_count as i32 {
+                                continue;
+                            }
+                            let assigned = region
+                                .columns
+                                .contains(&(RegionColumn::Column(column), Rotation(offset)));
+                            if !assigned && reported.insert((column.index(), offset)) {
+                                count += 1;
+                                self.log.log_finding(AnalyzerType::UnassignedCells, format!("unassigned cell in \"{}\" region: {:?} (offset: {}) is read by gate \"{}\" but never assigned -- very likely a missing assignment.", region.name, column, offset, gate.name()));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::UnassignedCells,
+            count,
+            "unassigned cells",
+        )
+    }
+
+    /// Detects cells assigned more than once within a region
+    ///
+    /// The layouter records every assignment made by `synthesize`, and for a cell assigned several times only the
+    /// last value reaches the witness, which usually hides a bug. This function logs every advice or fixed cell
+    /// assigned more than once in the same region in the `self.log` vector, in the order of their first assignment.
+    ///
+    pub fn analyze_double_assigned_cells(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for region in self.layouter.regions.iter() {
+            let mut assignments: HashMap<(Column<Any>, usize), usize> = HashMap::new();
+            for cell in region.assignments.iter() {
+                *assignments.entry(*cell).or_insert(0) += 1;
+            }
+            let mut reported = HashSet::new();
+            for cell in region.assignments.iter() {
+                let times = assignments[cell];
+                if times > 1 && reported.insert(*cell) {
+                    count += 1;
+                    self.log.log_finding(AnalyzerType::DoubleAssignedCells, format!("double-assigned cell in \"{}\" region: {:?} (offset: {}) is assigned {} times, only the last value is kept -- very likely a bug.", region.name, cell.0, cell.1, times));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::DoubleAssignedCells,
+            count,
+            "double-assigned cells",
+        )
+    }
+
+    /// Detects selectors that are always on
+    ///
+    /// A region applies the gates of a selector when it enables the selector or assigns a column queried by those
+    /// gates. Within such a region, the gates fit on every row where all their queried rotations stay inside the
+    /// region. A selector enabled on all of these rows, in every region applying its gates, is equivalent to no
+    /// selector at all and only wastes a column. Such selectors are logged in the `self.log` vector.
+    ///
+    pub fn analyze_always_on_selectors(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut selectors: Vec<Selector> = vec![];
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                for selector in abstract_expr::extract_selectors(poly) {
+                    if !selectors.contains(&selector) {
+                        selectors.push(selector);
+                    }
+                }
+            }
+        }
+        for selector in selectors {
+            let mut columns = HashSet::new();
+            let mut rotations = vec![];
+            for gate in self.cs.gates.iter() {
+                if self.disabled_gates.contains(gate.name()) {
+                    continue;
+                }
+                for poly in gate.polynomials() {
+                    if abstract_expr::extract_selectors(poly).contains(&selector) {
+                        for (column, rotation) in abstract_expr::extract_columns(poly) {
+                            columns.insert(column);
+                            rotations.push(rotation.0);
+                        }
+                    }
+                }
+            }
+            let min_rotation = rotations.iter().copied().min().unwrap_or(0);
+            let max_rotation = rotations.iter().copied().max().unwrap_or(0);
+
+            let mut applied = false;
+            let mut always_on = true;
+            for region in self.layouter.regions.iter() {
+                let applies = region
+                    .columns
+                    .iter()
+                    .any(|(reg_column, _)| match reg_column {
+                        RegionColumn::Selector(enabled) => *enabled == selector,
+                        RegionColumn::Column(column) => columns.contains(column),
+                    });
+                if !applies {
+                    continue;
+                }
+                let row_count = region.row_count as i32;
+                for row in 0..row_count {
+                    if row + min_rotation < 0 || row + max_rotation >= row_count {
+                        continue;
+                    }
+                    applied = true;
+                    if !region
+                        .columns
+                        .contains(&(RegionColumn::Selector(selector), Rotation(row)))
+                    {
+                        always_on = false;
+                    }
+                }
+            }
+
+            if applied && always_on {
+                count += 1;
+                self.log.log_finding(AnalyzerType::AlwaysOnSelectors, format!("always-on selector: {:?} is enabled on every row its gates apply to (consider removing it from the gates).", selector));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::AlwaysOnSelectors,
+            count,
+            "always-on selectors",
+        )
+    }
+
+    /// Detects selectors enabled one row away from where their gates fit
+    ///
+    /// For every selector, this function collects the advice cells queried by its gates, as columns and rotations.
+    /// In every region enabling the selector, the gates fit if, on each enabled row, all the queried cells are
+    /// assigned in the region. When they do not fit, but would if every enabled row were shifted by one row up or
+    /// down, the enablement is most likely off by one (for instance a gate reading `Rotation::prev()` enabled from
+    /// the first row of the region). Such selectors are logged in the `self.log` vector with the suggested rows.
+    ///
+    pub fn analyze_off_by_one_selectors(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        // Keyed by selector index, so that findings are reported in a stable order.
+        let mut queries: BTreeMap<usize, (Selector, HashSet<(Column<Any>, i32)>)> = BTreeMap::new();
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                let columns = abstract_expr::extract_columns(poly);
+                for selector in abstract_expr::extract_selectors(poly) {
+                    queries
+                        .entry(selector.0)
+                        .or_insert_with(|| (selector, HashSet::new()))
+                        .1
+                        .extend(
+                            columns
+                                .iter()
+                                .map(|(column, rotation)| (*column, rotation.0)),
+                        );
+                }
+            }
+        }
+        for region in self.layouter.regions.iter() {
+            for (selector, selector_queries) in queries.values() {
+                if selector_queries.is_empty() {
+                    continue;
+                }
+                let mut enabled_rows: Vec<i32> = region
+                    .columns
+                    .iter()
+                    .filter_map(|(reg_column, rotation)| match reg_column {
+                        RegionColumn::Selector(enabled) if enabled == selector => Some(rotation.0),
+                        _ => None,
+                    })
+                    .collect();
+                if enabled_rows.is_empty() {
+                    continue;
+                }
+                enabled_rows.sort();
+                let fits = |shift: i32| {
+                    enabled_rows.iter().all(|row| {
+                        selector_queries.iter().all(|(column, rotation)| {
+                            region.columns.contains(&(
+                                RegionColumn::Column(*column),
+                                Rotation(row + shift + rotation),
+                            ))
+                        })
+                    })
+                };
+                if fits(0) {
+                    continue;
+                }
+                for shift in [1, -1] {
+                    if fits(shift) {
+                        count += 1;
+                        let suggested_rows: Vec<i32> =
+                            enabled_rows.iter().map(|row| row + shift).collect();
+                        self.log.log_finding(AnalyzerType::OffByOneSelectors, format!("off-by-one selector: {:?} is enabled on rows {:?} of region \"{}\", where its gates read unassigned cells, but all of them are assigned on rows {:?} (shift by {:+}).", selector, enabled_rows, region.name, suggested_rows, shift));
+                        break;
+                    }
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::OffByOneSelectors,
+            count,
+            "off-by-one selectors",
+        )
+    }
+
+    /// Detects constant-propagation opportunities across gates
+    ///
+    /// A gate polynomial such as `s * (a - 5)` pins an advice cell to a constant on every row its selectors are
+    /// enabled (see `abstract_expr::pinned_advice`). For every region of the layouter (`self.layouter`), this
+    /// function computes the cells pinned this way and the other gates reading them on their enabled rows. Those
+    /// gates could be simplified by substituting the constant, and are logged in the `self.log` vector. The pass is
+    /// advisory: only polynomials guarded by a selector are considered as pins.
+    ///
+    pub fn analyze_constant_propagation(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut pins = vec![];
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                let selectors = abstract_expr::extract_selectors(poly);
+                if selectors.is_empty() {
+                    continue;
+                }
+                if let Some((column, rotation, value)) = abstract_expr::pinned_advice(poly) {
+                    pins.push((gate.name(), selectors, column, rotation, value));
+                }
+            }
+        }
+        for region in self.layouter.regions.iter() {
+            let enabled_rows = |selectors: &HashSet<Selector>| {
+                (0..region.row_count as i32)
+                    .filter(|row| {
+                        selectors.iter().all(|selector| {
+                            region
+                                .columns
+                                .contains(&(RegionColumn::Selector(*selector), Rotation(*row)))
+                        })
+                    })
+                    .collect::<Vec<i32>>()
+            };
+            for (pin_gate, pin_selectors, column, rotation, value) in pins.iter() {
+                let pinned_rows: BTreeSet<i32> = enabled_rows(pin_selectors)
+                    .into_iter()
+                    .map(|row| row + rotation)
+                    .collect();
+                if pinned_rows.is_empty() {
+                    continue;
+                }
+                for gate in self.cs.gates.iter() {
+                    if gate.name() == *pin_gate || self.disabled_gates.contains(gate.name()) {
+                        continue;
+                    }
+                    let mut rows = BTreeSet::new();
+                    for poly in gate.polynomials() {
+                        let reads: Vec<i32> = abstract_expr::extract_columns(poly)
+                            .into_iter()
+                            .filter(|(read, _)| read.index() == *column)
+                            .map(|(_, read_rotation)| read_rotation.0)
+                            .collect();
+                        for row in enabled_rows(&abstract_expr::extract_selectors(poly)) {
+                            for read_rotation in reads.iter() {
+                                if pinned_rows.contains(&(row + read_rotation)) {
+                                    rows.insert(row + read_rotation);
+                                }
+                            }
+                        }
+                    }
+                    if rows.is_empty() {
+                        continue;
+                    }
+                    count += 1;
+                    let location = self.gate_location(gate.name());
+                    self.log.log_finding(AnalyzerType::ConstantPropagation, format!("constant propagation: gate \"{}\"{} reads advice column {} on rows {:?} of region \"{}\", pinned to {} by gate \"{}\" (consider substituting the constant).", gate.name(), location, column, rows, region.name, field_to_decimal(value), pin_gate));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::ConstantPropagation,
+            count,
+            "constant-propagation opportunities",
+        )
+    }
+
+    /// Detects gates that can never be satisfied
+    ///
+    /// This function iterates through the gates in the constraint system (`self.cs`) and, for every row of every region
+    /// in the layouter (`self.layouter`), folds each polynomial with the selectors enabled at that row (see
+    /// `abstract_expr::eval_constant`). A polynomial folding to a nonzero constant does not depend on the witness, so
+    /// no witness can satisfy the gate at that row. Such gates are logged in the `self.log` vector together with the
+    /// offending region and its first offending row.
+    ///
+    pub fn analyze_always_violated_gates(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for region in self.layouter.regions.iter() {
+                let violated_row = (0..region.row_count).find(|row| {
+                    let selectors: HashSet<Selector> = region
+                        .columns
+                        .iter()
+                        .filter_map(|(column, rotation)| match column {
+                            RegionColumn::Selector(selector) if rotation.0 == *row as i32 => {
+                                Some(*selector)
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    gate.polynomials().iter().any(|poly| {
+                        abstract_expr::eval_constant(poly, &selectors)
+                            .map_or(false, |value| !bool::from(value.is_zero()))
+                    })
+                });
+
+                if let Some(row) = violated_row {
+                    count += 1;
+                    let location = self.gate_location(gate.name());
+                    self.log.log_finding(AnalyzerType::AlwaysViolatedGates, format!("always violated gate: \"{}\"{} in \"{}\" region at row {} (the polynomial is a nonzero constant, the circuit cannot be satisfied).", gate.name(), location, region.name, row));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::AlwaysViolatedGates,
+            count,
+            "always violated gates",
+        )
+    }
+
+    /// Detects constant lookup table columns
+    ///
+    /// This function iterates through the lookups in the constraint system (`self.cs`) and reads the values of every
+    /// fixed table column from the `fixed` matrix. As in the lookup encoding, a table ends at its first unassigned row.
+    /// A column holding the same value on every table row never discriminates membership, so it is logged in the
+    /// `self.log` vector as potentially removable.
+    ///
+    pub fn analyze_constant_lookup_columns(
+        &mut self,
+        fixed: &[Vec<CellValue<F>>],
+    ) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut checked = HashSet::new();
+        for (lookup_no, lookup) in self.cs.lookups.iter().enumerate() {
+            for table_expression in lookup.table_expressions.iter() {
+                if let Expression::Fixed(fixed_query) = table_expression {
+                    let column = fixed_query.column_index;
+                    if !checked.insert(column) {
+                        continue;
+                    }
+                    let values: Vec<F> = fixed
+                        .get(column)
+                        .context("Fixed column of lookup table is missing!")?
+                        .iter()
+                        .map_while(|cell| match cell {
+                            CellValue::Assigned(value) => Some(*value),
+                            _ => None,
+                        })
+                        .collect();
+
+                    if values.len() > 1 && values.iter().all(|value| *value == values[0]) {
+                        count += 1;
+                        self.log.log_finding(AnalyzerType::ConstantLookupColumns, format!("constant lookup table column: fixed column {} of lookup {} holds {} on all {} table rows (consider removing it from the table).", column, lookup_no, field_to_decimal(&values[0]), values.len()));
+                    }
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::ConstantLookupColumns,
+            count,
+            "constant lookup table columns",
+        )
+    }
+    /// Detects inert gates
+    ///
+    /// This function iterates through the gates in the constraint system (`self.cs`) and looks for a multiplicative
+    /// factor (see `abstract_expr::extract_factors`) of each polynomial that is a fixed column holding zero on every
+    /// row of the `fixed` matrix. Regions are laid out without absolute offsets, so the whole column is checked.
+    /// A gate whose polynomials all have such a factor never constrains anything, and is logged in the `self.log`
+    /// vector.
+    ///
+    pub fn analyze_inert_gates(&mut self, fixed: &[Vec<CellValue<F>>]) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) || gate.polynomials().is_empty() {
+                continue;
+            }
+            let zero_columns: Option<BTreeSet<usize>> = gate
+                .polynomials()
+                .iter()
+                .map(|poly| {
+                    abstract_expr::extract_factors(poly)
+                        .into_iter()
+                        .find_map(|factor| match factor {
+                            Expression::Fixed(fixed_query) => fixed
+                                .get(fixed_query.column_index)
+                                .filter(|cells| {
+                                    cells.iter().all(|cell| match cell {
+                                        CellValue::Unassigned => true,
+                                        CellValue::Assigned(value) => value.is_zero().into(),
+                                        _ => false,
+                                    })
+                                })
+                                .map(|_| fixed_query.column_index),
+                            _ => None,
+                        })
+                })
+                .collect();
+
+            if let Some(zero_columns) = zero_columns {
+                count += 1;
+                let location = self.gate_location(gate.name());
+                self.log.log_finding(AnalyzerType::InertGates, format!("inert gate: \"{}\"{} is multiplied by fixed column(s) {:?}, which are zero on every row (the gate never constrains anything).", gate.name(), location, zero_columns));
+            }
+        }
+        self.finish_pass(AnalyzerOutputStatus::InertGates, count, "inert gates")
+    }
+    /// Detects lookups that never fail
+    ///
+    /// This function iterates through the single-column lookups in the constraint system (`self.cs`) whose input is
+    /// an advice query, possibly guarded by selectors, and whose table is a fixed column. The plausible domain of the
+    /// input is the range set for its column with `with_witness_bound`; inputs without bounds range over the whole
+    /// field, which no table covers. When the values of the table, read from the `fixed` matrix up to its first
+    /// unassigned row, contain every value of that range, the lookup does not constrain the input and is logged in
+    /// the `self.log` vector.
+    ///
+    /// The pass is opt-in: a table covering the whole field cannot exist, so lookups on columns without a bound are
+    /// never reported. Without any bound, nothing is checked and a reminder is printed unless `quiet` is set.
+    ///
+    pub fn analyze_non_constraining_lookups(
+        &mut self,
+        fixed: &[Vec<CellValue<F>>],
+    ) -> Result<AnalyzerOutput> {
+        if self.witness_bounds.is_empty() && !self.quiet {
+            println!("No witness bounds set, see `with_witness_bound`: lookups cannot be checked.");
+        }
+        let mut count = 0;
+        for (lookup_no, lookup) in self.cs.lookups.iter().enumerate() {
+            if lookup.input_expressions.len() != 1 {
+                continue;
+            }
+            let table_column = match &lookup.table_expressions[0] {
+                Expression::Fixed(fixed_query) => fixed_query.column_index,
+                _ => continue,
+            };
+            let mut advice_columns = vec![];
+            let mut guarded_only = true;
+            for factor in abstract_expr::extract_factors(&lookup.input_expressions[0]) {
+                match factor {
+                    Expression::Advice(advice_query) => {
+                        advice_columns.push(advice_query.column_index)
+                    }
+                    Expression::Selector(_) => {}
+                    _ => guarded_only = false,
+                }
+            }
+            let input_column = match advice_columns.as_slice() {
+                [column] if guarded_only => *column,
+                _ => continue,
+            };
+            let bits = match self.witness_bounds.get(&input_column) {
+                Some(bits) if *bits < 64 => *bits,
+                _ => continue,
+            };
+
+            let values: BTreeSet<u128> = fixed
+                .get(table_column)
+                .context("Fixed column of lookup table is missing!")?
+                .iter()
+                .map_while(|cell| match cell {
+                    CellValue::Assigned(value) => Some(*value),
+                    _ => None,
+                })
+                .filter_map(|value| {
+                    let lower = value.get_lower_128();
+                    (F::from_u128(lower) == value && lower < 1 << bits).then_some(lower)
+                })
+                .collect();
+            if values.len() as u128 == 1 << bits {
+                count += 1;
+                self.log.log_finding(AnalyzerType::NonConstrainingLookups, format!("non-constraining lookup: lookup {} checks advice column {} against fixed column {}, which covers the whole {}-bit range of the column (the lookup never fails).", lookup_no, input_column, table_column, bits));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::NonConstrainingLookups,
+            count,
+            "non-constraining lookups",
+        )
+    }
+    /// Detects gate constants drifting from lookup table values
+    ///
+    /// A gate and a lookup share a context when the gate queries an advice column appearing in an input of the
+    /// lookup. For every such pair, the constants of the gate (see `abstract_expr::extract_constants`) are compared
+    /// with the values of the corresponding table column, read from the `fixed` matrix up to its first unassigned
+    /// row. A constant missing from the table but one away from one of its values, such as `16` next to a nibble
+    /// table, is likely a copy-paste drift and is logged in the `self.log` vector. This is a heuristic: `0` and `1`
+    /// are too common to be compared.
+    ///
+    pub fn analyze_constant_drift(
+        &mut self,
+        fixed: &[Vec<CellValue<F>>],
+    ) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for (lookup_no, lookup) in self.cs.lookups.iter().enumerate() {
+            for (input, table) in lookup
+                .input_expressions
+                .iter()
+                .zip(lookup.table_expressions.iter())
+            {
+                let table_column = match table {
+                    Expression::Fixed(fixed_query) => fixed_query.column_index,
+                    _ => continue,
+                };
+                let values: Vec<F> = fixed
+                    .get(table_column)
+                    .context("Fixed column of lookup table is missing!")?
+                    .iter()
+                    .map_while(|cell| match cell {
+                        CellValue::Assigned(value) => Some(*value),
+                        _ => None,
+                    })
+                    .collect();
+                let input_columns = abstract_expr::extract_columns(input);
+                for gate in self.cs.gates.iter() {
+                    if self.disabled_gates.contains(gate.name()) {
+                        continue;
+                    }
+                    let shares_context = gate.polynomials().iter().any(|poly| {
+                        abstract_expr::extract_columns(poly)
+                            .iter()
+                            .any(|(column, _)| {
+                                input_columns
+                                    .iter()
+                                    .any(|(input_column, _)| input_column == column)
+                            })
+                    });
+                    if !shares_context {
+                        continue;
+                    }
+                    let mut constants: Vec<F> = vec![];
+                    for poly in gate.polynomials() {
+                        for constant in abstract_expr::extract_constants(poly) {
+                            if !constants.contains(&constant) {
+                                constants.push(constant);
+                            }
+                        }
+                    }
+                    for constant in constants {
+                        if constant == F::zero()
+                            || constant == F::one()
+                            || values.contains(&constant)
+                        {
+                            continue;
+                        }
+                        let neighbour = [constant - F::one(), constant + F::one()]
+                            .into_iter()
+                            .find(|neighbour| values.contains(neighbour));
+                        if let Some(neighbour) = neighbour {
+                            count += 1;
+                            let location = self.gate_location(gate.name());
+                            self.log.log_finding(AnalyzerType::ConstantDrift, format!("constant drift: gate \"{}\"{} uses the constant {}, which lookup {} table column {} lacks although it contains {} (check that the constant matches the table).", gate.name(), location, field_to_decimal(&constant), lookup_no, table_column, field_to_decimal(&neighbour)));
+                        }
+                    }
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::ConstantDrift,
+            count,
+            "drifted constants",
+        )
+    }
+    /// Detects lookup inputs that no gate range-checks
+    ///
+    /// This function iterates through the lookups in the constraint system (`self.cs`) whose inputs are advice
+    /// queries, possibly guarded by selectors. An input column is considered range-checked if some gate polynomial
+    /// is, besides selectors and constants, a product of at least two factors linear in the same query of that
+    /// column, such as `s * a * (1 - a)` (see `abstract_expr::pinned_advice`). Lookups often assume their inputs to
+    /// be in a range; when nothing else enforces it, out-of-domain inputs only rely on the lookup, and the input is
+    /// logged in the `self.log` vector. This is a heuristic: range checks by decomposition are not recognized.
+    ///
+    pub fn analyze_unranged_lookup_inputs(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut ranged_columns = BTreeSet::new();
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                let roots: Vec<Option<(usize, i32, F)>> = abstract_expr::extract_factors(poly)
+                    .into_iter()
+                    .filter(|factor| {
+                        !matches!(factor, Expression::Selector(_) | Expression::Constant(_))
+                    })
+                    .map(abstract_expr::pinned_advice)
+                    .collect();
+                if let [Some((column, rotation, _)), rest @ ..] = roots.as_slice() {
+                    let same_query = |root: &Option<(usize, i32, F)>| match root {
+                        Some((other_column, other_rotation, _)) => {
+                            other_column == column && other_rotation == rotation
+                        }
+                        None => false,
+                    };
+                    if !rest.is_empty() && rest.iter().all(same_query) {
+                        ranged_columns.insert(*column);
+                    }
+                }
+            }
+        }
+        for (lookup_no, lookup) in self.cs.lookups.iter().enumerate() {
+            let mut input_columns = BTreeSet::new();
+            for input in lookup.input_expressions.iter() {
+                let mut advice_columns = vec![];
+                let mut guarded_only = true;
+                for factor in abstract_expr::extract_factors(input) {
+                    match factor {
+                        Expression::Advice(advice_query) => {
+                            advice_columns.push(advice_query.column_index)
+                        }
+                        Expression::Selector(_) => {}
+                        _ => guarded_only = false,
+                    }
+                }
+                if let [column] = advice_columns.as_slice() {
+                    if guarded_only {
+                        input_columns.insert(*column);
+                    }
+                }
+            }
+            for column in input_columns {
+                if !ranged_columns.contains(&column) {
+                    count += 1;
+                    self.log.log_finding(AnalyzerType::UnrangedLookupInputs, format!("unranged lookup input: lookup {} reads advice column {}, which no gate range-checks (out-of-domain values only rely on the lookup).", lookup_no, column));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::UnrangedLookupInputs,
+            count,
+            "unranged lookup inputs",
+        )
+    }
+    /// Detects advice columns constrained identically in every region
+    ///
+    /// The constraint footprint of a column in a region is the set of gates querying the column whose selectors are
+    /// enabled in the region. A column assigned in several regions with the same non-empty footprint in all of them
+    /// satisfies a global invariant, and might be better modeled as a global or fixed value. Such columns are logged
+    /// in the `self.log` vector.
+    ///
+    pub fn analyze_uniform_columns(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut footprints: BTreeMap<usize, Vec<BTreeSet<&str>>> = BTreeMap::new();
+        for region in self.layouter.regions.iter() {
+            let enabled: HashSet<Selector> = region.selectors().into_iter().collect();
+            let columns: HashSet<Column<Any>> = region
+                .columns
+                .iter()
+                .filter_map(|(column, _)| match column {
+                    RegionColumn::Column(column) if *column.column_type() == Any::Advice => {
+                        Some(*column)
+                    }
+                    _ => None,
+                })
+                .collect();
+            for column in columns {
+                let mut footprint = BTreeSet::new();
+                for gate in self.cs.gates.iter() {
+                    if self.disabled_gates.contains(gate.name()) {
+                        continue;
+                    }
+                    let constrains = gate.polynomials().iter().any(|poly| {
+                        abstract_expr::extract_selectors(poly).is_subset(&enabled)
+                            && abstract_expr::extract_columns(poly)
+                                .iter()
+                                .any(|(query, _)| *query == column)
+                    });
+                    if constrains {
+                        footprint.insert(gate.name());
+                    }
+                }
+                footprints
+                    .entry(column.index())
+                    .or_default()
+                    .push(footprint);
+            }
+        }
+        for (column, footprints) in footprints {
+            if footprints.len() < 2 || footprints[0].is_empty() {
+                continue;
+            }
+            if footprints
+                .iter()
+                .all(|footprint| *footprint == footprints[0])
+            {
+                count += 1;
+                self.log.log_finding(AnalyzerType::UniformColumns, format!("uniform column: advice column {} is constrained by the same gates {:?} in all the {} regions it is assigned in (consider modeling it as a global or fixed value).", column, footprints[0], footprints.len()));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::UniformColumns,
+            count,
+            "uniform columns",
+        )
+    }
+    /// Detects gates whose selectors are only enabled in regions that assign none of their columns
+    ///
+    /// A gate constrains the cells of the regions enabling its selectors. If every region enabling a selector of the
+    /// gate assigns none of the advice columns the gate queries, the gate only ever applies to cells that are never
+    /// assigned, so it is effectively dead. Such gates are logged in the `self.log` vector. Selectors never enabled
+    /// are left to `analyze_unused_custom_gates`.
+    ///
+    pub fn analyze_orphaned_selectors(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            let mut columns = HashSet::new();
+            let mut selectors = BTreeSet::new();
+            for poly in gate.polynomials() {
+                columns.extend(
+                    abstract_expr::extract_columns(poly)
+                        .into_iter()
+                        .map(|(column, _)| column),
+                );
+                selectors.extend(
+                    abstract_expr::extract_selectors(poly)
+                        .into_iter()
+                        .map(|selector| selector.0),
+                );
+            }
+            if columns.is_empty() {
+                continue;
+            }
+            for selector in selectors {
+                let enabling: Vec<_> = self
+                    .layouter
+                    .regions
+                    .iter()
+                    .filter(|region| {
+                        region
+                            .selectors()
+                            .iter()
+                            .any(|enabled| enabled.0 == selector)
+                    })
+                    .collect();
+                let orphaned = !enabling.is_empty()
+                    && enabling.iter().all(|region| {
+                        !region.columns.iter().any(|(column, _)| {
+                            matches!(column, RegionColumn::Column(column) if columns.contains(column))
+                        })
+                    });
+                if orphaned {
+                    let regions: Vec<&str> =
+                        enabling.iter().map(|region| region.name.as_str()).collect();
+                    let mut indices: Vec<usize> =
+                        columns.iter().map(|column| column.index()).collect();
+                    indices.sort();
+                    count += 1;
+                    let location = self.gate_location(gate.name());
+                    self.log.log_finding(AnalyzerType::OrphanedSelectors, format!("orphaned selector: gate \"{}\"{} uses Selector({}), which is only enabled in regions {:?} that assign none of its advice columns {:?}, so the gate never constrains its cells.", gate.name(), location, selector, regions, indices));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::OrphanedSelectors,
+            count,
+            "orphaned selectors",
+        )
+    }
+    /// Detects regions enabling selectors that a gate forbids to be enabled together
+    ///
+    /// A polynomial that is a product of selectors alone, e.g. `s1 * s2`, makes the selectors mutually exclusive:
+    /// they can never be enabled on the same row. A region enabling all of them on a row cannot be satisfied by any
+    /// witness. Such regions are logged in the `self.log` vector.
+    ///
+    pub fn analyze_mutually_exclusive_selectors(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                let monomials = abstract_expr::canonicalize(poly);
+                if monomials.len() != 1 {
+                    continue;
+                }
+                let selectors: Option<BTreeSet<usize>> = monomials
+                    .keys()
+                    .next()
+                    .unwrap()
+                    .iter()
+                    .map(|wire| {
+                        wire.strip_prefix("sel")
+                            .and_then(|index| index.parse().ok())
+                    })
+                    .collect();
+                let selectors = match selectors {
+                    Some(selectors) if selectors.len() >= 2 => selectors,
+                    _ => continue,
+                };
+                for region in self.layouter.regions.iter() {
+                    let mut enabled_rows: BTreeMap<usize, BTreeSet<i32>> = BTreeMap::new();
+                    for (column, rotation) in region.columns.iter() {
+                        if let RegionColumn::Selector(selector) = column {
+                            if selectors.contains(&selector.0) {
+                                enabled_rows
+                                    .entry(selector.0)
+                                    .or_default()
+                                    .insert(rotation.0);
+                            }
+                        }
+                    }
+                    if enabled_rows.len() < selectors.len() {
+                        continue;
+                    }
+                    let rows: Vec<i32> = enabled_rows[selectors.iter().next().unwrap()]
+                        .iter()
+                        .filter(|row| enabled_rows.values().all(|rows| rows.contains(row)))
+                        .copied()
+                        .collect();
+                    if rows.is_empty() {
+                        continue;
+                    }
+                    count += 1;
+                    let location = self.gate_location(gate.name());
+                    self.log.log_finding(AnalyzerType::MutuallyExclusiveSelectors, format!("mutually exclusive selectors: region \"{}\" enables selectors {:?} together on rows {:?}, which gate \"{}\"{} forbids, so no witness satisfies the region.", region.name, selectors, rows, gate.name(), location));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::MutuallyExclusiveSelectors,
+            count,
+            "regions enabling mutually exclusive selectors",
+        )
+    }
+    /// Returns, for each region assigning advice cells, its name, the number of those cells reached by a gate and
+    /// the number of those cells.
+    ///
+    /// A cell is reached by a gate if a polynomial of a gate, whose selectors are all enabled on some row of the
+    /// region, queries the cell from that row. Cells copy-constrained to a reached cell are reached as well.
+    ///
+    fn constrained_cell_counts(&self) -> Vec<(String, usize, usize)> {
+        let mut constrained = HashSet::new();
+        for (region_no, region) in self.layouter.regions.iter().enumerate() {
+            let mut enabled_rows: HashMap<Selector, HashSet<i32>> = HashMap::new();
+            for (column, rotation) in region.columns.iter() {
+                if let RegionColumn::Selector(selector) = column {
+                    enabled_rows
+                        .entry(*selector)
+                        .or_default()
+                        .insert(rotation.0);
+                }
+            }
+            for gate in self.cs.gates.iter() {
+                if self.disabled_gates.contains(gate.name()) {
+                    continue;
+                }
+                for poly in gate.polynomials() {
+                    let selectors = abstract_expr::extract_selectors(poly);
+                    let live_rows = (0..region.row_count as i32).filter(|row| {
+                        selectors.iter().all(|selector| {
+                            enabled_rows
+                                .get(selector)
+                                .map_or(false, |rows| rows.contains(row))
+                        })
+                    });
+                    let queries = abstract_expr::extract_columns(poly);
+                    for row in live_rows {
+                        for (column, rotation) in queries.iter() {
+                            constrained.insert(CellLocation {
+                                kind: CellKind::Advice,
+                                region: Some(region_no),
+                                column: column.index(),
+                                row: row + rotation.0,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let copies: Vec<(CellLocation, CellLocation)> = self
+            .layouter
+            .regions
+            .iter()
+            .flat_map(|region| region.advice_eq_table.iter().chain(region.eq_table.iter()))
+            .chain(self.layouter.eq_table.iter())
+            .filter_map(|(left, right)| {
+                Some((
+                    name_formatter::parse_default_name(left)?,
+                    name_formatter::parse_default_name(right)?,
+                ))
+            })
+            .collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (left, right) in copies.iter() {
+                if constrained.contains(left) != constrained.contains(right) {
+                    constrained.insert(*left);
+                    constrained.insert(*right);
+                    changed = true;
+                }
+            }
+        }
+
+        let mut counts = vec![];
+        for (region_no, region) in self.layouter.regions.iter().enumerate() {
+            let assigned: HashSet<CellLocation> = region
+                .assignments
+                .iter()
+                .filter(|(column, _)| *column.column_type() == Any::Advice)
+                .map(|(column, row)| CellLocation {
+                    kind: CellKind::Advice,
+                    region: Some(region_no),
+                    column: column.index(),
+                    row: *row as i32,
+                })
+                .collect();
+            if assigned.is_empty() {
+                continue;
+            }
+            let reached = assigned
+                .iter()
+                .filter(|cell| constrained.contains(*cell))
+                .count();
+            counts.push((region.name.clone(), reached, assigned.len()));
+        }
+        counts
+    }
+
+    /// Returns the constraint density of each region assigning advice cells, with the name of the region.
+    ///
+    /// The density is the fraction of the advice cells assigned in the region that are reached by a gate (see
+    /// `analyze_sparse_regions`), from 0 for a region no gate constrains to 1.
+    ///
+    pub fn constraint_density(&self) -> Vec<(String, f64)> {
+        self.constrained_cell_counts()
+            .into_iter()
+            .map(|(name, reached, assigned)| (name, reached as f64 / assigned as f64))
+            .collect()
+    }
+
+    /// Detects regions whose cells are sparsely constrained
+    ///
+    /// An advice cell is reached by a gate if a polynomial of a gate, whose selectors are all enabled on some row of
+    /// the region, queries the cell from that row, or if the cell is copy-constrained to a reached cell. Regions
+    /// where the fraction of assigned advice cells reached by a gate (see `constraint_density`) is below `threshold`
+    /// are logged in the `self.log` vector: most of their cells can take any value.
+    ///
+    pub fn analyze_sparse_regions(&mut self, threshold: f64) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for (name, reached, assigned) in self.constrained_cell_counts() {
+            let density = reached as f64 / assigned as f64;
+            if density >= threshold {
+                continue;
+            }
+            count += 1;
+            self.log.log_finding(AnalyzerType::SparseRegions, format!("sparse region: only {} of the {} advice cells assigned in region \"{}\" are reached by a gate (density {:.2}, threshold {:.2}).", reached, assigned, name, density, threshold));
+        }
+        self.finish_pass(AnalyzerOutputStatus::SparseRegions, count, "sparse regions")
+    }
+    /// Detects instance columns whose coefficient folds to zero in every gate using them
+    ///
+    /// Each polynomial is canonicalized (see `abstract_expr::canonicalize`), which folds literal zero scalings such
+    /// as `0 * i`, and the monomials reading a fixed column assigned only zeros in the `fixed` matrix are dropped. An
+    /// instance column read by gates, but by none of the remaining monomials nor bound by a copy constraint, is a
+    /// public input the circuit ignores. Such columns are logged in the `self.log` vector.
+    ///
+    pub fn analyze_dead_instances(
+        &mut self,
+        fixed: &[Vec<CellValue<F>>],
+    ) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let zero_columns: HashSet<usize> = fixed
+            .iter()
+            .enumerate()
+            .filter(|(_, cells)| {
+                cells.iter().all(|cell| match cell {
+                    CellValue::Unassigned => true,
+                    CellValue::Assigned(value) => *value == F::zero(),
+                    CellValue::Poison(_) => false,
+                })
+            })
+            .map(|(column, _)| column)
+            .collect();
+        let is_zero_wire = |wire: &String| {
+            wire.strip_prefix("fix")
+                .and_then(|wire| wire.split('[').next())
+                .and_then(|column| column.parse::<usize>().ok())
+                .map_or(false, |column| zero_columns.contains(&column))
+        };
+
+        let mut appearances: BTreeMap<usize, BTreeSet<&str>> = BTreeMap::new();
+        let mut live = HashSet::new();
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                let columns: BTreeSet<usize> = abstract_expr::extract_instance_columns(poly)
+                    .into_iter()
+                    .map(|(column, _)| column.index())
+                    .collect();
+                if columns.is_empty() {
+                    continue;
+                }
+                let monomials = abstract_expr::canonicalize(poly);
+                for column in columns {
+                    appearances.entry(column).or_default().insert(gate.name());
+                    let prefix = format!("inst{}[", column);
+                    let read = monomials.keys().any(|wires| {
+                        !wires.iter().any(is_zero_wire)
+                            && wires.iter().any(|wire| wire.starts_with(&prefix))
+                    });
+                    if read {
+                        live.insert(column);
+                    }
+                }
+            }
+        }
+        // Public inputs bound to advice cells are used, whatever the gates do.
+        let copies = self
+            .layouter
+            .regions
+            .iter()
+            .flat_map(|region| region.advice_eq_table.iter().chain(region.eq_table.iter()))
+            .chain(self.layouter.eq_table.iter());
+        for (left, right) in copies {
+            for name in [left, right] {
+                if let Some(location) = name_formatter::parse_default_name(name) {
+                    if location.kind == CellKind::Instance {
+                        live.insert(location.column);
+                    }
+                }
+            }
+        }
+
+        for (column, gates) in appearances {
+            if live.contains(&column) {
+                continue;
+            }
+            count += 1;
+            self.log.log_finding(AnalyzerType::DeadInstances, format!("dead public input: instance column {} is only read by gates {:?} with a coefficient that folds to zero, so the circuit ignores it.", column, gates));
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::DeadInstances,
+            count,
+            "dead public inputs",
+        )
+    }
+    /// Detects regions enabling the selector of a gate without assigning the columns the gate queries
+    ///
+    /// A gate constrains the cells of every region enabling one of its selectors. A region enabling the selector but
+    /// not assigning some advice column the gate queries lets the gate bleed into it: the gate then constrains
+    /// cells the region never assigned, which default to zero, typically because the selector was meant for another
+    /// region. Such regions are logged in the `self.log` vector. Selectors only enabled in regions assigning none of
+    /// the columns are left to `analyze_orphaned_selectors`.
+    ///
+    pub fn analyze_selector_bleed(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            let mut columns = BTreeSet::new();
+            let mut selectors = BTreeSet::new();
+            for poly in gate.polynomials() {
+                columns.extend(
+                    abstract_expr::extract_columns(poly)
+                        .into_iter()
+                        .map(|(column, _)| column.index()),
+                );
+                selectors.extend(
+                    abstract_expr::extract_selectors(poly)
+                        .into_iter()
+                        .map(|selector| selector.0),
+                );
+            }
+            if columns.is_empty() {
+                continue;
+            }
+            for selector in selectors {
+                let enabling: Vec<(&str, BTreeSet<usize>)> = self
+                    .layouter
+                    .regions
+                    .iter()
+                    .filter(|region| {
+                        region
+                            .selectors()
+                            .iter()
+                            .any(|enabled| enabled.0 == selector)
+                    })
+                    .map(|region| {
+                        let assigned: BTreeSet<usize> = region
+                            .columns
+                            .iter()
+                            .filter_map(|(column, _)| match column {
+                                RegionColumn::Column(column)
+                                    if *column.column_type() == Any::Advice =>
+                                {
+                                    Some(column.index())
+                                }
+                                _ => None,
+                            })
+                            .collect();
+                        let missing = columns.difference(&assigned).copied().collect();
+                        (region.name.as_str(), missing)
+                    })
+                    .collect();
+                if enabling
+                    .iter()
+                    .all(|(_, missing)| missing.len() == columns.len())
+                {
+                    continue;
+                }
+                for (region, missing) in enabling {
+                    if missing.is_empty() {
+                        continue;
+                    }
+                    count += 1;
+                    let location = self.gate_location(gate.name());
+                    self.log.log_finding(AnalyzerType::SelectorBleed, format!("selector bleed: region \"{}\" enables Selector({}) of gate \"{}\"{} but does not assign its advice columns {:?}, so the gate constrains cells the region never assigned.", region, selector, gate.name(), location, missing));
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::SelectorBleed,
+            count,
+            "selector bleeds",
+        )
+    }
+    /// Detects no-op gates
+    ///
+    /// This function iterates through the gates in the constraint system (`self.cs`) and canonicalizes each
+    /// polynomial (see `abstract_expr::canonicalize`). A polynomial canonicalizing to the zero polynomial, such as
+    /// `s * (x - x)`, holds whatever the witness and the selectors, so it constrains nothing. Unlike the gates found
+    /// by `analyze_unused_custom_gates`, this does not depend on where selectors are enabled. Gates with such
+    /// polynomials are logged in the `self.log` vector.
+    ///
+    pub fn analyze_noop_gates(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            let zero_polynomials: Vec<usize> = gate
+                .polynomials()
+                .iter()
+                .enumerate()
+                .filter(|(_, poly)| abstract_expr::canonicalize(poly).is_empty())
+                .map(|(poly_no, _)| poly_no)
+                .collect();
+
+            if !zero_polynomials.is_empty() {
+                count += 1;
+                let location = self.gate_location(gate.name());
+                self.log.log_finding(AnalyzerType::NoopGates, format!("no-op gate: \"{}\"{} has polynomial(s) {:?} that are identically zero (they constrain nothing).", gate.name(), location, zero_polynomials));
+            }
+        }
+        self.finish_pass(AnalyzerOutputStatus::NoopGates, count, "no-op gates")
+    }
+    /// Detects gates that could be split with helper columns
+    ///
+    /// This function iterates through the gates in the constraint system (`self.cs`) and, for each polynomial of
+    /// degree higher than `target_degree`, suggests a split point (see `abstract_expr::suggest_split`): the
+    /// highest-degree subexpression that, computed in a helper advice column, lowers the degree of the polynomial.
+    /// The suggestions are logged in the `self.log` vector with their cost, one advice column and one gate each.
+    /// They are advisory: a lower maximal degree shrinks the proving domain, at the price of the extra column.
+    ///
+    pub fn analyze_high_degree_gates(&mut self, target_degree: usize) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for (poly_no, poly) in gate.polynomials().iter().enumerate() {
+                let degree = poly.degree();
+                if degree <= target_degree {
+                    continue;
+                }
+                count += 1;
+                let location = self.gate_location(gate.name());
+                match abstract_expr::suggest_split(poly) {
+                    Some(split) => {
+                        self.log.log_finding(AnalyzerType::HighDegreeGates, format!("high-degree gate: \"{}\"{} polynomial {} has degree {} (target {}); computing {} in a helper advice column would split it into gates of degree {} and {} (cost: 1 advice column, 1 gate).", gate.name(), location, poly_no, degree, target_degree, lean::render_expression(split.subexpression), split.remaining_degree, split.helper_degree));
+                    }
+                    None => {
+                        self.log.log_finding(AnalyzerType::HighDegreeGates, format!("high-degree gate: \"{}\"{} polynomial {} has degree {} (target {}), and no single helper column lowers it.", gate.name(), location, poly_no, degree, target_degree));
+                    }
+                }
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::HighDegreeGates,
+            count,
+            "high-degree polynomials",
+        )
+    }
+    /// Detects gates that are trivial once fixed values are known
+    ///
+    /// This function iterates through the gates in the constraint system (`self.cs`) that query fixed columns. For
+    /// every row of the `fixed` matrix, the fixed queries of the gate are substituted with the values they read
+    /// (rotations wrap around, as in the proving system, and unassigned cells read zero) and the polynomials are
+    /// canonicalized (see `abstract_expr::canonicalize`). A gate whose polynomials become the zero polynomial on
+    /// every row, such as `f * (a - b) + g * a` with `f` and `g` all zero, never constrains anything and is logged in
+    /// the `self.log` vector. Rows reading poisoned cells are conservatively considered constraining.
+    ///
+    pub fn analyze_fixed_trivial_gates(
+        &mut self,
+        fixed: &[Vec<CellValue<F>>],
+    ) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let rows = fixed.iter().map(Vec::len).max().unwrap_or(0);
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) || rows == 0 {
+                continue;
+            }
+            let queries: Vec<(usize, i32)> = gate
+                .polynomials()
+                .iter()
+                .flat_map(abstract_expr::extract_fixed_queries)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            if queries.is_empty() {
+                continue;
+            }
+            let mut trivial = true;
+            // Rows reading the same fixed values give the same polynomials, so they are only canonicalized once.
+            let mut checked: Vec<Vec<F>> = vec![];
+            for row in 0..rows {
+                let values: Option<Vec<F>> = queries
+                    .iter()
+                    .map(|(column, rotation)| {
+                        let row = (row as i64 + *rotation as i64).rem_euclid(rows as i64) as usize;
+                        match fixed.get(*column).and_then(|cells| cells.get(row)) {
+                            None | Some(CellValue::Unassigned) => Some(F::zero()),
+                            Some(CellValue::Assigned(value)) => Some(*value),
+                            Some(_) => None,
+                        }
+                    })
+                    .collect();
+                let values = match values {
+                    Some(values) => values,
+                    None => {
+                        trivial = false;
+                        break;
+                    }
+                };
+                if checked.contains(&values) {
+                    continue;
+                }
+                let value = |column: usize, rotation: i32| {
+                    queries
+                        .iter()
+                        .position(|query| *query == (column, rotation))
+                        .map(|index| values[index])
+                };
+                let constraining = gate.polynomials().iter().any(|poly| {
+                    abstract_expr::substitute_fixed(poly, &value)
+                        .map_or(true, |poly| !abstract_expr::canonicalize(&poly).is_empty())
+                });
+                if constraining {
+                    trivial = false;
+                    break;
+                }
+                checked.push(values);
+            }
+
+            if trivial {
+                count += 1;
+                let location = self.gate_location(gate.name());
+                self.log.log_finding(AnalyzerType::FixedTrivialGates, format!("fixed-trivial gate: \"{}\"{} is identically zero on every row once the fixed columns are substituted (the gate never constrains anything).", gate.name(), location));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::FixedTrivialGates,
+            count,
+            "fixed-trivial gates",
+        )
+    }
+    /// Detects boolean fixed columns
+    ///
+    /// This function scans the `fixed` matrix for the fixed columns of the constraint system (`self.cs`) that only
+    /// hold 0 and 1 (unassigned cells read zero) and hold 1 on at least one row. Such a column behaves like a
+    /// selector: it might be intended as one, in which case declaring it with `selector()` lets halo2 compress it
+    /// with the other selectors. Columns used in lookup tables are skipped, since selectors cannot replace them.
+    /// The candidates are logged in the `self.log` vector.
+    ///
+    pub fn analyze_boolean_fixed_columns(
+        &mut self,
+        fixed: &[Vec<CellValue<F>>],
+    ) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let table_columns: HashSet<usize> = self
+            .cs
+            .lookups
+            .iter()
+            .flat_map(|lookup| lookup.table_expressions.iter())
+            .filter_map(|table_expression| match table_expression {
+                Expression::Fixed(fixed_query) => Some(fixed_query.column_index),
+                _ => None,
+            })
+            .collect();
+        for column in 0..self.cs.num_fixed_columns() {
+            if table_columns.contains(&column) {
+                continue;
+            }
+            let cells = match fixed.get(column) {
+                Some(cells) => cells,
+                None => continue,
+            };
+            let boolean = cells.iter().all(|cell| match cell {
+                CellValue::Unassigned => true,
+                CellValue::Assigned(value) => *value == F::zero() || *value == F::one(),
+                _ => false,
+            });
+            let enabled_rows = cells
+                .iter()
+                .filter(|cell| matches!(cell, CellValue::Assigned(value) if *value == F::one()))
+                .count();
+
+            if boolean && enabled_rows > 0 {
+                count += 1;
+                self.log.log_finding(AnalyzerType::BooleanFixedColumns, format!("boolean fixed column: fixed column {} only holds 0 and 1 (1 on {} row(s)), so it behaves like a selector (consider declaring it as one).", column, enabled_rows));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::BooleanFixedColumns,
+            count,
+            "boolean fixed columns",
+        )
+    }
+    /// Detects linear-only advice columns
+    ///
+    /// This function iterates through the advice columns queried in the constraint system (`self.cs`) and computes
+    /// the maximum witness degree (see `abstract_expr::witness_degree`) of the gate polynomials each column appears in.
+    /// Columns that only appear in degree-1 polynomials are logged in the `self.log` vector as candidates for
+    /// consolidation. Columns appearing in no gate are left to `analyze_unused_columns`.
+    ///
+    pub fn analyze_linear_columns(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut columns = vec![];
+        for (column, _) in self.cs.advice_queries.iter() {
+            if !columns.contains(column) {
+                columns.push(*column);
+            }
+        }
+        for column in columns {
+            let column_any: Column<Any> = column.into();
+            let mut max_degree = None;
+            for gate in self.cs.gates.iter() {
+                if self.disabled_gates.contains(gate.name()) {
+                    continue;
+                }
+                for poly in gate.polynomials() {
+                    let advices = abstract_expr::extract_columns(poly);
+                    if advices.iter().any(|(c, _)| *c == column_any) {
+                        let degree = abstract_expr::witness_degree(poly);
+                        max_degree = Some(max_degree.map_or(degree, |max: usize| max.max(degree)));
+                    }
+                }
+            }
+
+            if max_degree == Some(1) {
+                count += 1;
+                self.log.log_finding(AnalyzerType::LinearColumns, format!("linear-only column: {:?} (only appears in linear constraints, consider folding it into another column)", column));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::LinearColumns,
+            count,
+            "linear-only columns",
+        )
+    }
+
+    /// Detects naming collisions
+    ///
+    /// Findings refer to gates and regions by name, so two gates sharing a name, or a region named like a gate,
+    /// make reports ambiguous (and would clash in any SMT encoding keyed by names). This function logs every gate
+    /// name used more than once and every region name equal to a gate name in the `self.log` vector.
+    /// Columns are not checked: they carry no names in the constraint system and are reported by index.
+    ///
+    pub fn analyze_naming_collisions(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        let mut gate_names: HashMap<&str, usize> = HashMap::new();
+        for gate in self.cs.gates.iter() {
+            *gate_names.entry(gate.name()).or_insert(0) += 1;
+        }
+        let mut duplicates: Vec<(&str, usize)> = gate_names
+            .iter()
+            .filter(|(_, occurrences)| **occurrences > 1)
+            .map(|(name, occurrences)| (*name, *occurrences))
+            .collect();
+        duplicates.sort();
+        for (name, occurrences) in duplicates {
+            count += 1;
+            self.log.log_finding(AnalyzerType::NamingCollisions, format!("naming collision: {} gates are named \"{}\" (consider giving each gate a unique name).", occurrences, name));
+        }
+
+        let mut reported_regions = HashSet::new();
+        for region in self.layouter.regions.iter() {
+            if gate_names.contains_key(region.name.as_str())
+                && reported_regions.insert(&region.name)
+            {
+                count += 1;
+                self.log.log_finding(AnalyzerType::NamingCollisions, format!("naming collision: region \"{}\" has the same name as a gate (consider renaming the region).", region.name));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::NamingCollisions,
+            count,
+            "naming collisions",
+        )
+    }
+
+    /// Detects instance columns read at multiple rotations
+    ///
+    /// This function logs in the `self.log` vector every instance column that `instance_rotations` finds read at
+    /// more than one rotation. A public input read at several rotations usually means a shifting or accumulator
+    /// pattern, which is worth reviewing.
+    ///
+    pub fn analyze_instance_rotations(&mut self) -> Result<AnalyzerOutput> {
+        let mut count = 0;
+        for (column, rotations) in self.instance_rotations() {
+            if rotations.len() > 1 {
+                count += 1;
+                self.log.log_finding(AnalyzerType::InstanceRotations, format!("instance column {} is read at rotations {:?} (review the shifting pattern).", column, rotations));
+            }
+        }
+        self.finish_pass(
+            AnalyzerOutputStatus::InstanceRotations,
+            count,
+            "instance columns read at multiple rotations",
+        )
+    }
+
+    /// Collects the distinct constants used across gates.
+    ///
+    /// This function walks every polynomial of every gate in the constraint system (`self.cs`) and collects the
+    /// values of `Expression::Constant` nodes and the factors of `Expression::Scaled` nodes. It helps auditors spot
+    /// unexpected magic numbers in a circuit.
+    ///
+    pub fn constants(&self) -> HashSet<F>
+    where
+        F: Hash,
+    {
+        let mut constants = HashSet::new();
+        for gate in self.cs.gates.iter() {
+            for poly in gate.polynomials() {
+                constants.extend(abstract_expr::extract_constants(poly));
+            }
+        }
+        constants
+    }
+    /// Computes the rotations at which each instance column is read.
+    ///
+    /// This function maps the index of every instance column referenced by the gates of the constraint system
+    /// (`self.cs`) to the set of rotations it is queried at.
+    ///
+    pub fn instance_rotations(&self) -> BTreeMap<usize, BTreeSet<i32>> {
+        let mut rotations: BTreeMap<usize, BTreeSet<i32>> = BTreeMap::new();
+        for gate in self.cs.gates.iter() {
+            if self.disabled_gates.contains(gate.name()) {
+                continue;
+            }
+            for poly in gate.polynomials() {
+                for (column, rotation) in abstract_expr::extract_instance_columns(poly) {
+                    rotations
+                        .entry(column.index())
+                        .or_default()
+                        .insert(rotation.0);
+                }
+            }
+        }
+        rotations
+    }
+    /// Summarizes the lookup arguments of the constraint system.
+    ///
+    /// This function reports, for every lookup in `self.cs.lookups`, the number of input expressions, the columns of
+    /// its table and whether they are all fixed, and the selector guarding its inputs (see `LookupInfo`).
+    ///
+    pub fn lookup_summary(&self) -> Vec<LookupInfo> {
+        self.cs
+            .lookups
+            .iter()
+            .enumerate()
+            .map(|(index, lookup)| {
+                let mut table_columns = vec![];
+                let mut table_kind = LookupTableKind::Fixed;
+                for table_expression in lookup.table_expressions.iter() {
+                    match table_expression {
+                        Expression::Fixed(fixed_query) => {
+                            table_columns.push(fixed_query.column_index)
+                        }
+                        Expression::Advice(advice_query) => {
+                            table_columns.push(advice_query.column_index);
+                            table_kind = LookupTableKind::Dynamic;
+                        }
+                        Expression::Instance(instance_query) => {
+                            table_columns.push(instance_query.column_index);
+                            table_kind = LookupTableKind::Dynamic;
+                        }
+                        _ => table_kind = LookupTableKind::Dynamic,
+                    }
+                }
+
+                let mut guards = lookup
+                    .input_expressions
+                    .iter()
+                    .map(abstract_expr::extract_selectors);
+                let common = guards.next().map(|first| {
+                    guards.fold(first, |common, selectors| {
+                        common.intersection(&selectors).cloned().collect()
+                    })
+                });
+                let selector = match common {
+                    Some(common) if common.len() == 1 => common.into_iter().next(),
+                    _ => None,
+                };
+
+                LookupInfo {
+                    index,
+                    input_count: lookup.input_expressions.len(),
+                    table_columns,
+                    table_kind,
+                    selector,
+                }
+            })
+            .collect()
+    }
+    /// Computes the instance columns each gate depends on.
+    ///
+    /// This function maps the name of every gate in the constraint system (`self.cs`) to the indices of the
+    /// instance columns referenced by its polynomials. Gates that do not reference public inputs map to an empty set.
+    ///
+    pub fn gate_instance_dependencies(&self) -> HashMap<String, HashSet<usize>> {
+        let mut dependencies: HashMap<String, HashSet<usize>> = HashMap::new();
+        for gate in self.cs.gates.iter() {
+            let columns = dependencies.entry(gate.name().to_owned()).or_default();
+            for poly in gate.polynomials() {
+                for (column, _) in abstract_expr::extract_instance_columns(poly) {
+                    columns.insert(column.index());
+                }
+            }
+        }
+        dependencies
+    }
+
+    /// Extracts instance columns from an equality table.
+    ///
+    /// This function takes an equality table (`eq_table`) represented as a `BTreeMap` with cell names as keys
+    /// and corresponding strings as values. It creates a new `BTreeMap` (`instance_cols_string`) and populates it
+    /// with the keys from the `eq_table`, assigning an initial value of zero to each key. The resulting `BTreeMap`
+    /// represents the extracted instance columns.
+    pub fn extract_instance_cols(
+        &mut self,
+        eq_table: BTreeMap<String, String>,
+    ) -> BTreeMap<String, i64> {
+        let mut instance_cols_string: BTreeMap<String, i64> = BTreeMap::new();
+        for cell in eq_table {
+            instance_cols_string.insert(cell.0.to_owned(), 0);
+        }
+        instance_cols_string
+    }
+    /// Extracts instance columns from regions in the layouter.
+    ///
+    /// This function iterates through the regions in the layouter (`self.layouter`) and extracts the instance columns
+    /// from each region's equality table. It creates a new `BTreeMap` (`instance_cols_string`) and populates it with
+    /// the extracted instance columns, assigning an initial value of zero to each column. The resulting `BTreeMap`
+    /// represents the extracted instance columns from all regions in the layouter.
+    ///
+    pub fn extract_instance_cols_from_region(&mut self) -> BTreeMap<String, i64> {
+        let mut instance_cols_string: BTreeMap<String, i64> = BTreeMap::new();
+        for region in self.layouter.regions.iter() {
+            for eq_adv in region.eq_table.iter() {
+                instance_cols_string.insert(eq_adv.0.to_owned(), 0);
+            }
+        }
+        instance_cols_string
+    }
+
+    /// Analyzes underconstrained circuits and generates an analyzer output.
+    ///
+    /// This function performs the analysis of underconstrained circuits. It takes as input an `analyzer_input` struct
+    /// containing the required information for the analysis, and a `fixed` vector representing the vallues of fixed columns.
+    /// The function creates an SMT file, decomposes the polynomials, writes the necessary variables and assertions, and runs
+    /// the control uniqueness function to determine the `output_status` of the analysis.
+    /// The analyzer output is returned as a `Result` indicating success or an error if the analysis fails.
+    pub fn analyze_underconstrained(
+        &mut self,
+        analyzer_input: AnalyzerInput,
+        fixed: impl Into<FixedMatrix<F>>,
+        base_field_prime: &str,
+    ) -> Result<AnalyzerOutput> {
+        fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/out.smt2";
+        let mut smt_file =
+            std::fs::File::create(smt_file_path).context("Failed to create file!")?;
+        let mut printer = if self.grouped_declarations {
+            smt::write_start_grouped(&mut smt_file, base_field_prime.to_owned())
+        } else {
+            smt::write_start(&mut smt_file, base_field_prime.to_owned())
+        };
+
+        self.encode_circuit(&mut printer, fixed)
+            .context("Failed to encode the circuit!")?;
+        smt::write_declarations(&mut printer);
+
+        let instance_string = analyzer_input.verification_input.instances_string.clone();
+
+        let mut analyzer_output: AnalyzerOutput = AnalyzerOutput {
+            output_status: AnalyzerOutputStatus::Invalid,
+        };
+        let output_status: AnalyzerOutputStatus = self
+            .uniqueness_assertion(
+                smt_file_path.to_owned(),
+                &instance_string,
+                &analyzer_input,
+                &mut printer,
+            )
+            .context("Failed to run control uniqueness function!")?;
+
+        analyzer_output.output_status = output_status;
+        if !self.quiet {
+            output_result(analyzer_input, &analyzer_output);
+        }
+
+        Ok(analyzer_output)
+    }
+
+    /// Analyzes a single gadget for underconstrained cells.
+    ///
+    /// This function runs `analyze_underconstrained` restricted to the regions whose names start with `name_prefix`
+    /// (see `with_region_prefix`), e.g. `poseidon_` for the regions of a Poseidon gadget. The cells shared with the
+    /// rest of the circuit are left free, so the gadget is checked in isolation. The previous region filter is
+    /// restored afterwards.
+    ///
+    pub fn analyze_gadget(
+        &mut self,
+        name_prefix: &str,
+        analyzer_input: AnalyzerInput,
+        fixed: impl Into<FixedMatrix<F>>,
+        base_field_prime: &str,
+    ) -> Result<AnalyzerOutput> {
+        if !self
+            .layouter
+            .regions
+            .iter()
+            .any(|region| region.name.starts_with(name_prefix))
+        {
+            return Err(anyhow!("No region name starts with {:?}!", name_prefix));
+        }
+        let previous = self.region_prefix.replace(name_prefix.to_owned());
+        let output = self.analyze_underconstrained(analyzer_input, fixed, base_field_prime);
+        self.region_prefix = previous;
+        output
+    }
+
+    /// Encodes the constraints of the circuit in SMT-LIB format.
+    ///
+    /// This function writes the gates and lookups (see `decompose_polynomial`) as well as the copy constraints
+    /// of every region and the bindings of cells to instance columns to `printer`. The resulting formula is satisfied
+    /// by exactly the witnesses accepted by the circuit. With `with_inlined_copies`, the copy constraints are not
+    /// asserted but inlined, each copy class being encoded as the variable of its representative. Cells copied to
+    /// fixed cells are pinned to the value assigned to the fixed cell in either case.
+    ///
+    pub fn encode_circuit(
+        &mut self,
+        printer: &mut smt::Printer<File>,
+        fixed: impl Into<FixedMatrix<F>>,
+    ) -> Result<()> {
+        self.copy_representatives = if self.inline_copies {
+            self.copy_classes()
+        } else {
+            HashMap::new()
+        };
+        self.decompose_polynomial(printer, fixed)?;
+        self.encode_fixed_copies(printer)?;
+        if self.inline_copies {
+            // The cells of each copy class share a single variable, there is nothing left to assert.
+            return Ok(());
+        }
+
+        let selected = |(left, right): &(&String, &String)| {
+            self.copy_selected(left, right)
+                && self.fixed_value(left).is_none()
+                && self.fixed_value(right).is_none()
+        };
+        for region in self.layouter.regions.iter() {
+            self.encode_copies(printer, region.advice_eq_table.iter().filter(selected));
+        }
+        for region in self.layouter.regions.iter() {
+            self.encode_copies(printer, region.eq_table.iter().filter(selected));
+        }
+        // Cells bound to public inputs with `constrain_instance`.
+        self.encode_copies(printer, self.layouter.eq_table.iter().filter(selected));
+        Ok(())
+    }
+
+    /// Returns the value of the cell named `name` with the default scheme if it is a fixed cell.
+    ///
+    /// The value is the one assigned during synthesis (see `AnalyticalShape::fixed_values`), 0 if the cell was
+    /// never assigned.
+    ///
+    fn fixed_value(&self, name: &str) -> Option<String> {
+        let location = name_formatter::parse_default_name(name)?;
+        if location.kind != CellKind::Fixed {
+            return None;
+        }
+        let value = location
+            .region
+            .and_then(|region| self.layouter.regions.get(region))
+            .and_then(|region| region.fixed_values.get(name));
+        Some(value.map_or_else(|| "0".to_owned(), String::clone))
+    }
+
+    /// Pins each cell copied to a fixed cell to the value of the fixed cell.
+    ///
+    /// Copies between two fixed cells do not involve the witness and are skipped.
+    ///
+    fn encode_fixed_copies(&self, printer: &mut smt::Printer<File>) -> Result<()> {
+        let copies = self
+            .layouter
+            .regions
+            .iter()
+            .flat_map(|region| region.advice_eq_table.iter().chain(region.eq_table.iter()))
+            .chain(self.layouter.eq_table.iter());
+        for (left, right) in copies {
+            if !self.copy_selected(left, right) {
+                continue;
+            }
+            let (cell, value) = match (self.fixed_value(left), self.fixed_value(right)) {
+                (None, Some(value)) => (left, value),
+                (Some(value), None) => (right, value),
+                _ => continue,
+            };
+            let cell = self.rename_cell(cell);
+            let value = self.decimal_literal(&value)?;
+            smt::write_var(printer, cell.clone());
+            smt::write_assert(printer, cell, value, NodeType::Advice, Operation::Equal);
+        }
+        Ok(())
+    }
+
+    /// Returns the SMT literal of the field element written `value` in decimal, e.g. `-1` or a value of a model.
+    ///
+    fn decimal_literal(&self, value: &str) -> Result<String> {
+        let element = match value.strip_prefix('-') {
+            Some(digits) => F::from_str_vartime(digits).map(|element| -element),
+            None => F::from_str_vartime(value),
+        }
+        .with_context(|| format!("Invalid field element {}!", value))?;
+        Ok(self.field_encoder.encode(&element))
+    }
+
+    /// Returns the literals of the powers of two below `2^bits`, the weights of the bits of `smt::write_range`.
+    ///
+    fn range_weights(&self, bits: u32) -> Vec<String> {
+        let mut weight = F::one();
+        (0..bits)
+            .map(|_| {
+                let literal = self.field_encoder.encode(&weight);
+                weight = weight.double();
+                literal
+            })
+            .collect()
+    }
+
+    /// Writes an equality assertion for each pair of cells in `copies`, named with the default scheme.
+    ///
+    fn encode_copies<'c>(
+        &self,
+        printer: &mut smt::Printer<File>,
+        copies: impl Iterator<Item = (&'c String, &'c String)>,
+    ) {
+        for (left, right) in copies {
+            let (left, right) = (self.rename_cell(left), self.rename_cell(right));
+            smt::write_var(printer, left.clone());
+            smt::write_var(printer, right.clone());
+
+            let neg = format!("(ff.neg {})", right);
+            let term = smt::write_term(
+                printer,
+                "add".to_owned(),
+                left,
+                NodeType::Advice,
+                neg,
+                NodeType::Advice,
+            );
+            smt::write_assert(
+                printer,
+                term,
+                self.field_encoder.encode(&F::zero()),
+                NodeType::Poly,
+                Operation::Equal,
+            );
+        }
+    }
+
+    /// Checks that encoding the circuit is deterministic.
+    ///
+    /// This function runs `decompose_polynomial` twice, into `src/output/dry_encode_0.smt2` and
+    /// `src/output/dry_encode_1.smt2`, and compares the two encodings with `compare_encodings`. Iterating over a
+    /// `HashMap` while encoding would make them differ, so this is meant as a guard to run in CI.
+    ///
+    pub fn dry_encode(&mut self, fixed: impl Into<FixedMatrix<F>>, prime: &str) -> Result<()> {
+        fs::create_dir_all("src/output/").unwrap();
+        let fixed: FixedMatrix<F> = fixed.into();
+        let mut encodings = vec![];
+        for run in 0..2 {
+            let smt_file_path = format!("src/output/dry_encode_{}.smt2", run);
+            let mut smt_file = File::create(&smt_file_path).context("Failed to create file!")?;
+            let mut printer = smt::write_start(&mut smt_file, prime.to_owned());
+            self.decompose_polynomial(&mut printer, fixed.clone())
+                .context("Failed to decompose polynomials!")?;
+            encodings.push(fs::read_to_string(&smt_file_path).context("Failed to read smt file!")?);
+        }
+        Self::compare_encodings(&encodings[0], &encodings[1])
+    }
+
+    /// Returns an error pointing at the first line where two SMT encodings differ.
+    ///
+    pub fn compare_encodings(first: &str, second: &str) -> Result<()> {
+        let mut first_lines = first.lines();
+        let mut second_lines = second.lines();
+        let mut line = 1;
+        loop {
+            match (first_lines.next(), second_lines.next()) {
+                (None, None) => return Ok(()),
+                (left, right) if left == right => line += 1,
+                (left, right) => {
+                    return Err(anyhow::anyhow!(
+                        "Nondeterministic encoding: line {} differs between runs ({:?} vs {:?})!",
+                        line,
+                        left.unwrap_or("<end of file>"),
+                        right.unwrap_or("<end of file>")
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Checks that the circuit constraints imply every registered property.
+    ///
+    /// This function encodes the circuit (see `encode_circuit`) and, for each property in `self.properties`, asserts
+    /// the negation of its relation in a new scope. If the solver finds the formula unsatisfiable, every accepted
+    /// witness satisfies the property. Otherwise the property is violated and it is logged in the `self.log` vector
+    /// together with the counterexample returned by the solver.
+    ///
+    pub fn analyze_properties(
+        &mut self,
+        fixed: impl Into<FixedMatrix<F>>,
+        base_field_prime: &str,
+    ) -> Result<AnalyzerOutput> {
+        fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/properties.smt2";
+        let mut smt_file =
+            std::fs::File::create(smt_file_path).context("Failed to create file!")?;
+        let mut printer = smt::write_start(&mut smt_file, base_field_prime.to_owned());
+
+        self.encode_circuit(&mut printer, fixed)
+            .context("Failed to encode the circuit!")?;
+        let variables: BTreeSet<String> = printer.vars.keys().cloned().collect();
+
+        let mut count = 0;
+        for property in self.properties.iter() {
+            smt::write_push(&mut printer, 1);
+            smt::write_assert_not(&mut printer, property.relation.clone());
+            let model = Self::solve_and_get_model(
+                smt_file_path.to_owned(),
+                &variables,
+                self.solver.as_ref(),
+            )
+            .context("Failed to solve and get model!")?;
+            smt::write_pop(&mut printer, 1);
+
+            if matches!(model.sat, Satisfiability::Unknown) {
+                return Err(anyhow!(
+                    "The solver could not decide property \"{}\"!",
+                    property.name
+                ));
+            }
+            if matches!(model.sat, Satisfiability::Satisfiable) {
                 count += 1;
-                self.log.push(format!("unused gate: \"{}\" (consider removing the gate or checking selectors in regions)", gate.name()));
+                let mut assignments: Vec<String> = model
+                    .result
+                    .values()
+                    .map(|variable| format!("{} = {}", variable.name, variable.value.element))
+                    .collect();
+                assignments.sort();
+                self.log.log_finding(
+                    AnalyzerType::Properties,
+                    format!(
+                        "violated property: \"{}\" (counterexample: {}).",
+                        property.name,
+                        assignments.join(", ")
+                    ),
+                );
             }
         }
-        println!("Finished analysis: {} unused gates found.", count);
-        Ok(AnalyzerOutput {
-            output_status: AnalyzerOutputStatus::UnusedCustomGates,
-        })
+        self.finish_pass(
+            AnalyzerOutputStatus::Properties,
+            count,
+            "violated properties",
+        )
     }
 
-    /// Detects unused columns
+    /// Detects instance columns that are always equal
     ///
-    /// This function iterates through the advice queries in the constraint system (`self.cs`) and checks if each column is used.
-    /// A column is considered unused if it does not appear in any of the polynomials within the gates of the constraint system.
-    /// If an unused column is found, it is logged in the `self.log` vector.
-    /// Finally, the function prints the total number of unused columns found.
+    /// This function encodes the circuit (see `encode_circuit`) to `src/output/equal_instances.smt2` and, for each
+    /// pair of instance columns with cells at common rows, asserts in a new scope that the two columns differ on at
+    /// least one of these rows. If the solver finds the formula unsatisfiable, the constraints force the columns to
+    /// be equal, so one of them is redundant, and the pair is logged in the `self.log` vector.
     ///
-    pub fn analyze_unused_columns(&mut self) -> Result<AnalyzerOutput> {
-        let mut count = 0;
-        let mut used;
-        for (column, rotation) in self.cs.advice_queries.iter().cloned() {
-            used = false;
+    pub fn analyze_equal_instance_columns(
+        &mut self,
+        fixed: impl Into<FixedMatrix<F>>,
+        base_field_prime: &str,
+    ) -> Result<AnalyzerOutput> {
+        fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/equal_instances.smt2";
+        let mut smt_file =
+            std::fs::File::create(smt_file_path).context("Failed to create file!")?;
+        let mut printer = smt::write_start(&mut smt_file, base_field_prime.to_owned());
 
-            for gate in self.cs.gates.iter() {
-                for poly in gate.polynomials() {
-                    let advices = abstract_expr::extract_columns(poly);
-                    if advices.contains(&(column.into(), rotation)) {
-                        used = true;
-                    }
-                }
-            }
+        self.encode_circuit(&mut printer, fixed)
+            .context("Failed to encode the circuit!")?;
+        let variables: BTreeSet<String> = printer.vars.keys().cloned().collect();
 
-            if !used {
-                count += 1;
-                self.log.push(format!("unused column: {:?}", column));
+        // The variable of each instance cell by column and absolute row.
+        let mut columns: BTreeMap<usize, BTreeMap<i32, String>> = BTreeMap::new();
+        for (name, location) in self.cell_locations() {
+            if location.kind == CellKind::Instance && variables.contains(&name) {
+                columns
+                    .entry(location.column)
+                    .or_default()
+                    .insert(location.row, name);
             }
         }
-        println!("Finished analysis: {} unused columns found.", count);
-        Ok(AnalyzerOutput {
-            output_status: AnalyzerOutputStatus::UnusedColumns,
-        })
-    }
 
-    /// Detect assigned but unconstrained cells:
-    /// (does it occur in a not-identially zero polynomial in the region?)
-    /// (if not almost certainly a bug)
-    pub fn analyze_unconstrained_cells(&mut self) -> Result<AnalyzerOutput> {
         let mut count = 0;
-        for region in self.layouter.regions.iter() {
-            let selectors = HashSet::from_iter(region.selectors().into_iter());
-            let mut used;
-            for (reg_column, rotation) in region.columns.iter().cloned() {
-                used = false;
-
-                match reg_column {
-                    RegionColumn::Selector(_) => continue,
-                    RegionColumn::Column(column) => {
-                        for gate in self.cs.gates.iter() {
-                            for poly in gate.polynomials() {
-                                let advices = abstract_expr::extract_columns(poly);
-                                let eval = abstract_expr::eval_abstract(poly, &selectors);
+        for (first, first_cells) in columns.iter() {
+            for (second, second_cells) in columns.range(first + 1..) {
+                let differences: Vec<String> = first_cells
+                    .iter()
+                    .filter_map(|(row, left)| {
+                        second_cells
+                            .get(row)
+                            .map(|right| format!("(not (= {} {}))", left, right))
+                    })
+                    .collect();
+                if differences.is_empty() {
+                    continue;
+                }
+                smt::write_push(&mut printer, 1);
+                smt::write_assert_bool(&mut printer, differences.join(" "), Operation::Or);
+                let model = Self::solve_and_get_model(
+                    smt_file_path.to_owned(),
+                    &variables,
+                    self.solver.as_ref(),
+                )
+                .context("Failed to solve and get model!")?;
+                smt::write_pop(&mut printer, 1);
 
-                                if eval != AbsResult::Zero && advices.contains(&(column, rotation))
-                                {
-                                    used = true;
-                                }
-                            }
-                        }
+                match model.sat {
+                    Satisfiability::Unsatisfiable => {
+                        count += 1;
+                        self.log.log_finding(AnalyzerType::EqualInstanceColumns, format!("equal instance columns: instance columns {} and {} are equal on all {} row(s) they share in every satisfying assignment (one of them is redundant).", first, second, differences.len()));
                     }
-                };
-
-                if !used {
-                    count += 1;
-                    self.log.push(format!("unconstrained cell in \"{}\" region: {:?} (rotation: {:?}) -- very likely a bug.", region.name,  reg_column, rotation));
+                    Satisfiability::Unknown => {
+                        return Err(anyhow!(
+                        "The solver could not decide whether instance columns {} and {} are equal!",
+                        first,
+                        second
+                    ))
+                    }
+                    Satisfiability::Satisfiable => {}
                 }
             }
         }
-        println!("Finished analysis: {} unconstrained cells found.", count);
-        Ok(AnalyzerOutput {
-            output_status: AnalyzerOutputStatus::UnconstrainedCells,
-        })
-    }
-    /// Extracts instance columns from an equality table.
-    ///
-    /// This function takes an equality table (`eq_table`) represented as a `HashMap` with cell names as keys
-    /// and corresponding strings as values. It creates a new `HashMap` (`instance_cols_string`) and populates it
-    /// with the keys from the `eq_table`, assigning an initial value of zero to each key. The resulting `HashMap`
-    /// represents the extracted instance columns.
-    pub fn extract_instance_cols(
-        &mut self,
-        eq_table: HashMap<String, String>,
-    ) -> HashMap<String, i64> {
-        let mut instance_cols_string: HashMap<String, i64> = HashMap::new();
-        for cell in eq_table {
-            instance_cols_string.insert(cell.0.to_owned(), 0);
-        }
-        instance_cols_string
-    }
-    /// Extracts instance columns from regions in the layouter.
-    ///
-    /// This function iterates through the regions in the layouter (`self.layouter`) and extracts the instance columns
-    /// from each region's equality table. It creates a new `HashMap` (`instance_cols_string`) and populates it with
-    /// the extracted instance columns, assigning an initial value of zero to each column. The resulting `HashMap`
-    /// represents the extracted instance columns from all regions in the layouter.
-    ///
-    pub fn extract_instance_cols_from_region(&mut self) -> HashMap<String, i64> {
-        let mut instance_cols_string: HashMap<String, i64> = HashMap::new();
-        for region in self.layouter.regions.iter() {
-            for eq_adv in region.eq_table.iter() {
-                instance_cols_string.insert(eq_adv.0.to_owned(), 0);
-            }
-        }
-        instance_cols_string
+        self.finish_pass(
+            AnalyzerOutputStatus::EqualInstanceColumns,
+            count,
+            "pairs of equal instance columns",
+        )
     }
 
-    /// Analyzes underconstrained circuits and generates an analyzer output.
+    /// Detects advice cells fully determined by the inputs
     ///
-    /// This function performs the analysis of underconstrained circuits. It takes as input an `analyzer_input` struct
-    /// containing the required information for the analysis, and a `fixed` vector representing the vallues of fixed columns.
-    /// The function creates an SMT file, decomposes the polynomials, writes the necessary variables and assertions, and runs
-    /// the control uniqueness function to determine the `output_status` of the analysis.
-    /// The analyzer output is returned as a `Result` indicating success or an error if the analysis fails.
-    pub fn analyze_underconstrained(
+    /// This function encodes the circuit to `src/output/determined.smt2` with the cells of `inputs` fixed to their
+    /// values, and solves it once to get a witness. Then, for each advice cell that is not an input, it asserts in a
+    /// new scope that the cell differs from its value in this witness. If the solver finds the formula
+    /// unsatisfiable, no second witness exists for this cell: it is fully determined, and it is logged in the
+    /// `self.log` vector. This is the dual of `analyze_underconstrained`, which looks for the cells that are not.
+    ///
+    pub fn analyze_determined_cells(
         &mut self,
-        analyzer_input: AnalyzerInput,
-        fixed: Vec<Vec<CellValue<F>>>,
+        inputs: &BTreeMap<CellLocation, F>,
+        fixed: impl Into<FixedMatrix<F>>,
         base_field_prime: &str,
     ) -> Result<AnalyzerOutput> {
         fs::create_dir_all("src/output/").unwrap();
-        let smt_file_path = "src/output/out.smt2";
+        let smt_file_path = "src/output/determined.smt2";
         let mut smt_file =
             std::fs::File::create(smt_file_path).context("Failed to create file!")?;
         let mut printer = smt::write_start(&mut smt_file, base_field_prime.to_owned());
 
-        Self::decompose_polynomial(self, &mut printer, fixed);
-
-        let instance_string = analyzer_input.verification_input.instances_string.clone();
-
-        let mut analyzer_output: AnalyzerOutput = AnalyzerOutput {
-            output_status: AnalyzerOutputStatus::Invalid,
-        };
-        for region in self.layouter.regions.iter() {
-            for eq_adv in region.advice_eq_table.iter() {
-                smt::write_var(&mut printer, eq_adv.0.to_owned());
-                smt::write_var(&mut printer, eq_adv.1.to_owned());
+        self.encode_circuit(&mut printer, fixed)
+            .context("Failed to encode the circuit!")?;
+        let mut input_names = HashSet::new();
+        for (location, value) in inputs.iter() {
+            let name = self.location_identifier(location);
+            smt::write_var(&mut printer, name.clone());
+            smt::write_assert(
+                &mut printer,
+                name.clone(),
+                self.field_encoder.encode(value),
+                NodeType::Advice,
+                Operation::Equal,
+            );
+            input_names.insert(name);
+        }
+        let witness_cells: BTreeMap<String, CellLocation> = self
+            .cell_locations()
+            .into_iter()
+            .filter(|(name, location)| {
+                location.kind == CellKind::Advice
+                    && printer.vars.contains_key(name)
+                    && !input_names.contains(name)
+            })
+            .collect();
+        let variables: BTreeSet<String> = witness_cells.keys().cloned().collect();
 
-                let neg = format!("(ff.neg {})", eq_adv.1);
-                let term = smt::write_term(
-                    &mut printer,
-                    "add".to_owned(),
-                    eq_adv.0.to_owned(),
-                    NodeType::Advice,
-                    neg,
-                    NodeType::Advice,
-                );
-                smt::write_assert(
-                    &mut printer,
-                    term,
-                    "0".to_owned(),
-                    NodeType::Poly,
-                    Operation::Equal,
-                );
+        let witness =
+            Self::solve_and_get_model(smt_file_path.to_owned(), &variables, self.solver.as_ref())
+                .context("Failed to solve and get model!")?;
+        match witness.sat {
+            Satisfiability::Unsatisfiable => {
+                if !self.quiet {
+                    println!(
+                        "Finished analysis: no witness satisfies the constraints for these inputs."
+                    );
+                }
+                return Ok(AnalyzerOutput {
+                    output_status: AnalyzerOutputStatus::DeterminedCells,
+                });
+            }
+            Satisfiability::Unknown => {
+                return Err(anyhow!(
+                    "The solver could not find a witness for these inputs!"
+                ))
             }
+            Satisfiability::Satisfiable => {}
         }
 
-        for region in self.layouter.regions.iter() {
-            for eq_adv in region.eq_table.iter() {
-                smt::write_var(&mut printer, eq_adv.0.to_owned());
-                smt::write_var(&mut printer, eq_adv.1.to_owned());
+        let mut count = 0;
+        for (name, location) in witness_cells.iter() {
+            let value = match witness.result.get(name) {
+                Some(variable) => self.decimal_literal(&variable.value.element)?,
+                None => continue,
+            };
+            smt::write_push(&mut printer, 1);
+            smt::write_assert(
+                &mut printer,
+                name.clone(),
+                value.clone(),
+                NodeType::Advice,
+                Operation::NotEqual,
+            );
+            let model = Self::solve_and_get_model(
+                smt_file_path.to_owned(),
+                &BTreeSet::new(),
+                self.solver.as_ref(),
+            )
+            .context("Failed to solve and get model!")?;
+            smt::write_pop(&mut printer, 1);
 
-                let neg = format!("(ff.neg {})", eq_adv.1);
-                let term = smt::write_term(
-                    &mut printer,
-                    "add".to_owned(),
-                    eq_adv.0.to_owned(),
-                    NodeType::Advice,
-                    neg,
-                    NodeType::Advice,
-                );
-                smt::write_assert(
-                    &mut printer,
-                    term,
-                    "0".to_owned(),
-                    NodeType::Poly,
-                    Operation::Equal,
-                );
+            match model.sat {
+                Satisfiability::Unsatisfiable => {
+                    count += 1;
+                    self.log.log_finding(AnalyzerType::DeterminedCells, format!("determined cell: cell {} (advice column {}, row {} of region {}) always equals {} for these inputs.", name, location.column, location.row, location.region.unwrap_or_default(), value));
+                }
+                Satisfiability::Unknown => {
+                    return Err(anyhow!(
+                        "The solver could not decide whether cell {} is determined!",
+                        name
+                    ))
+                }
+                Satisfiability::Satisfiable => {}
             }
         }
+        if !self.quiet {
+            println!(
+                "Finished analysis: {} of {} witness cells are determined by the inputs.",
+                count,
+                witness_cells.len()
+            );
+        }
+        Ok(AnalyzerOutput {
+            output_status: AnalyzerOutputStatus::DeterminedCells,
+        })
+    }
 
-        let output_status: AnalyzerOutputStatus = Self::uniqueness_assertion(
-            smt_file_path.to_owned(),
-            &instance_string,
-            &analyzer_input,
-            &mut printer,
-        )
-        .context("Failed to run control uniqueness function!")?;
+    /// Checks whether the constraints tell two witnesses apart through their public outputs.
+    ///
+    /// This function encodes two copies of the circuit to `src/output/distinguishable.smt2`, their cells being
+    /// suffixed with `~1` and `~2`, and fixes the cells of each witness in its copy. It then asserts that a cell bound
+    /// to an instance column (see `extract_instance_cols`) differs between the copies, and solves the formula once.
+    /// It returns true if both witnesses satisfy the constraints and can expose different public outputs. A witness
+    /// violating the constraints is not observable, so false is returned in that case. The parameters of the circuit
+    /// are shared by both copies.
+    ///
+    pub fn distinguishable(
+        &mut self,
+        first: &BTreeMap<CellLocation, F>,
+        second: &BTreeMap<CellLocation, F>,
+        fixed: impl Into<FixedMatrix<F>>,
+        base_field_prime: &str,
+    ) -> Result<bool> {
+        fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/distinguishable.smt2";
+        let mut smt_file =
+            std::fs::File::create(smt_file_path).context("Failed to create file!")?;
+        let mut printer = smt::write_start(&mut smt_file, base_field_prime.to_owned());
 
-        analyzer_output.output_status = output_status;
-        output_result(analyzer_input, &analyzer_output);
+        let fixed: FixedMatrix<F> = fixed.into();
+        let mut public_cells = self.extract_instance_cols(self.layouter.eq_table.clone());
+        public_cells.extend(self.extract_instance_cols_from_region());
+        let encoded: Result<Vec<Vec<String>>> = [first, second]
+            .into_iter()
+            .enumerate()
+            .map(|(copy, witness)| {
+                self.witness_suffix = format!("~{}", copy + 1);
+                self.encode_circuit(&mut printer, fixed.clone())
+                    .context("Failed to encode the circuit!")?;
+                for (location, value) in witness.iter() {
+                    let name = self.location_identifier(location);
+                    smt::write_var(&mut printer, name.clone());
+                    smt::write_assert(
+                        &mut printer,
+                        name,
+                        self.field_encoder.encode(value),
+                        NodeType::Advice,
+                        Operation::Equal,
+                    );
+                }
+                // The public outputs of this copy, in the same order for both copies.
+                Ok(public_cells
+                    .keys()
+                    .map(|name| self.rename_cell(name))
+                    .filter(|name| printer.vars.contains_key(name))
+                    .collect())
+            })
+            .collect();
+        self.witness_suffix.clear();
+        let outputs = encoded?;
 
-        Ok(analyzer_output)
+        let differences: String = outputs[0]
+            .iter()
+            .zip(outputs[1].iter())
+            .map(|(left, right)| format!("(not (= {} {}))", left, right))
+            .collect();
+        if differences.is_empty() {
+            // Without public outputs, no witness can be told apart.
+            return Ok(false);
+        }
+        smt::write_assert_bool(&mut printer, differences, Operation::Or);
+        let model = Self::solve_and_get_model(
+            smt_file_path.to_owned(),
+            &BTreeSet::new(),
+            self.solver.as_ref(),
+        )
+        .context("Failed to solve and get model!")?;
+        match model.sat {
+            Satisfiability::Satisfiable => Ok(true),
+            Satisfiability::Unsatisfiable => Ok(false),
+            Satisfiability::Unknown => Err(anyhow!(
+                "The solver could not decide whether the witnesses are distinguishable!"
+            )),
+        }
     }
 
     #[cfg(test)]
-    pub fn log(&self) -> &[String] {
+    pub fn log(&self) -> &[Finding] {
+        &self.log
+    }
+
+    /// Returns the findings logged by the passes run so far, in order.
+    ///
+    pub fn findings(&self) -> &[Finding] {
         &self.log
     }
     /**
@@ -319,9 +3272,11 @@ impl<'b, F: Field> Analyzer<F> {
      *
      * * `poly` - A reference to an `Expression` instance that is to be decomposed into SMT-LIB v2 format.
      * * `printer` - A mutable reference to a `Printer` instance which is used for writing the decomposed expression.
-     * * `region_no` - An integer that represents the region number.
+     * * `region` - The region the expression is evaluated in. Its enabled selectors are checked during the decomposition.
      * * `row_num` - An integer that represents the row number in region.
-     * * `es` - A reference to a `HashSet` of Strings representing enabled selectors. These selectors are checked during the decomposition.
+     * * `fixed` - The `fixed` matrix, whose cells replace fixed queries (see `FixedMatrix`).
+     * * `names` - The `NameFormatter` producing the SMT identifiers of cells.
+     * * `encoder` - The `FieldEncoder` producing the SMT literals of constants.
      *
      * # Returns
      *
@@ -334,68 +3289,89 @@ impl<'b, F: Field> Analyzer<F> {
      * The function formats a string representation of the expression in SMT-LIB v2 format and identifies its polynomial type as a NodeType.
      *  The function has a recursive behavior in the cases of `Negated`, `Sum`, `Product`,
      * and `Scaled` variants of `Expression`, where it decomposes the nested expressions by calling itself.
+     * Selector sites are written once as a `define-fun` named `S-{region}-{selector}-{row}` and referenced by name.
+     * Fixed queries are replaced by the value of the cell in `fixed`, or by the identifier of its parameter.
      */
     fn decompose_expression(
         poly: &Expression<F>,
         printer: &mut smt::Printer<File>,
-        region_no: usize,
+        region: &AnalyticalShape,
         row_num: i32,
-        es: &HashSet<String>,
+        fixed: &FixedMatrix<F>,
+        names: &dyn NameFormatter,
+        encoder: &dyn FieldEncoder<F>,
     ) -> (String, NodeType) {
         match &poly {
             Expression::Constant(a) => {
-                let term = format!("(as ff{} F)", a.get_lower_128());
+                let term = encoder.encode(a);
                 (term, NodeType::Constant)
             }
             Expression::Selector(a) => {
-                let s = format!("S-{:?}-{}-{}", region_no, a.0, row_num);
-                if es.contains(&s) {
-                    ("(as ff1 F)".to_owned(), NodeType::Fixed)
+                let s = format!("S-{:?}-{}-{}", region.region_index.0, a.0, row_num);
+                let value = if region.enabled_selectors.contains(&s) {
+                    encoder.encode(&F::one())
                 } else {
-                    ("(as ff0 F)".to_owned(), NodeType::Fixed)
-                }
+                    encoder.encode(&F::zero())
+                };
+                smt::write_define(printer, s.clone(), value);
+                (s, NodeType::Fixed)
             }
             Expression::Fixed(fixed_query) => {
-                let term = format!(
-                    "F-{}-{}-{}",
-                    region_no,
-                    fixed_query.column_index,
-                    fixed_query.rotation.0 + row_num
-                );
-
-                (term, NodeType::Fixed)
+                // Fixed cells are known, except for parameters which are free variables.
+                let row = region.row_offset as i32 + fixed_query.rotation.0 + row_num;
+                let cell = usize::try_from(row)
+                    .ok()
+                    .and_then(|row| fixed.0.get(fixed_query.column_index)?.get(row));
+                match cell {
+                    Some(FixedCell::Symbolic(name)) => {
+                        let term = name_formatter::to_identifier(name);
+                        smt::write_var(printer, term.clone());
+                        (term, NodeType::Fixed)
+                    }
+                    Some(FixedCell::Value(CellValue::Assigned(value))) => {
+                        (encoder.encode(value), NodeType::Constant)
+                    }
+                    // Unassigned fixed cells are zero.
+                    _ => (encoder.encode(&F::zero()), NodeType::Constant),
+                }
             }
             Expression::Advice(advice_query) => {
-                let term = format!(
-                    "A-{}-{}-{}",
-                    region_no,
-                    advice_query.column_index,
-                    advice_query.rotation.0 + row_num
-                );
+                let cell = CellRef {
+                    kind: CellKind::Advice,
+                    region: Some(region.region_index.0),
+                    region_name: &region.name,
+                    column: advice_query.column_index,
+                    row: advice_query.rotation.0 + row_num,
+                };
+                let term = Self::cell_identifier(names, &cell);
                 smt::write_var(printer, term.clone());
                 (term, NodeType::Advice)
             }
-            Expression::Instance(_instance_query) => ("".to_owned(), NodeType::Instance),
-            Expression::Negated(poly) => {
-                let (node_str, node_type) =
-                    Self::decompose_expression(poly, printer, region_no, row_num, es);
-                let term = if (matches!(node_type, NodeType::Advice)
-                    || matches!(node_type, NodeType::Instance)
-                    || matches!(node_type, NodeType::Fixed)
-                    || matches!(node_type, NodeType::Constant))
-                {
-                    format!("ff.neg {}", node_str)
-                } else {
-                    format!("ff.neg ({})", node_str)
+            Expression::Instance(instance_query) => {
+                // Public inputs are shared by the regions, they are named by their absolute row.
+                let cell = CellRef {
+                    kind: CellKind::Instance,
+                    region: None,
+                    region_name: &region.name,
+                    column: instance_query.column_index,
+                    row: region.row_offset as i32 + instance_query.rotation.0 + row_num,
                 };
-                //let term = format!("ff.neg {}", node_str);
+                let term = Self::cell_identifier(names, &cell);
+                smt::write_var(printer, term.clone());
+                (term, NodeType::Instance)
+            }
+            Expression::Negated(poly) => {
+                let (node_str, node_type) = Self::decompose_expression(
+                    poly, printer, region, row_num, fixed, names, encoder,
+                );
+                let term = format!("ff.neg {}", smt::wrap_operand(node_str, &node_type));
                 (term, NodeType::Negated)
             }
             Expression::Sum(a, b) => {
                 let (node_str_left, nodet_type_left) =
-                    Self::decompose_expression(a, printer, region_no, row_num, es);
+                    Self::decompose_expression(a, printer, region, row_num, fixed, names, encoder);
                 let (node_str_right, nodet_type_right) =
-                    Self::decompose_expression(b, printer, region_no, row_num, es);
+                    Self::decompose_expression(b, printer, region, row_num, fixed, names, encoder);
                 let term = smt::write_term(
                     printer,
                     "add".to_owned(),
@@ -408,9 +3384,9 @@ impl<'b, F: Field> Analyzer<F> {
             }
             Expression::Product(a, b) => {
                 let (node_str_left, nodet_type_left) =
-                    Self::decompose_expression(a, printer, region_no, row_num, es);
+                    Self::decompose_expression(a, printer, region, row_num, fixed, names, encoder);
                 let (node_str_right, nodet_type_right) =
-                    Self::decompose_expression(b, printer, region_no, row_num, es);
+                    Self::decompose_expression(b, printer, region, row_num, fixed, names, encoder);
                 let term = smt::write_term(
                     printer,
                     "mul".to_owned(),
@@ -426,12 +3402,15 @@ impl<'b, F: Field> Analyzer<F> {
                 let (node_str_left, nodet_type_left) = Self::decompose_expression(
                     &Expression::Constant(*c),
                     printer,
-                    region_no,
+                    region,
                     row_num,
-                    es,
+                    fixed,
+                    names,
+                    encoder,
+                );
+                let (node_str_right, nodet_type_right) = Self::decompose_expression(
+                    _poly, printer, region, row_num, fixed, names, encoder,
                 );
-                let (node_str_right, nodet_type_right) =
-                    Self::decompose_expression(_poly, printer, region_no, row_num, es);
                 let term = smt::write_term(
                     printer,
                     "mul".to_owned(),
@@ -444,6 +3423,44 @@ impl<'b, F: Field> Analyzer<F> {
             }
         }
     }
+    /// Estimates the size of the lookup encoding written by `decompose_polynomial`.
+    ///
+    /// For every lookup, each row of each region gets a disjunction with one term per table row, a table ending at
+    /// the first unassigned row of its fixed columns in the `fixed` matrix, parameters being assigned. This function
+    /// returns the total number of such terms.
+    ///
+    pub fn lookup_encoding_size(&self, fixed: &FixedMatrix<F>) -> u128 {
+        let rows: u128 = self
+            .layouter
+            .regions
+            .iter()
+            .map(|region| region.row_count as u128)
+            .sum();
+        self.cs
+            .lookups
+            .iter()
+            .map(|lookup| {
+                let table_rows = lookup
+                    .table_expressions
+                    .iter()
+                    .filter_map(|table_expression| match table_expression {
+                        Expression::Fixed(fixed_query) => fixed.0.get(fixed_query.column_index),
+                        _ => None,
+                    })
+                    .map(|cells| {
+                        cells
+                            .iter()
+                            .take_while(|cell| {
+                                !matches!(cell, FixedCell::Value(CellValue::Unassigned))
+                            })
+                            .count()
+                    })
+                    .min()
+                    .unwrap_or(0);
+                rows * table_rows as u128
+            })
+            .sum()
+    }
     /// Decomposes polynomials and writes assertions using an SMT printer.
     ///
     /// This function iterates over the regions and rows of a layouter and decomposes the polynomials
@@ -452,25 +3469,54 @@ impl<'b, F: Field> Analyzer<F> {
     pub fn decompose_polynomial(
         &'b mut self,
         printer: &mut smt::Printer<File>,
-        fixed: Vec<Vec<CellValue<F>>>,
-    ) ->Result<(), anyhow::Error>{
+        fixed: impl Into<FixedMatrix<F>>,
+    ) -> Result<(), anyhow::Error> {
+        let fixed: FixedMatrix<F> = fixed.into();
+        let lookup_encoding_size = self.lookup_encoding_size(&fixed);
+        self.parameters = fixed.parameters();
+        if lookup_encoding_size > self.lookup_encoding_limit {
+            return Err(anyhow!(
+                "The lookup encoding would have {} table row terms, above the limit of {}. Consider hoisting lookup tables out of the per-row encoding, shrinking the tables, or raising the limit with `with_lookup_encoding_limit`.",
+                lookup_encoding_size,
+                self.lookup_encoding_limit
+            ));
+        }
         if !self.layouter.regions.is_empty() {
             for region_no in 0..self.layouter.regions.len() {
+                if !self.region_selected(region_no) {
+                    continue;
+                }
                 for row_num in 0..self.layouter.regions[region_no].row_count {
                     for gate in self.cs.gates.iter() {
-                        for poly in &gate.polys {
+                        if self.disabled_gates.contains(gate.name()) {
+                            continue;
+                        }
+                        for (poly_no, poly) in gate.polys.iter().enumerate() {
+                            if self.degree_annotations {
+                                smt::write_comment(
+                                    printer,
+                                    format!(
+                                        "degree {} (gate {:?}, polynomial {})",
+                                        abstract_expr::witness_degree(poly),
+                                        gate.name(),
+                                        poly_no
+                                    ),
+                                );
+                            }
                             let (node_str, _) = Self::decompose_expression(
                                 poly,
                                 printer,
-                                region_no,
+                                &self.layouter.regions[region_no],
                                 i32::try_from(row_num).ok().unwrap(),
-                                &self.layouter.regions[region_no].enabled_selectors,
+                                &fixed,
+                                &self.cell_names(),
+                                self.field_encoder.as_ref(),
                             );
 
                             smt::write_assert(
                                 printer,
                                 node_str,
-                                "0".to_owned(),
+                                self.field_encoder.encode(&F::zero()),
                                 NodeType::Poly,
                                 Operation::Equal,
                             );
@@ -480,6 +3526,9 @@ impl<'b, F: Field> Analyzer<F> {
             }
 
             for region_no in 0..self.layouter.regions.len() {
+                if !self.region_selected(region_no) {
+                    continue;
+                }
                 for row_num in 0..self.layouter.regions[region_no].row_count {
                     for lookup in self.cs.lookups.iter() {
                         let mut cons_str_vec = Vec::new();
@@ -487,9 +3536,11 @@ impl<'b, F: Field> Analyzer<F> {
                             let (node_str, _) = Self::decompose_expression(
                                 poly,
                                 printer,
-                                region_no,
+                                &self.layouter.regions[region_no],
                                 i32::try_from(row_num).ok().unwrap(),
-                                &self.layouter.regions[region_no].enabled_selectors,
+                                &fixed,
+                                &self.cell_names(),
+                                self.field_encoder.as_ref(),
                             );
                             cons_str_vec.push(node_str);
                         }
@@ -505,7 +3556,7 @@ impl<'b, F: Field> Analyzer<F> {
                         }
                         let mut big_cons_str = "".to_owned();
                         let mut big_cons = vec![];
-                        for row in 0..fixed[0].len() {
+                        for row in 0..fixed.0[0].len() {
                             //*** Iterate over look up table rows */
                             if exit {
                                 break;
@@ -515,18 +3566,29 @@ impl<'b, F: Field> Analyzer<F> {
                             for col in 0..col_indices.len() {
                                 //*** Iterate over fixed cols */
                                 let mut t = String::new();
-                                match fixed[col_indices[col]][row] {
-                                    CellValue::Unassigned => {
+                                match &fixed.0[col_indices[col]][row] {
+                                    FixedCell::Value(CellValue::Unassigned) => {
                                         exit = true;
                                         break;
                                     }
-                                    CellValue::Assigned(f) => {
-                                        t = f.get_lower_128().to_string();
+                                    FixedCell::Value(CellValue::Assigned(f)) => {
+                                        t = self.field_encoder.encode(f);
+                                    }
+                                    FixedCell::Value(CellValue::Poison(_)) => {}
+                                    FixedCell::Symbolic(name) => {
+                                        // Parameters are compared as free variables instead of constants.
+                                        let name = name_formatter::to_identifier(name);
+                                        smt::write_var(printer, name.clone());
+                                        let equality = smt::get_equal(
+                                            printer,
+                                            cons_str_vec[col].clone(),
+                                            NodeType::Mult,
+                                            name,
+                                            NodeType::Fixed,
+                                        );
+                                        equalities.push(equality);
+                                        continue;
                                     }
-                                    CellValue::Poison(_) => {}
-                                }
-                                if let CellValue::Assigned(value) = fixed[col_indices[col]][row] {
-                                    t = value.get_lower_128().to_string();
                                 }
                                 let sa = smt::get_assert(
                                     printer,
@@ -563,19 +3625,54 @@ impl<'b, F: Field> Analyzer<F> {
     /// This function checks the uniqueness by solving SMT formulas with various assignments
     /// and constraints. It iterates over the variables and applies different rules based on the verification method
     /// specified in the `analyzer_input`. The function writes assertions using an SMT printer and returns the
-    /// analysis result as `AnalyzerOutputStatus`.
+    /// analysis result as `AnalyzerOutputStatus`. Cells of columns registered with `with_witness_bound` are kept
+    /// within their bounds, and when the circuit is under-constrained the two witnesses are stored in
+    /// `self.counterexample`. With `with_checkpoint`, the progress is saved after every iteration, and with
+    /// `with_early_stop` the iterations stop once models no longer reveal new witness behavior. The clauses blocking
+    /// the models examined are kept in `self.blocking_clauses` (see `save_blocking_clauses`). With
+    /// `with_output_columns`, the second witness must differ from the first in a cell of the output columns, and
+    /// with `with_boundary_values_first` the first `Random` iterations use boundary public inputs. The parameters of
+    /// the circuit (see `FixedMatrix::with_symbolic`) are shared by both witnesses, like the public inputs.
     ///
     pub fn uniqueness_assertion(
+        &mut self,
         smt_file_path: String,
-        instance_cols_string: &HashMap<String, i64>,
+        instance_cols_string: &BTreeMap<String, i64>,
         analyzer_input: &AnalyzerInput,
         printer: &mut smt::Printer<File>,
     ) -> Result<AnalyzerOutputStatus> {
+        let renamed_instance_cols: BTreeMap<String, i64> = instance_cols_string
+            .iter()
+            .map(|(name, value)| (self.rename_cell(name), *value))
+            .collect();
+        let instance_cols_string = &renamed_instance_cols;
+        let solver = self.solver.as_ref();
         let mut result: AnalyzerOutputStatus = AnalyzerOutputStatus::NotUnderconstrainedLocal;
-        let mut variables: HashSet<String> = HashSet::new();
+        let mut variables: BTreeSet<String> = BTreeSet::new();
         for variable in printer.vars.keys() {
             variables.insert(variable.clone());
         }
+        // Bounds hold for both witnesses, so they are asserted outside of any scope.
+        let witness_bounds = self.witness_bounds_by_cell();
+        for variable in variables.iter() {
+            if let Some(bits) = witness_bounds.get(variable) {
+                smt::write_range(
+                    printer,
+                    variable.clone(),
+                    &self.range_weights(*bits),
+                    self.field_encoder.encode(&F::zero()),
+                );
+            }
+        }
+        let output_cells = self.output_cells();
+        if let Some(output_cells) = &output_cells {
+            if !output_cells.iter().any(|cell| variables.contains(cell)) {
+                return Err(anyhow!(
+                    "No cell of the output columns {:?} is encoded!",
+                    self.output_columns
+                ));
+            }
+        }
 
         let mut max_iterations: u128 = 1;
 
@@ -586,7 +3683,7 @@ impl<'b, F: Field> Analyzer<F> {
                     smt::write_assert(
                         printer,
                         var.0.clone(),
-                        (*var.1).to_string(),
+                        self.decimal_literal(&var.1.to_string())?,
                         NodeType::Instance,
                         Operation::Equal,
                     );
@@ -596,23 +3693,90 @@ impl<'b, F: Field> Analyzer<F> {
                 max_iterations = analyzer_input.verification_input.iterations;
             }
         }
-        let model = Self::solve_and_get_model(smt_file_path.clone(), &variables)
+        let model = Self::solve_and_get_model(smt_file_path.clone(), &variables, solver)
             .context("Failed to solve and get model!")?;
+        if let Some(status) = self.apply_verdict_policy(model.sat)? {
+            return Ok(status);
+        }
         if matches!(model.sat, Satisfiability::Unsatisfiable) {
             result = AnalyzerOutputStatus::Overconstrained;
             return Ok(result); // We can just break here.
         }
-        for i in 1..=max_iterations {
-            let model = Self::solve_and_get_model(smt_file_path.clone(), &variables)
+        let mut checkpoint = Checkpoint::default();
+        if let Some(path) = &self.checkpoint_path {
+            if self.resume && Path::new(path).exists() {
+                checkpoint = Checkpoint::load(path).context("Failed to resume analysis!")?;
+            }
+        }
+        // Models blocked by an earlier analysis, see `load_blocking_clauses`, then by the resumed checkpoint.
+        self.blocking_clauses = self.resumed_blocking_clauses.clone();
+        self.blocking_clauses
+            .extend(checkpoint.blocking_clauses.iter().cloned());
+        for clause in self.blocking_clauses.iter() {
+            smt::write_assert_bool(printer, clause.clone(), Operation::Or);
+        }
+        let mut behaviors = HashSet::new();
+        let mut stale_iterations = 0;
+        self.iterations_used = checkpoint.iteration;
+        for i in checkpoint.iteration + 1..=max_iterations {
+            self.iterations_used = i;
+            let boundary_value = match analyzer_input.verification_method {
+                VerificationMethod::Random if self.boundary_values_first => {
+                    BOUNDARY_VALUES.get((i - 1) as usize)
+                }
+                _ => None,
+            };
+            if let Some(value) = boundary_value {
+                smt::write_push(printer, 1);
+                for var in instance_cols_string
+                    .keys()
+                    .filter(|var| variables.contains(*var))
+                {
+                    smt::write_assert(
+                        printer,
+                        var.clone(),
+                        self.decimal_literal(value)?,
+                        NodeType::Instance,
+                        Operation::Equal,
+                    );
+                }
+            }
+            let model = Self::solve_and_get_model(smt_file_path.clone(), &variables, solver)
                 .context("Failed to solve and get model!")?;
+            if boundary_value.is_some() {
+                smt::write_pop(printer, 1);
+                if matches!(model.sat, Satisfiability::Unsatisfiable) {
+                    // No witness for this boundary input, which says nothing about the other inputs.
+                    continue;
+                }
+            }
+            if let Some(status) = self.apply_verdict_policy(model.sat)? {
+                return Ok(status);
+            }
             if matches!(model.sat, Satisfiability::Unsatisfiable) {
                 result = AnalyzerOutputStatus::NotUnderconstrained;
                 return Ok(result); // We can just break here.
             }
+            let behavior: Vec<u8> = model
+                .result
+                .values()
+                .filter(|variable| {
+                    !instance_cols_string.contains_key(&variable.name)
+                        && !self.parameters.contains(&variable.name)
+                })
+                .map(|variable| match variable.value.element.as_str() {
+                    "0" => 0,
+                    "1" => 1,
+                    _ => 2,
+                })
+                .collect();
 
-            println!("Model {} to be checked:", i);
-            for r in &model.result {
-                println!("{} : {}", r.1.name, r.1.value.element)
+            if !self.quiet {
+                println!("Model {} to be checked:", i);
+                print!(
+                    "{}",
+                    format_model_with_limit(&model, self.model_print_limit.as_ref())
+                );
             }
 
             // Imitate the creation of a new solver by utilizing the stack functionality of solver
@@ -629,29 +3793,34 @@ impl<'b, F: Field> Analyzer<F> {
             for var in variables.iter() {
                 // The second condition is needed because the following constraints would've been added already to the solver in the beginning.
                 // It is not strictly necessary, but there is no point in adding redundant constraints to the solver.
-                if instance_cols_string.contains_key(var)
+                let public = instance_cols_string.contains_key(var)
                     && !matches!(
                         analyzer_input.verification_method,
                         VerificationMethod::Specific
-                    )
-                {
-                    // 1. Fix the public input
+                    );
+                // Parameters describe the circuit, not the witness, so both witnesses share them.
+                if public || self.parameters.contains(var) {
+                    // 1. Fix the public input and the parameters
                     let result_from_model = &model.result[var];
                     let sa = smt::get_assert(
                         printer,
                         result_from_model.name.clone(),
-                        result_from_model.value.element.clone(),
+                        self.decimal_literal(&result_from_model.value.element)?,
                         NodeType::Instance,
                         Operation::Equal,
-                    ).context("Failled to generate assert!")?;
+                    )
+                    .context("Failled to generate assert!")?;
                     same_assignments.push(sa);
-                } else {
-                    //2. Change the other vars
+                } else if output_cells
+                    .as_ref()
+                    .map_or(true, |output_cells| output_cells.contains(var))
+                {
+                    //2. Change the other vars (only the outputs, if declared)
                     let result_from_model = &model.result[var];
                     let sa = smt::get_assert(
                         printer,
                         result_from_model.name.clone(),
-                        result_from_model.value.element.clone(),
+                        self.decimal_literal(&result_from_model.value.element)?,
                         NodeType::Instance,
                         Operation::NotEqual,
                     ).context("Failled to generate assert!")?;
@@ -676,16 +3845,26 @@ impl<'b, F: Field> Analyzer<F> {
 
             // 4. find a model that satisfies these rules
             let model_with_constraint =
-                Self::solve_and_get_model(smt_file_path.clone(), &variables)
+                Self::solve_and_get_model(smt_file_path.clone(), &variables, solver)
                     .context("Failed to solve and get model!")?;
+            if let Some(status) = self.apply_verdict_policy(model_with_constraint.sat)? {
+                return Ok(status);
+            }
             if matches!(model_with_constraint.sat, Satisfiability::Satisfiable) {
-                println!("Equivalent model for the same public input:");
-                for r in &model_with_constraint.result {
-                    println!("{} : {}", r.1.name, r.1.value.element)
+                if !self.quiet {
+                    println!("Equivalent model for the same public input:");
+                    print!(
+                        "{}",
+                        format_model_with_limit(
+                            &model_with_constraint,
+                            self.model_print_limit.as_ref()
+                        )
+                    );
                 }
                 result = AnalyzerOutputStatus::Underconstrained;
+                self.counterexample = Some((model, model_with_constraint));
                 return Ok(result);
-            } else {
+            } else if !self.quiet {
                 println!("There is no equivalent model with the same public input to prove model {} is under-constrained!", i);
             }
             smt::write_pop(printer, 1);
@@ -693,11 +3872,13 @@ impl<'b, F: Field> Analyzer<F> {
             // If no model found, add some rules to the initial solver to make sure does not generate the same model again
             let mut negated_model_variable_assignments = vec![];
             for res in &model.result {
-                if instance_cols_string.contains_key(&res.1.name) {
+                if instance_cols_string.contains_key(&res.1.name)
+                    || self.parameters.contains(&res.1.name)
+                {
                     let sa = smt::get_assert(
                         printer,
                         res.1.name.clone(),
-                        res.1.value.element.clone(),
+                        self.decimal_literal(&res.1.value.element)?,
                         NodeType::Instance,
                         Operation::NotEqual,
                     ).context("Failled to generate assert!")?;
@@ -708,7 +3889,30 @@ impl<'b, F: Field> Analyzer<F> {
             for var in negated_model_variable_assignments.iter() {
                 neg_model.push_str(var);
             }
-            smt::write_assert_bool(printer, neg_model, Operation::Or);
+            smt::write_assert_bool(printer, neg_model.clone(), Operation::Or);
+            self.blocking_clauses.push(neg_model.clone());
+
+            if let Some(path) = &self.checkpoint_path {
+                checkpoint.iteration = i;
+                checkpoint.blocking_clauses.push(neg_model);
+                checkpoint
+                    .save(path)
+                    .context("Failed to save checkpoint!")?;
+            }
+
+            if let Some(patience) = self.early_stop_patience {
+                if behaviors.insert(behavior) {
+                    stale_iterations = 0;
+                } else {
+                    stale_iterations += 1;
+                }
+                if stale_iterations >= patience {
+                    if !self.quiet {
+                        println!("Stopped early after {} iterations: the last {} models revealed no new witness behavior.", i, patience);
+                    }
+                    break;
+                }
+            }
         }
         Ok(result)
     }
@@ -735,14 +3939,15 @@ impl<'b, F: Field> Analyzer<F> {
     }
     // Solves the SMT formula in the specified file and retrieves the model result.
     ///
-    /// This function solves the SMT formula in the given `smt_file_path` by executing the CVC5 solver.
+    /// This function solves the SMT formula in the given `smt_file_path` with the provided `solver`.
     /// It appends the necessary commands to the SMT file for checking satisfiability and retrieving values
-    /// for the specified variables. The function then runs the CVC5 solver and captures its output.
+    /// for the specified variables. The function then passes the script to the solver and captures its output.
     /// The output is parsed to extract the model result, which is returned as a `ModelResult`.
     ///
     pub fn solve_and_get_model(
         smt_file_path: String,
-        variables: &HashSet<String>,
+        variables: &BTreeSet<String>,
+        solver: &dyn Solver,
     ) -> Result<ModelResult> {
         let smt_file_copy_path =
             Self::generate_copy_path(smt_file_path).context("Failed to generate copy path!")?;
@@ -757,12 +3962,63 @@ impl<'b, F: Field> Analyzer<F> {
         for var in variables.iter() {
             smt::write_get_value(&mut copy_printer, var.clone());
         }
-        let output = Command::new("cvc5").arg(smt_file_copy_path).output();
-        let term = output.unwrap();
-        let output_string = String::from_utf8_lossy(&term.stdout);
+        let smt = fs::read_to_string(smt_file_copy_path).context("Failed to read smt file!")?;
+        smt_validator::validate(&smt).context("Malformed smt file!")?;
+        let output_string = solver.solve(&smt).context("Failed to run solver!")?;
 
-        smt_parser::extract_model_response(output_string.to_string())
-            .context("Failed to parse smt result!")
+        smt_parser::extract_model_response(output_string).context("Failed to parse smt result!")
+    }
+    /// Solves the SMT formula in the specified file and returns the model as a `SolvedModel`.
+    ///
+    /// This function runs `solve_and_get_model` with the solver of the analyzer and joins the result with the cell
+    /// locations (see `solved_model`). An unsatisfiable formula yields no assignments.
+    ///
+    pub fn solve_and_get_solved_model(
+        &self,
+        smt_file_path: String,
+        variables: &BTreeSet<String>,
+    ) -> Result<SolvedModel<F>> {
+        let model = Self::solve_and_get_model(smt_file_path, variables, self.solver.as_ref())?;
+        self.solved_model(&model)
+    }
+    /// Solves an existing SMT file without encoding a circuit.
+    ///
+    /// This function skips the decomposition of the circuit and runs `solver` on the SMT file at `smt_file_path`,
+    /// e.g. an `out.smt2` written by a previous analysis. As in `solve_and_get_model`, the commands for checking
+    /// satisfiability and retrieving the values of `variables` are appended to a copy, leaving the file untouched.
+    ///
+    pub fn solve_existing(
+        smt_file_path: &str,
+        variables: &BTreeSet<String>,
+        solver: &dyn Solver,
+    ) -> Result<ModelResult> {
+        if !Path::new(smt_file_path).is_file() {
+            return Err(anyhow::anyhow!(
+                "SMT file {} does not exist!",
+                smt_file_path
+            ));
+        }
+        fs::create_dir_all("src/output/").context("Failed to create output directory!")?;
+        Self::solve_and_get_model(smt_file_path.to_owned(), variables, solver)
+    }
+    /// Warns about circuits that define no gates.
+    ///
+    /// Without gates, the cells of the circuit are only constrained by lookups and copy constraints, so the passes
+    /// would report many unconstrained cells without pointing at the cause: the circuit most likely never configured
+    /// its constraints. The warning is logged in the `self.log` vector, once, for the pass `analyzer_type`.
+    ///
+    fn warn_no_gates(&mut self, analyzer_type: AnalyzerType) {
+        if !self.cs.gates.is_empty() {
+            return;
+        }
+        let warning = "no gates: the circuit defines no gates, so its cells are only constrained by lookups and copy constraints (check that `configure` creates the constraints of the circuit).";
+        if self.log.iter().any(|finding| finding.message == warning) {
+            return;
+        }
+        if !self.quiet {
+            println!("Warning: {}", warning);
+        }
+        self.log.log_finding(analyzer_type, warning.to_owned());
     }
     /// Dispatches the analysis based on the specified analyzer type.
     ///
@@ -772,10 +4028,39 @@ impl<'b, F: Field> Analyzer<F> {
     /// - `UnusedGates`: Analyzes and identifies unused custom gates in the circuit.
     /// - `UnconstrainedCells`: Analyzes and identifies cells with unconstrained values in the circuit.
     /// - `UnusedColumns`: Analyzes and identifies unused columns in the circuit.
+    /// - `AlwaysViolatedGates`: Analyzes and identifies gates that reduce to a nonzero constant.
+    /// - `ConstantLookupColumns`: Analyzes and identifies lookup table columns holding a single value.
+    /// - `NamingCollisions`: Analyzes and identifies gates and regions sharing a name.
+    /// - `LinearColumns`: Analyzes and identifies advice columns only used in linear constraints.
+    /// - `Properties`: Checks that the circuit implies the properties registered with `add_property`.
+    /// - `UnassignedCells`: Analyzes and identifies cells read by gates but never assigned.
+    /// - `AlwaysOnSelectors`: Analyzes and identifies selectors enabled wherever their gates apply.
+    /// - `InstanceRotations`: Analyzes and identifies instance columns read at multiple rotations.
+    /// - `DoubleAssignedCells`: Analyzes and identifies cells assigned more than once within a region.
+    /// - `InertGates`: Analyzes and identifies gates multiplied by an all-zero fixed column.
+    /// - `NonConstrainingLookups`: Analyzes and identifies lookups whose table covers the bound set for their input.
+    /// - `NoopGates`: Analyzes and identifies gates with polynomials that are identically zero.
+    /// - `FixedTrivialGates`: Analyzes and identifies gates that are identically zero once fixed values are known.
+    /// - `BooleanFixedColumns`: Analyzes and identifies fixed columns only holding 0 and 1, as selector candidates.
+    /// - `EqualInstanceColumns`: Analyzes and identifies instance columns forced equal by the constraints.
+    /// - `HighDegreeGates`: Analyzes and identifies gates of degree above `DEFAULT_TARGET_DEGREE`, with split points.
+    /// - `OffByOneSelectors`: Analyzes and identifies selectors enabled one row away from where their gates fit.
+    /// - `ConstantPropagation`: Analyzes and identifies gates reading cells pinned to a constant by another gate.
+    /// - `ConstantDrift`: Analyzes and identifies gate constants one away from the values of a related lookup table.
+    /// - `UnrangedLookupInputs`: Analyzes and identifies lookup input columns that no gate range-checks.
+    /// - `UniformColumns`: Analyzes and identifies advice columns constrained by the same gates in every region.
+    /// - `OrphanedSelectors`: Analyzes and identifies gates whose selectors are only enabled in regions without their columns.
+    /// - `MutuallyExclusiveSelectors`: Analyzes and identifies regions enabling selectors that a gate forbids together.
+    /// - `SparseRegions`: Analyzes and identifies regions with a constraint density below `DEFAULT_DENSITY_THRESHOLD`.
+    /// - `DeadInstances`: Analyzes and identifies instance columns whose coefficient folds to zero in every gate.
+    /// - `SelectorBleed`: Analyzes and identifies regions enabling a gate's selector without assigning its columns.
+    /// - `DeterminedCells`: Analyzes and identifies advice cells fully determined by the public inputs, which are
+    ///   retrieved from the user as for `UnderconstrainedCircuit` (random inputs leave them free).
     /// - `UnderconstrainedCircuit`: Analyzes the circuit for underconstrained properties by
     ///   retrieving user input for specific instance columns and conducting analysis.
     ///
-    /// The function performs the analysis and updates the internal state accordingly.
+    /// The function performs the analysis and updates the internal state accordingly. A circuit without any gate
+    /// is warned about first (see `warn_no_gates`), since the findings of the passes would only be symptoms of it.
     ///
     pub fn dispatch_analysis(
         &mut self,
@@ -783,10 +4068,62 @@ impl<'b, F: Field> Analyzer<F> {
         fixed: Vec<Vec<CellValue<F>>>,
         prime: &str,
     ) -> Result<AnalyzerOutput> {
+        self.warn_no_gates(analyzer_type);
         match analyzer_type {
             AnalyzerType::UnusedGates => self.analyze_unused_custom_gates(),
             AnalyzerType::UnconstrainedCells => self.analyze_unconstrained_cells(),
             AnalyzerType::UnusedColumns => self.analyze_unused_columns(),
+            AnalyzerType::AlwaysViolatedGates => self.analyze_always_violated_gates(),
+            AnalyzerType::ConstantLookupColumns => self.analyze_constant_lookup_columns(&fixed),
+            AnalyzerType::NamingCollisions => self.analyze_naming_collisions(),
+            AnalyzerType::LinearColumns => self.analyze_linear_columns(),
+            AnalyzerType::Properties => self.analyze_properties(fixed, prime),
+            AnalyzerType::UnassignedCells => self.analyze_unassigned_cells(),
+            AnalyzerType::AlwaysOnSelectors => self.analyze_always_on_selectors(),
+            AnalyzerType::InstanceRotations => self.analyze_instance_rotations(),
+            AnalyzerType::DoubleAssignedCells => self.analyze_double_assigned_cells(),
+            AnalyzerType::InertGates => self.analyze_inert_gates(&fixed),
+            AnalyzerType::NonConstrainingLookups => self.analyze_non_constraining_lookups(&fixed),
+            AnalyzerType::NoopGates => self.analyze_noop_gates(),
+            AnalyzerType::FixedTrivialGates => self.analyze_fixed_trivial_gates(&fixed),
+            AnalyzerType::BooleanFixedColumns => self.analyze_boolean_fixed_columns(&fixed),
+            AnalyzerType::EqualInstanceColumns => self.analyze_equal_instance_columns(fixed, prime),
+            AnalyzerType::HighDegreeGates => self.analyze_high_degree_gates(DEFAULT_TARGET_DEGREE),
+            AnalyzerType::OffByOneSelectors => self.analyze_off_by_one_selectors(),
+            AnalyzerType::ConstantPropagation => self.analyze_constant_propagation(),
+            AnalyzerType::ConstantDrift => self.analyze_constant_drift(&fixed),
+            AnalyzerType::UnrangedLookupInputs => self.analyze_unranged_lookup_inputs(),
+            AnalyzerType::UniformColumns => self.analyze_uniform_columns(),
+            AnalyzerType::OrphanedSelectors => self.analyze_orphaned_selectors(),
+            AnalyzerType::MutuallyExclusiveSelectors => self.analyze_mutually_exclusive_selectors(),
+            AnalyzerType::SparseRegions => self.analyze_sparse_regions(DEFAULT_DENSITY_THRESHOLD),
+            AnalyzerType::DeadInstances => self.analyze_dead_instances(&fixed),
+            AnalyzerType::SelectorBleed => self.analyze_selector_bleed(),
+            AnalyzerType::DeterminedCells => {
+                let mut instance_cols_string =
+                    self.extract_instance_cols(self.layouter.eq_table.clone());
+                instance_cols_string.extend(self.extract_instance_cols_from_region());
+                let analyzer_input: AnalyzerInput =
+                    retrieve_user_input_for_underconstrained(&instance_cols_string)
+                        .context("Failed to retrieve user input!")?;
+                let mut inputs = BTreeMap::new();
+                if matches!(
+                    analyzer_input.verification_method,
+                    VerificationMethod::Specific
+                ) {
+                    for (name, value) in analyzer_input.verification_input.instances_string.iter() {
+                        let location = name_formatter::parse_default_name(name)
+                            .ok_or_else(|| anyhow!("Cannot locate input cell {}!", name))?;
+                        let value = if *value >= 0 {
+                            F::from(*value as u64)
+                        } else {
+                            -F::from(value.unsigned_abs())
+                        };
+                        inputs.insert(location, value);
+                    }
+                }
+                self.analyze_determined_cells(&inputs, fixed, prime)
+            }
             AnalyzerType::UnderconstrainedCircuit => {
                 let mut instance_cols_string =
                     self.extract_instance_cols(self.layouter.eq_table.clone());
@@ -0,0 +1,23 @@
+use std::fmt::Debug;
+
+use halo2_proofs::arithmetic::FieldExt as Field;
+
+use crate::circuit_analyzer::analyzer::field_to_decimal;
+
+/// Produces the SMT literal of a field element.
+///
+/// Literals must be terms of sort `F`, the finite field declared at the start of the encoding. Solvers differ in
+/// the syntaxes they accept, e.g. `(as ff5 F)` or `#f5m11`.
+pub trait FieldEncoder<F: Field>: Debug {
+    fn encode(&self, value: &F) -> String;
+}
+
+/// The default `(as ff{value} F)` literals, e.g. `(as ff5 F)`.
+#[derive(Debug, Default)]
+pub struct DefaultFieldEncoder;
+
+impl<F: Field> FieldEncoder<F> for DefaultFieldEncoder {
+    fn encode(&self, value: &F) -> String {
+        format!("(as ff{} F)", field_to_decimal(value))
+    }
+}
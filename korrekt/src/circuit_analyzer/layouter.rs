@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::cmp;
+use std::collections::{BTreeMap, HashMap};
 use std::marker::PhantomData;
 
 use halo2_proofs::arithmetic::FieldExt as Field;
@@ -6,7 +7,7 @@ use halo2_proofs::circuit::{Cell, Layouter, Region, Table};
 use halo2_proofs::plonk::Error;
 use halo2_proofs::plonk::{Column, Instance};
 
-use halo2_proofs::circuit::layouter::RegionLayouter;
+use halo2_proofs::circuit::layouter::{RegionColumn, RegionLayouter};
 
 use crate::circuit_analyzer::shape::AnalyticalShape;
 
@@ -14,7 +15,9 @@ use crate::circuit_analyzer::shape::AnalyticalShape;
 pub struct AnalyticLayouter<F: Field> {
     pub regions: Vec<AnalyticalShape>,
     _ph: PhantomData<F>,
-    pub eq_table: HashMap<String, String>,
+    pub eq_table: BTreeMap<String, String>,
+    /// The first free row of each column, to place the regions as `SimpleFloorPlanner` does.
+    columns: HashMap<RegionColumn, usize>,
 }
 
 impl<F: Field> AnalyticLayouter<F> {
@@ -22,7 +25,8 @@ impl<F: Field> AnalyticLayouter<F> {
         Self {
             regions: vec![],
             _ph: PhantomData,
-            eq_table: HashMap::new(),
+            eq_table: BTreeMap::new(),
+            columns: HashMap::new(),
         }
     }
 }
@@ -50,6 +54,17 @@ impl<'a, F: Field> Layouter<F> for &'a mut AnalyticLayouter<F> {
         let result = assignment(region.into())?;
         let _a = assignment;
 
+        // Place the region as `SimpleFloorPlanner` does: at the first row from which all its columns are free.
+        let columns: Vec<RegionColumn> = shape.columns.iter().map(|(column, _)| *column).collect();
+        shape.row_offset = columns
+            .iter()
+            .map(|column| self.columns.get(column).copied().unwrap_or(0))
+            .fold(0, cmp::max);
+        for column in columns {
+            self.columns
+                .insert(column, shape.row_offset + shape.row_count);
+        }
+
         // save region
 
         self.regions.push(shape);
@@ -79,12 +94,11 @@ impl<'a, F: Field> Layouter<F> for &'a mut AnalyticLayouter<F> {
             cell.row_offset
         );
 
-        let right = format!("A-{}-{}-{:?}", cell.region_index.0, column.index(), row);
+        // Instance cells are named as in `assign_advice_from_instance`.
+        let right = format!("I-{}-{}", column.index(), row);
 
         self.eq_table.insert(left, right);
         Ok(())
-
-        //todo!("handle instance columns"); Quantstamp ticket ZKR-1881
     }
 
     fn get_root(&mut self) -> &mut Self::Root {
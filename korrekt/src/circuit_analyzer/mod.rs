@@ -1,4 +1,6 @@
 pub mod abstract_expr;
 pub mod analyzer;
+pub mod field_encoder;
 pub mod layouter;
+pub mod name_formatter;
 pub mod shape;
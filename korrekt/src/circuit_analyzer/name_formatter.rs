@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+
+/// The kind of column a cell belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CellKind {
+    Advice,
+    Fixed,
+    Instance,
+}
+
+impl CellKind {
+    /// Returns the prefix of the cells of this kind in the default naming scheme.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            CellKind::Advice => "A",
+            CellKind::Fixed => "F",
+            CellKind::Instance => "I",
+        }
+    }
+}
+
+/// A cell referenced by the SMT encoding.
+///
+/// Instance cells belong to no region: their `region` is `None` and their `row` is the absolute row of the public
+/// input, so that every region reading or binding the same public input refers to the same cell.
+#[derive(Debug, Clone)]
+pub struct CellRef<'a> {
+    pub kind: CellKind,
+    pub region: Option<usize>,
+    pub region_name: &'a str,
+    pub column: usize,
+    pub row: i32,
+}
+
+impl CellRef<'_> {
+    /// Returns the location of the cell, without its region name.
+    pub fn location(&self) -> CellLocation {
+        CellLocation {
+            kind: self.kind,
+            region: self.region,
+            column: self.column,
+            row: self.row,
+        }
+    }
+}
+
+/// The location of a cell: its kind, region, column and row within the region.
+///
+/// As in `CellRef`, instance cells have no region and an absolute row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CellLocation {
+    pub kind: CellKind,
+    pub region: Option<usize>,
+    pub column: usize,
+    pub row: i32,
+}
+
+/// Produces the SMT identifier of a cell.
+///
+/// Names must be distinct for distinct cells. They do not need to be valid SMT-LIB symbols: the analyzer passes them
+/// through `to_identifier`, which quotes them when needed.
+pub trait NameFormatter: Debug {
+    fn cell_name(&self, cell: &CellRef) -> String;
+}
+
+/// The default `{A,F}-{region}-{column}-{row}` naming scheme, e.g. `A-0-2-1`, with instance cells named
+/// `I-{column}-{row}`.
+#[derive(Debug, Default)]
+pub struct DefaultNameFormatter;
+
+impl NameFormatter for DefaultNameFormatter {
+    fn cell_name(&self, cell: &CellRef) -> String {
+        match cell.region {
+            Some(region) => format!(
+                "{}-{}-{}-{}",
+                cell.kind.prefix(),
+                region,
+                cell.column,
+                cell.row
+            ),
+            None => format!("{}-{}-{}", cell.kind.prefix(), cell.column, cell.row),
+        }
+    }
+}
+
+/// A readable naming scheme using region names, e.g. `main.advice[2]@row0`, or `instance[0]@row0`.
+#[derive(Debug, Default)]
+pub struct RegionNameFormatter;
+
+impl NameFormatter for RegionNameFormatter {
+    fn cell_name(&self, cell: &CellRef) -> String {
+        let kind = match cell.kind {
+            CellKind::Advice => "advice",
+            CellKind::Fixed => "fixed",
+            CellKind::Instance => "instance",
+        };
+        match cell.region {
+            Some(_) => format!(
+                "{}.{}[{}]@row{}",
+                cell.region_name, kind, cell.column, cell.row
+            ),
+            None => format!("{}[{}]@row{}", kind, cell.column, cell.row),
+        }
+    }
+}
+
+/// Parses a cell name of the default naming scheme into its location.
+pub fn parse_default_name(name: &str) -> Option<CellLocation> {
+    let (prefix, indices) = name.split_once('-')?;
+    let kind = match prefix {
+        "A" => CellKind::Advice,
+        "F" => CellKind::Fixed,
+        "I" => CellKind::Instance,
+        _ => return None,
+    };
+    let mut indices = indices.split('-');
+    let region = match kind {
+        CellKind::Instance => None,
+        _ => Some(indices.next()?.parse().ok()?),
+    };
+    let column = indices.next()?.parse().ok()?;
+    let row = indices.next()?.parse().ok()?;
+    if indices.next().is_some() {
+        return None;
+    }
+    Some(CellLocation {
+        kind,
+        region,
+        column,
+        row,
+    })
+}
+
+/// Returns `name` as an SMT-LIB symbol.
+///
+/// Simple symbols (letters, digits and `~!@$%^&*_-+=<>.?/`, not starting with a digit) are returned as they are.
+/// Other names are quoted as `|name|`, after dropping the `|` and `\` characters quoted symbols cannot contain.
+pub fn to_identifier(name: &str) -> String {
+    let is_simple = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "~!@$%^&*_-+=<>.?/".contains(c));
+    if is_simple {
+        name.to_owned()
+    } else {
+        let quoted: String = name.chars().filter(|c| *c != '|' && *c != '\\').collect();
+        format!("|{}|", quoted)
+    }
+}
@@ -1,11 +1,13 @@
-use halo2_proofs::arithmetic::Field;
+use halo2_proofs::arithmetic::FieldExt as Field;
 use halo2_proofs::circuit::layouter::{RegionColumn, RegionLayouter};
 use halo2_proofs::circuit::{Cell, RegionIndex, Value};
 use halo2_proofs::plonk::{Advice, Any, Assigned, Column, Error, Fixed, Instance, Selector};
 use halo2_proofs::poly::Rotation;
 
 use std::cmp;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::circuit_analyzer::analyzer::field_to_decimal;
 
 #[derive(Clone, Debug)]
 pub struct AnalyticalShape {
@@ -14,11 +16,18 @@ pub struct AnalyticalShape {
     pub selectors: HashSet<RegionColumn>,
     pub columns: HashSet<(RegionColumn, Rotation)>,
     pub row_count: usize,
+    /// The absolute row the region starts at, see `AnalyticLayouter::assign_region`.
+    pub row_offset: usize,
 
     pub enabled_selectors: HashSet<String>,
 
-    pub advice_eq_table: HashMap<String, String>,
-    pub eq_table: HashMap<String, String>,
+    pub advice_eq_table: BTreeMap<String, String>,
+    pub eq_table: BTreeMap<String, String>,
+    /// The decimal value assigned to each fixed cell of the region, keyed by its name, e.g. `F-0-1-2`.
+    pub fixed_values: BTreeMap<String, String>,
+
+    /// Every advice and fixed cell assignment, in order, including repeated assignments of the same cell.
+    pub assignments: Vec<(Column<Any>, usize)>,
 }
 
 impl AnalyticalShape {
@@ -28,10 +37,13 @@ impl AnalyticalShape {
             columns: HashSet::new(),
             selectors: HashSet::new(),
             row_count: 0,
+            row_offset: 0,
             name,
             enabled_selectors: HashSet::new(),
-            advice_eq_table: HashMap::new(),
-            eq_table: HashMap::new(),
+            advice_eq_table: BTreeMap::new(),
+            eq_table: BTreeMap::new(),
+            fixed_values: BTreeMap::new(),
+            assignments: vec![],
         }
     }
 
@@ -74,6 +86,7 @@ impl<F: Field> RegionLayouter<F> for AnalyticalShape {
         offset: usize,
         _to: &'v mut (dyn FnMut() -> Value<Assigned<F>> + 'v),
     ) -> Result<Cell, Error> {
+        self.assignments.push((column.into(), offset));
         self.columns
             .insert((Column::<Any>::from(column).into(), Rotation(offset as i32)));
         self.row_count = cmp::max(self.row_count, offset + 1);
@@ -104,7 +117,8 @@ impl<F: Field> RegionLayouter<F> for AnalyticalShape {
         advice: Column<Advice>,
         offset: usize,
     ) -> Result<(Cell, Value<F>), Error> {
-        let left = format!("I-{:?}-{}-{:?}", self.region_index.0, instance.index(), row);
+        // Public inputs belong to no region, they are named by their absolute row.
+        let left = format!("I-{}-{}", instance.index(), row);
 
         let right = format!(
             "A-{:?}-{}-{:?}",
@@ -114,6 +128,7 @@ impl<F: Field> RegionLayouter<F> for AnalyticalShape {
         );
 
         self.eq_table.insert(left, right);
+        self.assignments.push((advice.into(), offset));
         self.columns
             .insert((Column::<Any>::from(advice).into(), Rotation(offset as i32)));
         self.row_count = cmp::max(self.row_count, offset + 1);
@@ -133,8 +148,21 @@ impl<F: Field> RegionLayouter<F> for AnalyticalShape {
         _: &'v (dyn Fn() -> String + 'v),
         column: Column<Fixed>,
         offset: usize,
-        _to: &'v mut (dyn FnMut() -> Value<Assigned<F>> + 'v),
+        to: &'v mut (dyn FnMut() -> Value<Assigned<F>> + 'v),
     ) -> Result<Cell, Error> {
+        // Fixed values are known at synthesis, keep them to pin the cells copied to this one.
+        if let Ok(value) = to().assign() {
+            self.fixed_values.insert(
+                format!(
+                    "F-{:?}-{}-{:?}",
+                    self.region_index.0,
+                    column.index(),
+                    offset
+                ),
+                field_to_decimal(&value.evaluate()),
+            );
+        }
+        self.assignments.push((column.into(), offset));
         self.columns
             .insert((Column::<Any>::from(column).into(), Rotation(offset as i32)));
         self.row_count = cmp::max(self.row_count, offset + 1);
@@ -153,16 +181,25 @@ impl<F: Field> RegionLayouter<F> for AnalyticalShape {
 
     fn constrain_equal(&mut self, left: Cell, right: Cell) -> Result<(), Error> {
         // Equality constraints don't affect the region shape.
+        fn prefix(column: &Column<Any>) -> &'static str {
+            match column.column_type() {
+                Any::Advice => "A",
+                Any::Fixed => "F",
+                Any::Instance => "I",
+            }
+        }
 
         let left_name = format!(
-            "A-{:?}-{}-{:?}",
+            "{}-{:?}-{}-{:?}",
+            prefix(&left.column),
             left.region_index.0,
             left.column.index(),
             left.row_offset
         );
 
         let right_name = format!(
-            "A-{:?}-{}-{:?}",
+            "{}-{:?}-{}-{:?}",
+            prefix(&right.column),
             right.region_index.0,
             right.column.index(),
             right.row_offset
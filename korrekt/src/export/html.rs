@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::io::analyzer_io_type::{AnalyzerType, Finding, Severity};
+
+/// Escapes the characters of `text` that have a meaning in HTML, or XML.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Returns the region a finding is about, if its message names one as `region "name"`.
+fn region(pattern: &Regex, finding: &Finding) -> Option<String> {
+    pattern
+        .captures(&finding.message)
+        .map(|captures| captures[1].to_owned())
+}
+
+/// Renders the findings of an analysis as a standalone HTML page.
+///
+/// The findings are grouped by severity, errors first, then by pass, each pass being a collapsible `<section>`
+/// with the id of the pass (e.g. `UnusedGates`). Within a pass, the findings are grouped by the region their
+/// message names, findings about no particular region coming first. `graph_svg`, e.g. a rendering of the circuit
+/// graph, is embedded as is at the end of the page.
+pub fn render(findings: &[Finding], graph_svg: Option<&str>) -> Result<String> {
+    let pattern = Regex::new(r#"region "([^"]*)""#).context("Failed to compile regex!")?;
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>halo2-analyzer report</title>\n</head>\n<body>\n");
+    html.push_str("<h1>halo2-analyzer report</h1>\n");
+    html.push_str(&format!("<p>{} finding(s).</p>\n", findings.len()));
+
+    for severity in [Severity::Error, Severity::Warning] {
+        let mut passes: BTreeMap<usize, (AnalyzerType, BTreeMap<Option<String>, Vec<&Finding>>)> =
+            BTreeMap::new();
+        for finding in findings
+            .iter()
+            .filter(|finding| finding.severity() == severity)
+        {
+            // Passes are listed in declaration order.
+            let order = AnalyzerType::ALL
+                .iter()
+                .position(|pass| *pass == finding.pass)
+                .unwrap_or(usize::MAX);
+            passes
+                .entry(order)
+                .or_insert_with(|| (finding.pass, BTreeMap::new()))
+                .1
+                .entry(region(&pattern, finding))
+                .or_default()
+                .push(finding);
+        }
+        if passes.is_empty() {
+            continue;
+        }
+        html.push_str(&format!(
+            "<details open>\n<summary><h2>{:?}s</h2></summary>\n",
+            severity
+        ));
+        for (pass, regions) in passes.values() {
+            let count: usize = regions.values().map(Vec::len).sum();
+            html.push_str(&format!(
+                "<section id=\"{:?}\">\n<details open>\n<summary><h3>{:?} ({})</h3></summary>\n",
+                pass, pass, count
+            ));
+            for (region, findings) in regions {
+                match region {
+                    Some(region) => {
+                        html.push_str(&format!("<h4>Region &quot;{}&quot;</h4>\n", escape(region)))
+                    }
+                    None => html.push_str("<h4>Circuit</h4>\n"),
+                }
+                html.push_str("<ul>\n");
+                for finding in findings {
+                    html.push_str(&format!("<li>{}</li>\n", escape(&finding.message)));
+                }
+                html.push_str("</ul>\n");
+            }
+            html.push_str("</details>\n</section>\n");
+        }
+        html.push_str("</details>\n");
+    }
+
+    if let Some(graph_svg) = graph_svg {
+        html.push_str("<details>\n<summary><h2>Circuit graph</h2></summary>\n");
+        html.push_str(graph_svg);
+        html.push_str("\n</details>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+/// Writes the HTML report of `findings` (see `render`) to `path`.
+pub fn export(path: &str, findings: &[Finding], graph_svg: Option<&str>) -> Result<()> {
+    let html = render(findings, graph_svg)?;
+    fs::write(path, html).context(format!("Failed to write the report {}!", path))
+}
@@ -0,0 +1,73 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use super::html::escape;
+use crate::io::analyzer_io::exit_code;
+use crate::io::analyzer_io_type::{AnalyzerOutput, AnalyzerType, Finding};
+
+/// Renders the results of the passes run on a circuit as a JUnit XML report, one `<testcase>` per pass.
+///
+/// A pass fails when its exit code would be non-zero (see `analyzer_io::exit_code`): an error finding, any finding
+/// if `strict`, or an under- or over-constrained circuit. The failure lists the findings of the pass, one per line.
+/// The findings of a passing pass, e.g. warnings, are kept in its `<system-out>`.
+pub fn render(
+    results: &[(AnalyzerType, AnalyzerOutput)],
+    findings: &[Finding],
+    strict: bool,
+) -> String {
+    let mut testcases = String::new();
+    let mut failures = 0;
+    for (pass, output) in results {
+        let pass_findings: Vec<Finding> = findings
+            .iter()
+            .filter(|finding| finding.pass == *pass)
+            .cloned()
+            .collect();
+        let messages: Vec<String> = pass_findings
+            .iter()
+            .map(|finding| escape(&finding.message))
+            .collect();
+        testcases.push_str(&format!(
+            "    <testcase classname=\"halo2-analyzer\" name=\"{:?}\">\n",
+            pass
+        ));
+        if exit_code(output, &pass_findings, strict) != 0 {
+            failures += 1;
+            testcases.push_str(&format!(
+                "      <failure message=\"{:?}: {} finding(s)\" type=\"{:?}\">{}</failure>\n",
+                output.output_status,
+                pass_findings.len(),
+                output.output_status,
+                messages.join("\n")
+            ));
+        } else if !messages.is_empty() {
+            testcases.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                messages.join("\n")
+            ));
+        }
+        testcases.push_str("    </testcase>\n");
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    xml.push_str(&format!(
+        "  <testsuite name=\"halo2-analyzer\" tests=\"{}\" failures=\"{}\">\n",
+        results.len(),
+        failures
+    ));
+    xml.push_str(&testcases);
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Writes the JUnit XML report of the passes (see `render`) to `path`.
+pub fn export(
+    path: &str,
+    results: &[(AnalyzerType, AnalyzerOutput)],
+    findings: &[Finding],
+    strict: bool,
+) -> Result<()> {
+    fs::write(path, render(results, findings, strict))
+        .context(format!("Failed to write the report {}!", path))
+}
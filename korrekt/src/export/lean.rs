@@ -0,0 +1,99 @@
+use halo2_proofs::{
+    arithmetic::FieldExt as Field,
+    plonk::{ConstraintSystem, Expression},
+    poly::Rotation,
+};
+
+/// Returns a Lean identifier for a gate name, replacing characters Lean does not accept.
+fn gate_identifier(name: &str, gate_no: usize) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.is_empty() {
+        format!("gate_{}", gate_no)
+    } else {
+        format!("gate_{}", name)
+    }
+}
+
+/// Returns the Lean row index `row` shifted by `rotation`.
+fn row(rotation: &Rotation) -> String {
+    match rotation.0 {
+        0 => "row".to_owned(),
+        r if r > 0 => format!("(row + {})", r),
+        r => format!("(row - {})", -r),
+    }
+}
+
+/// Renders an `Expression` as a Lean term over `ZMod p`.
+///
+/// The traversal follows `decompose_expression`: columns become applications of the `adv`, `fix` and `inst`
+/// functions to a column index and a row, selectors applications of `sel`, and every operation is parenthesized.
+pub fn render_expression<F: Field>(poly: &Expression<F>) -> String {
+    match poly {
+        Expression::Constant(a) => format!("{}", a.get_lower_128()),
+        Expression::Selector(a) => format!("sel {} row", a.0),
+        Expression::Fixed(fixed_query) => format!(
+            "fix {} {}",
+            fixed_query.column_index,
+            row(&fixed_query.rotation)
+        ),
+        Expression::Advice(advice_query) => format!(
+            "adv {} {}",
+            advice_query.column_index,
+            row(&advice_query.rotation)
+        ),
+        Expression::Instance(instance_query) => format!(
+            "inst {} {}",
+            instance_query.column_index,
+            row(&instance_query.rotation)
+        ),
+        Expression::Negated(poly) => format!("(-({}))", render_expression(poly)),
+        Expression::Sum(a, b) => format!("({} + {})", render_expression(a), render_expression(b)),
+        Expression::Product(a, b) => {
+            format!("({} * {})", render_expression(a), render_expression(b))
+        }
+        Expression::Scaled(poly, c) => {
+            format!("({} * {})", c.get_lower_128(), render_expression(poly))
+        }
+    }
+}
+
+/// Exports the custom gates of a constraint system as Lean 4 definitions.
+///
+/// Every gate polynomial becomes a `def` computing its value at a row, and every gate a `Prop` stating that all of
+/// its polynomials vanish on every row. Columns and selectors are parameters (`adv`, `fix`, `inst`, `sel`), so
+/// statements about a concrete witness can be proven by instantiating them.
+pub fn export_gates<F: Field>(cs: &ConstraintSystem<F>) -> String {
+    let columns = "(adv fix inst sel : ℕ → ℤ → ZMod p)";
+    let mut lean = String::new();
+    lean.push_str("import Mathlib.Data.ZMod.Basic\n\n");
+    lean.push_str("namespace Circuit\n\n");
+    lean.push_str("variable {p : ℕ}\n\n");
+    for (gate_no, gate) in cs.gates.iter().enumerate() {
+        let identifier = gate_identifier(gate.name(), gate_no);
+        let mut polys = vec![];
+        for (poly_no, poly) in gate.polynomials().iter().enumerate() {
+            let poly_identifier = format!("{}_{}", identifier, poly_no);
+            lean.push_str(&format!(
+                "/-- Gate \"{}\", polynomial {}. -/\ndef {} {} (row : ℤ) : ZMod p :=\n  {}\n\n",
+                gate.name(),
+                poly_no,
+                poly_identifier,
+                columns,
+                render_expression(poly)
+            ));
+            polys.push(format!("{} adv fix inst sel row = 0", poly_identifier));
+        }
+        lean.push_str(&format!(
+            "/-- Gate \"{}\" holds on every row. -/\ndef {}_holds {} : Prop :=\n  ∀ row : ℤ, {}\n\n",
+            gate.name(),
+            identifier,
+            columns,
+            polys.join(" ∧ ")
+        ));
+    }
+    lean.push_str("end Circuit\n");
+    lean
+}
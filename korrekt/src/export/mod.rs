@@ -0,0 +1,6 @@
+pub mod html;
+pub mod junit;
+pub mod lean;
+pub mod r1cs;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
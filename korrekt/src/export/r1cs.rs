@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use halo2_proofs::{
+    arithmetic::FieldExt as Field,
+    plonk::{ConstraintSystem, Expression},
+};
+
+use crate::circuit_analyzer::abstract_expr;
+
+/// The wire holding the constant 1.
+pub const ONE: &str = "one";
+
+/// A linear combination of wires, as `(coefficient, wire)` pairs. An empty combination is 0.
+pub type LinearCombination<F> = Vec<(F, String)>;
+
+/// A constraint `a * b = c` between linear combinations, derived from a gate polynomial.
+#[derive(Debug)]
+pub struct Constraint<F: Field> {
+    pub gate: String,
+    pub polynomial: usize,
+    pub a: LinearCombination<F>,
+    pub b: LinearCombination<F>,
+    pub c: LinearCombination<F>,
+}
+
+/// A gate polynomial that cannot be expressed as a single R1CS constraint.
+#[derive(Debug)]
+pub struct Unsupported {
+    pub gate: String,
+    pub polynomial: usize,
+    pub reason: String,
+}
+
+/// The constraints exported from a constraint system, together with the polynomials that could not be exported.
+#[derive(Debug)]
+pub struct R1cs<F: Field> {
+    pub constraints: Vec<Constraint<F>>,
+    pub unsupported: Vec<Unsupported>,
+}
+
+/// Expands an `Expression` into a sum of monomials, mapping the (sorted) wires of each monomial to its coefficient.
+///
+/// Wires are named by `abstract_expr::canonicalize`, e.g. `adv0[1]` for advice column 0 at the next row. Selectors
+/// are replaced with 1: the constraints describe a row on which the gate is enabled.
+fn expand<F: Field>(poly: &Expression<F>) -> BTreeMap<Vec<String>, F> {
+    let mut monomials = BTreeMap::new();
+    for (wires, coefficient) in abstract_expr::canonicalize(poly) {
+        let wires = wires
+            .into_iter()
+            .filter(|wire| !wire.starts_with("sel"))
+            .collect();
+        *monomials.entry(wires).or_insert_with(F::zero) += coefficient;
+    }
+    monomials.retain(|_, coefficient| *coefficient != F::zero());
+    monomials
+}
+
+/// Linearizes a gate polynomial into a constraint `a * b = c`, or reports why it cannot be.
+fn linearize<F: Field>(
+    gate: &str,
+    polynomial: usize,
+    poly: &Expression<F>,
+) -> Result<Constraint<F>, Unsupported> {
+    let unsupported = |reason| Unsupported {
+        gate: gate.to_owned(),
+        polynomial,
+        reason,
+    };
+    let mut quadratic = vec![];
+    let mut linear = vec![];
+    for (wires, coefficient) in expand(poly) {
+        match wires.len() {
+            0 => linear.push((coefficient, ONE.to_owned())),
+            1 => linear.push((coefficient, wires[0].clone())),
+            2 => quadratic.push((coefficient, wires)),
+            degree => return Err(unsupported(format!("degree {} polynomial", degree))),
+        }
+    }
+    let (a, b, c) = match quadratic.len() {
+        0 => (vec![(F::one(), ONE.to_owned())], linear, vec![]),
+        1 => {
+            let (coefficient, wires) = quadratic.remove(0);
+            let c = linear
+                .into_iter()
+                .map(|(coefficient, wire)| (-coefficient, wire))
+                .collect();
+            (
+                vec![(coefficient, wires[0].clone())],
+                vec![(F::one(), wires[1].clone())],
+                c,
+            )
+        }
+        terms => return Err(unsupported(format!("{} quadratic terms", terms))),
+    };
+    Ok(Constraint {
+        gate: gate.to_owned(),
+        polynomial,
+        a,
+        b,
+        c,
+    })
+}
+
+/// Exports the custom gates of a constraint system as R1CS constraints.
+///
+/// Every gate polynomial of witness degree at most 2 with at most one quadratic term becomes a constraint
+/// `a * b = c` over the wires `adv{column}[{rotation}]`, `fix{column}[{rotation}]`, `inst{column}[{rotation}]` and
+/// `one`. Selectors are replaced with 1. The other polynomials, such as degree-3 gates, are reported in
+/// `R1cs::unsupported`, as they would need auxiliary wires.
+pub fn export_gates<F: Field>(cs: &ConstraintSystem<F>) -> R1cs<F> {
+    let mut r1cs = R1cs {
+        constraints: vec![],
+        unsupported: vec![],
+    };
+    for gate in cs.gates.iter() {
+        for (poly_no, poly) in gate.polynomials().iter().enumerate() {
+            match linearize(gate.name(), poly_no, poly) {
+                Ok(constraint) => r1cs.constraints.push(constraint),
+                Err(unsupported) => r1cs.unsupported.push(unsupported),
+            }
+        }
+    }
+    r1cs
+}
+
+/// Renders a coefficient, as a negative number when its negation is small.
+fn render_coefficient<F: Field>(coefficient: &F) -> String {
+    if F::from_u128(coefficient.get_lower_128()) == *coefficient {
+        coefficient.get_lower_128().to_string()
+    } else if F::from_u128((-*coefficient).get_lower_128()) == -*coefficient {
+        format!("-{}", (-*coefficient).get_lower_128())
+    } else {
+        format!("{:?}", coefficient)
+    }
+}
+
+/// Renders a linear combination, e.g. `2*adv0[0] + -1*one`, omitting coefficients equal to 1.
+pub fn render_linear_combination<F: Field>(lc: &LinearCombination<F>) -> String {
+    if lc.is_empty() {
+        return "0".to_owned();
+    }
+    lc.iter()
+        .map(|(coefficient, wire)| {
+            if *coefficient == F::one() {
+                wire.clone()
+            } else {
+                format!("{}*{}", render_coefficient(coefficient), wire)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Renders the exported constraints one per line, followed by the unsupported polynomials as comments.
+pub fn render<F: Field>(r1cs: &R1cs<F>) -> String {
+    let mut output = String::new();
+    for constraint in r1cs.constraints.iter() {
+        output.push_str(&format!(
+            "({}) * ({}) = ({}) ; gate \"{}\", polynomial {}\n",
+            render_linear_combination(&constraint.a),
+            render_linear_combination(&constraint.b),
+            render_linear_combination(&constraint.c),
+            constraint.gate,
+            constraint.polynomial
+        ));
+    }
+    for unsupported in r1cs.unsupported.iter() {
+        output.push_str(&format!(
+            "; unsupported: gate \"{}\", polynomial {} ({})\n",
+            unsupported.gate, unsupported.polynomial, unsupported.reason
+        ));
+    }
+    output
+}
@@ -0,0 +1,87 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+
+use crate::io::analyzer_io_type::Finding;
+
+/// Creates the `runs` and `findings` tables, unless they already exist.
+///
+/// A run is one analysis of a circuit: its time (seconds since the Unix epoch) and the hash identifying the circuit.
+/// Each finding belongs to a run, so the history of a circuit is `SELECT ... WHERE circuit_hash = ? ORDER BY
+/// timestamp`.
+pub fn create_tables(connection: &Connection) -> Result<()> {
+    connection
+        .execute_batch(
+            "CREATE TABLE IF NOT EXISTS runs (
+                id INTEGER PRIMARY KEY,
+                timestamp INTEGER NOT NULL,
+                circuit_hash TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS findings (
+                run_id INTEGER NOT NULL REFERENCES runs(id),
+                pass TEXT NOT NULL,
+                message TEXT NOT NULL
+            );",
+        )
+        .context("Failed to create the findings tables!")
+}
+
+/// Records a run of `circuit_hash` at `timestamp` with its findings, and returns the id of the run.
+pub fn insert_run(
+    connection: &mut Connection,
+    circuit_hash: &str,
+    timestamp: u64,
+    findings: &[Finding],
+) -> Result<i64> {
+    create_tables(connection)?;
+    let transaction = connection
+        .transaction()
+        .context("Failed to start a transaction!")?;
+    transaction
+        .execute(
+            "INSERT INTO runs (timestamp, circuit_hash) VALUES (?1, ?2)",
+            params![timestamp as i64, circuit_hash],
+        )
+        .context("Failed to insert the run!")?;
+    let run_id = transaction.last_insert_rowid();
+    for finding in findings {
+        transaction
+            .execute(
+                "INSERT INTO findings (run_id, pass, message) VALUES (?1, ?2, ?3)",
+                params![run_id, format!("{:?}", finding.pass), finding.message],
+            )
+            .context("Failed to insert a finding!")?;
+    }
+    transaction.commit().context("Failed to commit the run!")?;
+    Ok(run_id)
+}
+
+/// Appends the findings of a run of `circuit_hash`, made now, to the SQLite database at `path`.
+pub fn export(path: &str, circuit_hash: &str, findings: &[Finding]) -> Result<i64> {
+    let mut connection =
+        Connection::open(path).context(format!("Failed to open the database {}!", path))?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("The system clock is before the Unix epoch!")?
+        .as_secs();
+    insert_run(&mut connection, circuit_hash, timestamp, findings)
+}
+
+/// Reads back the findings of a run, in insertion order.
+pub fn run_findings(connection: &Connection, run_id: i64) -> Result<Vec<Finding>> {
+    let mut statement = connection
+        .prepare("SELECT pass, message FROM findings WHERE run_id = ?1 ORDER BY rowid")
+        .context("Failed to prepare the findings query!")?;
+    let rows = statement
+        .query_map(params![run_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .context("Failed to query the findings!")?;
+    let mut findings = vec![];
+    for row in rows {
+        let (pass, message) = row.context("Failed to read a finding!")?;
+        findings.push(Finding::new(pass.parse()?, message));
+    }
+    Ok(findings)
+}
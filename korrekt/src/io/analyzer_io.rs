@@ -1,10 +1,11 @@
 use anyhow::{anyhow, Context, Result};
-use std::{collections::HashMap, io};
+use std::{collections::BTreeMap, io};
 
 use crate::io::analyzer_io_type::{
-    AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, VerificationInput,
-    VerificationMethod,
+    AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, Finding, Severity,
+    VerificationInput, VerificationMethod,
 };
+use crate::smt_solver::smt_parser::ModelResult;
 /// Retrieves user input for underconstrained circuit analysis.
 ///
 /// This function prompts the user to choose between verifying the circuit for a specific public input
@@ -12,7 +13,7 @@ use crate::io::analyzer_io_type::{
 /// verification type and constructs an `AnalyzerInput` struct to be used in the underconstrained analysis.
 ///
 pub fn retrieve_user_input_for_underconstrained(
-    instance_cols_string: &HashMap<String, i64>,
+    instance_cols_string: &BTreeMap<String, i64>,
 ) -> Result<AnalyzerInput> {
     println!("You can verify the circuit for a specific public input or a random number of public inputs:");
     println!("1. verify the circuit for a specific public input!");
@@ -33,13 +34,13 @@ pub fn retrieve_user_input_for_underconstrained(
         verification_method: VerificationMethod::Random,
         verification_input: VerificationInput {
             iterations: 1,
-            instances_string: HashMap::new(),
+            instances_string: BTreeMap::new(),
         },
     };
 
     match verification_type {
         SPECIFIC => {
-            let mut specified_instance_cols_string: HashMap<String, i64> = HashMap::new();
+            let mut specified_instance_cols_string: BTreeMap<String, i64> = BTreeMap::new();
 
             for var in instance_cols_string.iter() {
                 println!("Enter value for {} : ", var.0);
@@ -109,11 +110,147 @@ pub fn output_result(analyzer_input: AnalyzerInput, analyzer_output: &AnalyzerOu
         AnalyzerOutputStatus::UnusedCustomGates => {}
         AnalyzerOutputStatus::UnconstrainedCells => {}
         AnalyzerOutputStatus::UnusedColumns => {}
+        AnalyzerOutputStatus::AlwaysViolatedGates => {}
+        AnalyzerOutputStatus::ConstantLookupColumns => {}
+        AnalyzerOutputStatus::NamingCollisions => {}
+        AnalyzerOutputStatus::LinearColumns => {}
+        AnalyzerOutputStatus::Properties => {}
+        AnalyzerOutputStatus::UnassignedCells => {}
+        AnalyzerOutputStatus::AlwaysOnSelectors => {}
+        AnalyzerOutputStatus::InstanceRotations => {}
+        AnalyzerOutputStatus::DoubleAssignedCells => {}
+        AnalyzerOutputStatus::InertGates => {}
+        AnalyzerOutputStatus::NonConstrainingLookups => {}
+        AnalyzerOutputStatus::NoopGates => {}
+        AnalyzerOutputStatus::FixedTrivialGates => {}
+        AnalyzerOutputStatus::BooleanFixedColumns => {}
+        AnalyzerOutputStatus::EqualInstanceColumns => {}
+        AnalyzerOutputStatus::DeterminedCells => {}
+        AnalyzerOutputStatus::HighDegreeGates => {}
+        AnalyzerOutputStatus::OffByOneSelectors => {}
+        AnalyzerOutputStatus::ConstantPropagation => {}
+        AnalyzerOutputStatus::ConstantDrift => {}
+        AnalyzerOutputStatus::UnrangedLookupInputs => {}
+        AnalyzerOutputStatus::UniformColumns => {}
+        AnalyzerOutputStatus::OrphanedSelectors => {}
+        AnalyzerOutputStatus::MutuallyExclusiveSelectors => {}
+        AnalyzerOutputStatus::SparseRegions => {}
+        AnalyzerOutputStatus::DeadInstances => {}
+        AnalyzerOutputStatus::SelectorBleed => {}
         AnalyzerOutputStatus::Invalid => {
             println!("The analyzer output is invalid.");
         }
     }
 }
+/// Limits on the models printed to the terminal.
+///
+/// At most `max_assignments` assignments are printed, followed by a `... and N more` note, and values longer than
+/// `max_value_width` characters are cut. This only affects printing: models stay complete in `ModelResult`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelPrintLimit {
+    pub max_assignments: usize,
+    pub max_value_width: usize,
+}
+/// Formats the assignments of a model grouped by region.
+///
+/// Cell variables are named `A-{region}-{column}-{row}`. This function lists them under a header per region,
+/// sorted by column and then row. The remaining variables (instance cells, range-check bits, ...) are listed
+/// by name under a final `other:` header.
+///
+pub fn format_model(model: &ModelResult) -> String {
+    format_model_with_limit(model, None)
+}
+/// Formats the assignments of a model grouped by region, as `format_model`, within an optional `ModelPrintLimit`.
+///
+pub fn format_model_with_limit(model: &ModelResult, limit: Option<&ModelPrintLimit>) -> String {
+    let mut regions: BTreeMap<usize, BTreeMap<(usize, usize), (&str, &str)>> = BTreeMap::new();
+    let mut other = vec![];
+    for variable in model.result.values() {
+        let cell: Option<Vec<usize>> = variable
+            .name
+            .strip_prefix("A-")
+            .and_then(|indices| indices.split('-').map(|index| index.parse().ok()).collect());
+        match cell.as_deref() {
+            Some(&[region, column, row]) => {
+                regions.entry(region).or_default().insert(
+                    (column, row),
+                    (variable.name.as_str(), variable.value.element.as_str()),
+                );
+            }
+            _ => other.push((variable.name.as_str(), variable.value.element.as_str())),
+        }
+    }
+
+    let max_assignments = limit.map_or(usize::MAX, |limit| limit.max_assignments);
+    let value = |value: &str| match limit {
+        Some(limit) if value.len() > limit.max_value_width => {
+            format!("{}...", &value[..limit.max_value_width])
+        }
+        _ => value.to_owned(),
+    };
+    let mut printed = 0;
+    let mut output = String::new();
+    for (region, cells) in regions.iter() {
+        if printed == max_assignments {
+            break;
+        }
+        output.push_str(&format!("region {}:\n", region));
+        for ((column, row), (name, element)) in cells.iter().take(max_assignments - printed) {
+            output.push_str(&format!(
+                "  {} (column {}, row {}) : {}\n",
+                name,
+                column,
+                row,
+                value(element)
+            ));
+            printed += 1;
+        }
+    }
+    if !other.is_empty() && printed < max_assignments {
+        output.push_str("other:\n");
+        for (name, element) in other.iter().take(max_assignments - printed) {
+            output.push_str(&format!("  {} : {}\n", name, value(element)));
+            printed += 1;
+        }
+    }
+    if printed < model.result.len() {
+        output.push_str(&format!("... and {} more\n", model.result.len() - printed));
+    }
+    output
+}
+/// Maps the result of an analysis to a process exit code.
+///
+/// This function is meant for scripting (`korrekt --exit-code && echo ok`). The codes are:
+///
+/// - `0`: clean, i.e. not under-constrained, or a static check without errors (see `Severity`). Determined cells
+///   are not problems, so their analysis always exits with `0`, even if `strict`.
+/// - `1`: the analyzer output is invalid.
+/// - `2`: the circuit is under-constrained.
+/// - `3`: the circuit is over-constrained.
+/// - `4`: a static check logged at least one error in `log`, or at least one finding of any severity if `strict`.
+///
+pub fn exit_code(analyzer_output: &AnalyzerOutput, log: &[Finding], strict: bool) -> i32 {
+    match analyzer_output.output_status.static_pass() {
+        None => match analyzer_output.output_status {
+            AnalyzerOutputStatus::Underconstrained => 2,
+            AnalyzerOutputStatus::Overconstrained => 3,
+            AnalyzerOutputStatus::NotUnderconstrained
+            | AnalyzerOutputStatus::NotUnderconstrainedLocal => 0,
+            _ => 1,
+        },
+        Some(AnalyzerType::DeterminedCells) => 0,
+        Some(_) => {
+            if log
+                .iter()
+                .any(|finding| strict || finding.severity() == Severity::Error)
+            {
+                4
+            } else {
+                0
+            }
+        }
+    }
+}
 /// Retrieves user input to determine the type of analysis for the circuit.
 ///
 /// This function prompts the user to choose the mode of analysis for the circuit and returns
@@ -124,12 +261,66 @@ pub fn retrieve_user_input_for_analyzer_type() -> Result<AnalyzerType> {
     const UNUSED_COLUMNS: i64 = 2;
     const UNCONSTRAINED_CELLS: i64 = 3;
     const UNDERCONSTRAINED_CIRCUITS: i64 = 4;
+    const ALWAYS_VIOLATED_GATES: i64 = 5;
+    const CONSTANT_LOOKUP_COLUMNS: i64 = 6;
+    const NAMING_COLLISIONS: i64 = 7;
+    const LINEAR_COLUMNS: i64 = 8;
+    const PROPERTIES: i64 = 9;
+    const UNASSIGNED_CELLS: i64 = 10;
+    const ALWAYS_ON_SELECTORS: i64 = 11;
+    const INSTANCE_ROTATIONS: i64 = 12;
+    const DOUBLE_ASSIGNED_CELLS: i64 = 13;
+    const INERT_GATES: i64 = 14;
+    const NON_CONSTRAINING_LOOKUPS: i64 = 15;
+    const NOOP_GATES: i64 = 16;
+    const FIXED_TRIVIAL_GATES: i64 = 17;
+    const BOOLEAN_FIXED_COLUMNS: i64 = 18;
+    const EQUAL_INSTANCE_COLUMNS: i64 = 19;
+    const DETERMINED_CELLS: i64 = 20;
+    const HIGH_DEGREE_GATES: i64 = 21;
+    const OFF_BY_ONE_SELECTORS: i64 = 22;
+    const CONSTANT_PROPAGATION: i64 = 23;
+    const CONSTANT_DRIFT: i64 = 24;
+    const UNRANGED_LOOKUP_INPUTS: i64 = 25;
+    const UNIFORM_COLUMNS: i64 = 26;
+    const ORPHANED_SELECTORS: i64 = 27;
+    const MUTUALLY_EXCLUSIVE_SELECTORS: i64 = 28;
+    const SPARSE_REGIONS: i64 = 29;
+    const DEAD_INSTANCES: i64 = 30;
+    const SELECTOR_BLEED: i64 = 31;
 
     println!("Choose the mode of analysis for your circuit.");
     println!("1. Unused Gates");
     println!("2. Unused Columns");
     println!("3. Unconstrained Cells");
     println!("4. Underconstrained Circuit");
+    println!("5. Always Violated Gates");
+    println!("6. Constant Lookup Table Columns");
+    println!("7. Naming Collisions");
+    println!("8. Linear-only Advice Columns");
+    println!("9. Custom Properties");
+    println!("10. Unassigned Cells");
+    println!("11. Always-on Selectors");
+    println!("12. Instance Column Rotations");
+    println!("13. Double-assigned Cells");
+    println!("14. Inert Gates");
+    println!("15. Non-constraining Lookups");
+    println!("16. No-op Gates");
+    println!("17. Fixed-trivial Gates");
+    println!("18. Boolean Fixed Columns");
+    println!("19. Equal Instance Columns");
+    println!("20. Determined Cells");
+    println!("21. High-degree Gates");
+    println!("22. Off-by-one Selectors");
+    println!("23. Constant Propagation");
+    println!("24. Constant Drift");
+    println!("25. Unranged Lookup Inputs");
+    println!("26. Uniform Columns");
+    println!("27. Orphaned Selectors");
+    println!("28. Mutually Exclusive Selectors");
+    println!("29. Sparse Regions");
+    println!("30. Dead Instances");
+    println!("31. Selector Bleed");
 
     let mut menu = String::new();
     io::stdin()
@@ -154,6 +345,87 @@ pub fn retrieve_user_input_for_analyzer_type() -> Result<AnalyzerType> {
         UNDERCONSTRAINED_CIRCUITS => {
             analyzer_type = AnalyzerType::UnderconstrainedCircuit;
         }
+        ALWAYS_VIOLATED_GATES => {
+            analyzer_type = AnalyzerType::AlwaysViolatedGates;
+        }
+        CONSTANT_LOOKUP_COLUMNS => {
+            analyzer_type = AnalyzerType::ConstantLookupColumns;
+        }
+        NAMING_COLLISIONS => {
+            analyzer_type = AnalyzerType::NamingCollisions;
+        }
+        LINEAR_COLUMNS => {
+            analyzer_type = AnalyzerType::LinearColumns;
+        }
+        PROPERTIES => {
+            analyzer_type = AnalyzerType::Properties;
+        }
+        UNASSIGNED_CELLS => {
+            analyzer_type = AnalyzerType::UnassignedCells;
+        }
+        ALWAYS_ON_SELECTORS => {
+            analyzer_type = AnalyzerType::AlwaysOnSelectors;
+        }
+        INSTANCE_ROTATIONS => {
+            analyzer_type = AnalyzerType::InstanceRotations;
+        }
+        DOUBLE_ASSIGNED_CELLS => {
+            analyzer_type = AnalyzerType::DoubleAssignedCells;
+        }
+        INERT_GATES => {
+            analyzer_type = AnalyzerType::InertGates;
+        }
+        NON_CONSTRAINING_LOOKUPS => {
+            analyzer_type = AnalyzerType::NonConstrainingLookups;
+        }
+        NOOP_GATES => {
+            analyzer_type = AnalyzerType::NoopGates;
+        }
+        FIXED_TRIVIAL_GATES => {
+            analyzer_type = AnalyzerType::FixedTrivialGates;
+        }
+        BOOLEAN_FIXED_COLUMNS => {
+            analyzer_type = AnalyzerType::BooleanFixedColumns;
+        }
+        EQUAL_INSTANCE_COLUMNS => {
+            analyzer_type = AnalyzerType::EqualInstanceColumns;
+        }
+        DETERMINED_CELLS => {
+            analyzer_type = AnalyzerType::DeterminedCells;
+        }
+        HIGH_DEGREE_GATES => {
+            analyzer_type = AnalyzerType::HighDegreeGates;
+        }
+        OFF_BY_ONE_SELECTORS => {
+            analyzer_type = AnalyzerType::OffByOneSelectors;
+        }
+        CONSTANT_PROPAGATION => {
+            analyzer_type = AnalyzerType::ConstantPropagation;
+        }
+        CONSTANT_DRIFT => {
+            analyzer_type = AnalyzerType::ConstantDrift;
+        }
+        UNRANGED_LOOKUP_INPUTS => {
+            analyzer_type = AnalyzerType::UnrangedLookupInputs;
+        }
+        UNIFORM_COLUMNS => {
+            analyzer_type = AnalyzerType::UniformColumns;
+        }
+        ORPHANED_SELECTORS => {
+            analyzer_type = AnalyzerType::OrphanedSelectors;
+        }
+        MUTUALLY_EXCLUSIVE_SELECTORS => {
+            analyzer_type = AnalyzerType::MutuallyExclusiveSelectors;
+        }
+        SPARSE_REGIONS => {
+            analyzer_type = AnalyzerType::SparseRegions;
+        }
+        DEAD_INSTANCES => {
+            analyzer_type = AnalyzerType::DeadInstances;
+        }
+        SELECTOR_BLEED => {
+            analyzer_type = AnalyzerType::SelectorBleed;
+        }
         _ => {
             panic!("Not a valid mode of analysis.")
         }
@@ -1,4 +1,13 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+
+use crate::io::json::{json_string, unescape_json};
 #[derive(Debug, PartialEq, Eq)]
 pub enum VerificationMethod {
     Specific,
@@ -7,7 +16,7 @@ pub enum VerificationMethod {
 
 #[derive(Debug)]
 pub struct VerificationInput {
-    pub instances_string: HashMap<String, i64>,
+    pub instances_string: BTreeMap<String, i64>,
     pub iterations: u128,
 }
 
@@ -27,6 +36,84 @@ pub enum AnalyzerOutputStatus {
     UnusedCustomGates,
     UnconstrainedCells,
     UnusedColumns,
+    AlwaysViolatedGates,
+    ConstantLookupColumns,
+    NamingCollisions,
+    LinearColumns,
+    Properties,
+    UnassignedCells,
+    AlwaysOnSelectors,
+    InstanceRotations,
+    DoubleAssignedCells,
+    InertGates,
+    NonConstrainingLookups,
+    NoopGates,
+    FixedTrivialGates,
+    BooleanFixedColumns,
+    EqualInstanceColumns,
+    DeterminedCells,
+    HighDegreeGates,
+    OffByOneSelectors,
+    ConstantPropagation,
+    ConstantDrift,
+    UnrangedLookupInputs,
+    UniformColumns,
+    OrphanedSelectors,
+    MutuallyExclusiveSelectors,
+    SparseRegions,
+    DeadInstances,
+    SelectorBleed,
+}
+
+impl AnalyzerOutputStatus {
+    /// The static pass concluding with this status, or `None` for the verdicts of the under-constrained analysis.
+    ///
+    /// The match is exhaustive on purpose: a new status must be classified here, see `analyzer_io::exit_code`.
+    pub fn static_pass(&self) -> Option<AnalyzerType> {
+        match self {
+            AnalyzerOutputStatus::Invalid
+            | AnalyzerOutputStatus::Underconstrained
+            | AnalyzerOutputStatus::Overconstrained
+            | AnalyzerOutputStatus::NotUnderconstrained
+            | AnalyzerOutputStatus::NotUnderconstrainedLocal => None,
+            AnalyzerOutputStatus::UnusedCustomGates => Some(AnalyzerType::UnusedGates),
+            AnalyzerOutputStatus::UnconstrainedCells => Some(AnalyzerType::UnconstrainedCells),
+            AnalyzerOutputStatus::UnusedColumns => Some(AnalyzerType::UnusedColumns),
+            AnalyzerOutputStatus::AlwaysViolatedGates => Some(AnalyzerType::AlwaysViolatedGates),
+            AnalyzerOutputStatus::ConstantLookupColumns => {
+                Some(AnalyzerType::ConstantLookupColumns)
+            }
+            AnalyzerOutputStatus::NamingCollisions => Some(AnalyzerType::NamingCollisions),
+            AnalyzerOutputStatus::LinearColumns => Some(AnalyzerType::LinearColumns),
+            AnalyzerOutputStatus::Properties => Some(AnalyzerType::Properties),
+            AnalyzerOutputStatus::UnassignedCells => Some(AnalyzerType::UnassignedCells),
+            AnalyzerOutputStatus::AlwaysOnSelectors => Some(AnalyzerType::AlwaysOnSelectors),
+            AnalyzerOutputStatus::InstanceRotations => Some(AnalyzerType::InstanceRotations),
+            AnalyzerOutputStatus::DoubleAssignedCells => Some(AnalyzerType::DoubleAssignedCells),
+            AnalyzerOutputStatus::InertGates => Some(AnalyzerType::InertGates),
+            AnalyzerOutputStatus::NonConstrainingLookups => {
+                Some(AnalyzerType::NonConstrainingLookups)
+            }
+            AnalyzerOutputStatus::NoopGates => Some(AnalyzerType::NoopGates),
+            AnalyzerOutputStatus::FixedTrivialGates => Some(AnalyzerType::FixedTrivialGates),
+            AnalyzerOutputStatus::BooleanFixedColumns => Some(AnalyzerType::BooleanFixedColumns),
+            AnalyzerOutputStatus::EqualInstanceColumns => Some(AnalyzerType::EqualInstanceColumns),
+            AnalyzerOutputStatus::DeterminedCells => Some(AnalyzerType::DeterminedCells),
+            AnalyzerOutputStatus::HighDegreeGates => Some(AnalyzerType::HighDegreeGates),
+            AnalyzerOutputStatus::OffByOneSelectors => Some(AnalyzerType::OffByOneSelectors),
+            AnalyzerOutputStatus::ConstantPropagation => Some(AnalyzerType::ConstantPropagation),
+            AnalyzerOutputStatus::ConstantDrift => Some(AnalyzerType::ConstantDrift),
+            AnalyzerOutputStatus::UnrangedLookupInputs => Some(AnalyzerType::UnrangedLookupInputs),
+            AnalyzerOutputStatus::UniformColumns => Some(AnalyzerType::UniformColumns),
+            AnalyzerOutputStatus::OrphanedSelectors => Some(AnalyzerType::OrphanedSelectors),
+            AnalyzerOutputStatus::MutuallyExclusiveSelectors => {
+                Some(AnalyzerType::MutuallyExclusiveSelectors)
+            }
+            AnalyzerOutputStatus::SparseRegions => Some(AnalyzerType::SparseRegions),
+            AnalyzerOutputStatus::DeadInstances => Some(AnalyzerType::DeadInstances),
+            AnalyzerOutputStatus::SelectorBleed => Some(AnalyzerType::SelectorBleed),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -34,10 +121,277 @@ pub struct AnalyzerOutput {
     pub output_status: AnalyzerOutputStatus,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AnalyzerType {
     UnusedGates,
     UnconstrainedCells,
     UnusedColumns,
     UnderconstrainedCircuit,
+    AlwaysViolatedGates,
+    ConstantLookupColumns,
+    NamingCollisions,
+    LinearColumns,
+    Properties,
+    UnassignedCells,
+    AlwaysOnSelectors,
+    InstanceRotations,
+    DoubleAssignedCells,
+    InertGates,
+    NonConstrainingLookups,
+    NoopGates,
+    FixedTrivialGates,
+    BooleanFixedColumns,
+    EqualInstanceColumns,
+    DeterminedCells,
+    HighDegreeGates,
+    OffByOneSelectors,
+    ConstantPropagation,
+    ConstantDrift,
+    UnrangedLookupInputs,
+    UniformColumns,
+    OrphanedSelectors,
+    MutuallyExclusiveSelectors,
+    SparseRegions,
+    DeadInstances,
+    SelectorBleed,
+}
+
+impl AnalyzerType {
+    /// All the passes, in declaration order.
+    pub const ALL: &'static [AnalyzerType] = &[
+        AnalyzerType::UnusedGates,
+        AnalyzerType::UnconstrainedCells,
+        AnalyzerType::UnusedColumns,
+        AnalyzerType::UnderconstrainedCircuit,
+        AnalyzerType::AlwaysViolatedGates,
+        AnalyzerType::ConstantLookupColumns,
+        AnalyzerType::NamingCollisions,
+        AnalyzerType::LinearColumns,
+        AnalyzerType::Properties,
+        AnalyzerType::UnassignedCells,
+        AnalyzerType::AlwaysOnSelectors,
+        AnalyzerType::InstanceRotations,
+        AnalyzerType::DoubleAssignedCells,
+        AnalyzerType::InertGates,
+        AnalyzerType::NonConstrainingLookups,
+        AnalyzerType::NoopGates,
+        AnalyzerType::FixedTrivialGates,
+        AnalyzerType::BooleanFixedColumns,
+        AnalyzerType::EqualInstanceColumns,
+        AnalyzerType::DeterminedCells,
+        AnalyzerType::HighDegreeGates,
+        AnalyzerType::OffByOneSelectors,
+        AnalyzerType::ConstantPropagation,
+        AnalyzerType::ConstantDrift,
+        AnalyzerType::UnrangedLookupInputs,
+        AnalyzerType::UniformColumns,
+        AnalyzerType::OrphanedSelectors,
+        AnalyzerType::MutuallyExclusiveSelectors,
+        AnalyzerType::SparseRegions,
+        AnalyzerType::DeadInstances,
+        AnalyzerType::SelectorBleed,
+    ];
+
+    /// The severity of the findings logged by the pass.
+    pub fn severity(&self) -> Severity {
+        match self {
+            AnalyzerType::UnusedGates
+            | AnalyzerType::UnconstrainedCells
+            | AnalyzerType::UnderconstrainedCircuit
+            | AnalyzerType::AlwaysViolatedGates
+            | AnalyzerType::Properties
+            | AnalyzerType::UnassignedCells
+            | AnalyzerType::DoubleAssignedCells
+            | AnalyzerType::EqualInstanceColumns
+            | AnalyzerType::OffByOneSelectors
+            | AnalyzerType::OrphanedSelectors
+            | AnalyzerType::MutuallyExclusiveSelectors
+            | AnalyzerType::DeadInstances
+            | AnalyzerType::SelectorBleed => Severity::Error,
+            AnalyzerType::UnusedColumns
+            | AnalyzerType::ConstantLookupColumns
+            | AnalyzerType::NamingCollisions
+            | AnalyzerType::LinearColumns
+            | AnalyzerType::AlwaysOnSelectors
+            | AnalyzerType::InstanceRotations
+            | AnalyzerType::InertGates
+            | AnalyzerType::NonConstrainingLookups
+            | AnalyzerType::NoopGates
+            | AnalyzerType::FixedTrivialGates
+            | AnalyzerType::BooleanFixedColumns
+            | AnalyzerType::DeterminedCells
+            | AnalyzerType::HighDegreeGates
+            | AnalyzerType::ConstantPropagation
+            | AnalyzerType::ConstantDrift
+            | AnalyzerType::UnrangedLookupInputs
+            | AnalyzerType::UniformColumns
+            | AnalyzerType::SparseRegions => Severity::Warning,
+        }
+    }
+}
+
+/// The severity of a finding.
+///
+/// Errors point at likely soundness or correctness bugs. Warnings point at waste or suspicious but possibly
+/// intended constructions, and only fail the exit code in strict mode (see `analyzer_io::exit_code`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl FromStr for AnalyzerType {
+    type Err = anyhow::Error;
+
+    /// Parses the name of a pass, as printed with `{:?}`, e.g. `UnusedGates`.
+    fn from_str(name: &str) -> Result<Self> {
+        AnalyzerType::ALL
+            .iter()
+            .find(|pass| format!("{:?}", pass) == name)
+            .copied()
+            .ok_or_else(|| anyhow!("Unknown pass {}!", name))
+    }
+}
+
+/// A finding logged by a pass of the analyzer.
+///
+/// A finding dereferences to its message, so it can be used wherever the message is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The pass that logged the finding.
+    pub pass: AnalyzerType,
+    /// The description of the finding, e.g. `no-op gate: "sum" has polynomial(s) [0] that are identically zero ...`
+    pub message: String,
 }
+
+impl Finding {
+    pub fn new(pass: AnalyzerType, message: String) -> Self {
+        Finding { pass, message }
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.pass.severity()
+    }
+
+    /// Serializes the finding as a single-line JSON object, e.g. `{"pass": "NoopGates", "message": "..."}`.
+    ///
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"pass\": {}, \"message\": {}}}",
+            json_string(&format!("{:?}", self.pass)),
+            json_string(&self.message)
+        )
+    }
+
+    /// Parses a finding serialized with `to_json`.
+    ///
+    pub fn from_json(json: &str) -> Result<Self> {
+        let pass_pattern =
+            Regex::new(r#""pass"\s*:\s*"([^"]*)""#).context("Failed to compile regex!")?;
+        let message_pattern = Regex::new(r#""message"\s*:\s*"((?:[^"\\]|\\.)*)""#)
+            .context("Failed to compile regex!")?;
+        let json = json.trim();
+        if !json.starts_with('{') || !json.ends_with('}') {
+            return Err(anyhow!("A finding must be a JSON object: {}", json));
+        }
+        let pass = pass_pattern
+            .captures(json)
+            .ok_or_else(|| anyhow!("Missing pass in finding: {}", json))?;
+        let message = message_pattern
+            .captures(json)
+            .ok_or_else(|| anyhow!("Missing message in finding: {}", json))?;
+        Ok(Finding {
+            pass: pass.get(1).unwrap().as_str().parse()?,
+            message: unescape_json(message.get(1).unwrap().as_str())?,
+        })
+    }
+}
+
+impl Deref for Finding {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// The findings logged by the passes of an analyzer, in order.
+///
+/// The log dereferences to a slice of findings. It can also stream each finding to a writer as newline-delimited
+/// JSON (see `Finding::to_json`) as soon as it is logged, see `Analyzer::with_ndjson_findings`.
+#[derive(Default)]
+pub struct FindingLog {
+    findings: Vec<Finding>,
+    stream: Option<Box<dyn Write>>,
+}
+
+impl FindingLog {
+    /// Streams the findings logged from now on to `writer`, one JSON object per line.
+    pub fn stream_to(&mut self, writer: impl Write + 'static) {
+        self.stream = Some(Box::new(writer));
+    }
+
+    pub fn push(&mut self, finding: Finding) {
+        if let Some(stream) = self.stream.as_mut() {
+            // A consumer that went away must not abort the analysis.
+            let _ = writeln!(stream, "{}", finding.to_json()).and_then(|_| stream.flush());
+        }
+        self.findings.push(finding);
+    }
+
+    pub fn clear(&mut self) {
+        self.findings.clear();
+    }
+
+    /// Logs a finding of the pass `analyzer_type` with `message`.
+    pub fn log_finding(&mut self, analyzer_type: AnalyzerType, message: String) {
+        self.push(Finding::new(analyzer_type, message));
+    }
+}
+
+impl Deref for FindingLog {
+    type Target = [Finding];
+
+    fn deref(&self) -> &[Finding] {
+        &self.findings
+    }
+}
+
+impl fmt::Debug for FindingLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.findings.iter()).finish()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AnalyzerError {
+    SolverMissingFeature {
+        feature: &'static str,
+        solver_version: Option<String>,
+    },
+}
+
+impl fmt::Display for AnalyzerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnalyzerError::SolverMissingFeature {
+                feature,
+                solver_version,
+            } => write!(
+                f,
+                "The SMT solver ({}) does not support {}, please install a build with {} support (see README).",
+                solver_version.as_deref().unwrap_or("unknown version"),
+                feature,
+                feature
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnalyzerError {}
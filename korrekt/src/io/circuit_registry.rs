@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Context, Result};
+use halo2_proofs::arithmetic::FieldExt as Field;
+use halo2_proofs::dev::{CellValue, MockProver};
+use halo2_proofs::plonk::Circuit;
+
+use crate::circuit_analyzer::analyzer::Analyzer;
+use crate::io::analyzer_io_type::{AnalyzerOutput, AnalyzerType, Finding};
+
+/// The parameters of a circuit, by name, e.g. `k=6` on the command line.
+pub type CircuitParams = BTreeMap<String, String>;
+
+/// Parses `key=value` arguments into circuit parameters.
+///
+pub fn parse_params(args: &[String]) -> Result<CircuitParams> {
+    args.iter()
+        .map(|arg| {
+            arg.split_once('=')
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .ok_or_else(|| anyhow!("Invalid circuit parameter {:?}, expected key=value!", arg))
+        })
+        .collect()
+}
+
+/// Returns the parameter `key` parsed as a number, or `default` if it is not set.
+///
+pub fn param_or<T: std::str::FromStr>(params: &CircuitParams, key: &str, default: T) -> Result<T> {
+    match params.get(key) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| anyhow!("Invalid value {:?} for circuit parameter {}!", value, key)),
+        None => Ok(default),
+    }
+}
+
+/// A circuit built by a registered factory: its analyzer, and the values of its fixed columns for the passes
+/// reading them.
+pub struct RegisteredCircuit<F: Field> {
+    pub analyzer: Analyzer<F>,
+    pub fixed: Vec<Vec<CellValue<F>>>,
+}
+
+impl<F: Field> RegisteredCircuit<F> {
+    /// Lays out `circuit` with the analyzer, and with `MockProver` for `2^k` rows and `instances` to get the values
+    /// of its fixed columns.
+    ///
+    pub fn mock<C: Circuit<F>>(circuit: &C, k: u32, instances: Vec<Vec<F>>) -> Result<Self> {
+        let prover = MockProver::run(k, circuit, instances)
+            .map_err(|error| anyhow!("Failed to lay out the circuit: {:?}", error))?;
+        Ok(RegisteredCircuit {
+            analyzer: Analyzer::from(circuit),
+            fixed: prover.fixed,
+        })
+    }
+}
+
+/// A registry of circuit factories by name, so that a single binary can analyze any registered circuit.
+///
+/// Circuits are generic over their configuration, so the registry keeps factories building the analyzer of a
+/// circuit from its parameters rather than the circuits themselves.
+///
+pub struct CircuitRegistry<F: Field> {
+    factories: BTreeMap<String, Box<dyn Fn(&CircuitParams) -> Result<RegisteredCircuit<F>>>>,
+}
+
+impl<F: Field> Default for CircuitRegistry<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> CircuitRegistry<F> {
+    pub fn new() -> Self {
+        CircuitRegistry {
+            factories: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a factory building a circuit from its parameters under `name`, replacing any previous one.
+    ///
+    pub fn register_circuit(
+        &mut self,
+        name: &str,
+        factory: impl Fn(&CircuitParams) -> Result<RegisteredCircuit<F>> + 'static,
+    ) {
+        self.factories.insert(name.to_owned(), Box::new(factory));
+    }
+
+    /// Returns the names of the registered circuits, in order.
+    ///
+    pub fn names(&self) -> Vec<&str> {
+        self.factories.keys().map(String::as_str).collect()
+    }
+
+    /// Builds the circuit registered under `name` with `params`.
+    ///
+    pub fn build(&self, name: &str, params: &CircuitParams) -> Result<RegisteredCircuit<F>> {
+        let factory = self.factories.get(name).ok_or_else(|| {
+            anyhow!(
+                "Unknown circuit {:?}, the registered circuits are: {}.",
+                name,
+                self.names().join(", ")
+            )
+        })?;
+        factory(params).with_context(|| format!("Failed to build circuit {:?}!", name))
+    }
+
+    /// Builds the circuit registered under `name` with `params` and runs the analysis `analyzer_type` on it,
+    /// returning its output and findings.
+    ///
+    pub fn analyze(
+        &self,
+        name: &str,
+        params: &CircuitParams,
+        analyzer_type: AnalyzerType,
+        prime: &str,
+    ) -> Result<(AnalyzerOutput, Vec<Finding>)> {
+        let RegisteredCircuit {
+            mut analyzer,
+            fixed,
+        } = self.build(name, params)?;
+        let output = analyzer
+            .dispatch_analysis(analyzer_type, fixed, prime)
+            .context("Failed to perform analysis!")?;
+        Ok((output, analyzer.log.to_vec()))
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+
+/// Quotes `value` as a JSON string.
+pub fn json_string(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Unescapes the contents of a JSON string literal.
+pub fn unescape_json(escaped: &str) -> Result<String> {
+    let mut unescaped = String::new();
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            Some('/') => unescaped.push('/'),
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                let c = u32::from_str_radix(&code, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| anyhow!("Invalid escape \\u{} in JSON string!", code))?;
+                unescaped.push(c);
+            }
+            other => return Err(anyhow!("Invalid escape {:?} in JSON string!", other)),
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Returns the members of a JSON object by key, each value being the raw JSON text.
+///
+/// Only the top level of `object` is split: nested objects and arrays are returned as they are, so a key of a
+/// nested object never shadows a key of `object`. Returns `None` if `object` is not a well-formed object.
+pub fn object_members(object: &str) -> Option<BTreeMap<String, &str>> {
+    let body = object.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let mut members = BTreeMap::new();
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        let key_end = string_end(rest)?;
+        let key = unescape_json(&rest[1..key_end - 1]).ok()?;
+        rest = rest[key_end..].trim_start().strip_prefix(':')?.trim_start();
+        let value_end = value_end(rest)?;
+        members.insert(key, rest[..value_end].trim_end());
+        rest = rest[value_end..].trim_start();
+        if let Some(next) = rest.strip_prefix(',') {
+            rest = next.trim_start();
+            if rest.is_empty() {
+                return None;
+            }
+        } else if !rest.is_empty() {
+            return None;
+        }
+    }
+    Some(members)
+}
+
+/// Returns the contents of a JSON string, or `None` if `value` is not a string.
+pub fn string_value(value: &str) -> Option<String> {
+    let value = value.trim();
+    if !value.starts_with('"') || string_end(value)? != value.len() {
+        return None;
+    }
+    unescape_json(&value[1..value.len() - 1]).ok()
+}
+
+/// Returns the length of the JSON string `text` starts with, quotes included.
+fn string_end(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    if chars.next()?.1 != '"' {
+        return None;
+    }
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next()?;
+            }
+            '"' => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the length of the JSON value `text` starts with, up to the next top-level `,` or the end of `text`.
+fn value_end(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut index = 0;
+    while index < text.len() {
+        match text[index..].chars().next()? {
+            '"' => {
+                index += string_end(&text[index..])?;
+                continue;
+            }
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => break,
+            _ => {}
+        }
+        index += 1;
+    }
+    if depth != 0 || index == 0 {
+        return None;
+    }
+    Some(index)
+}
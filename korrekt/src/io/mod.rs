@@ -1,2 +1,6 @@
 pub mod analyzer_io;
 pub mod analyzer_io_type;
+pub mod circuit_registry;
+pub mod json;
+pub mod repl;
+pub mod rpc;
@@ -0,0 +1,188 @@
+use std::fs::{self, File};
+use std::io::{BufRead, Write};
+
+use anyhow::{anyhow, Context, Result};
+use halo2_proofs::arithmetic::FieldExt as Field;
+
+use crate::circuit_analyzer::abstract_expr;
+use crate::circuit_analyzer::analyzer::{Analyzer, FixedMatrix};
+use crate::circuit_analyzer::name_formatter::CellKind;
+use crate::smt_solver::smt;
+use crate::smt_solver::smt_parser::Satisfiability;
+use crate::smt_solver::solver::SolverSession;
+
+/// The commands understood by the REPL, printed by `help`.
+pub const HELP: &str = "\
+locate <cell>                         where a cell of the encoding is, e.g. `locate A-1-2-0`
+gates-touching <advice|fixed|instance> <column>
+                                      the gates querying a column
+degree <gate>                         the degree of a gate
+assert <term>                         adds `(assert <term>)` to the solver session
+check-sat                             decides the encoding and the assertions added so far
+help                                  prints this message
+quit                                  leaves the REPL";
+
+/// An interactive prompt for exploring the SMT encoding of a circuit.
+///
+/// The circuit is encoded once, into `src/output/repl.smt2`, and the encoding is the starting point of a
+/// `SolverSession`: assertions typed in the REPL are added to it and `check-sat` decides them with the solver of
+/// the analyzer. The other commands query the constraint system and the layout of the circuit, see `HELP`.
+///
+pub struct Repl<'a, F: Field> {
+    analyzer: &'a Analyzer<F>,
+    session: SolverSession<'a>,
+}
+
+impl<'a, F: Field> Repl<'a, F> {
+    pub fn new(
+        analyzer: &'a mut Analyzer<F>,
+        fixed: impl Into<FixedMatrix<F>>,
+        prime: &str,
+    ) -> Result<Self> {
+        fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/repl.smt2";
+        let mut smt_file = File::create(smt_file_path).context("Failed to create file!")?;
+        let mut printer = smt::write_start(&mut smt_file, prime.to_owned());
+        analyzer
+            .encode_circuit(&mut printer, fixed)
+            .context("Failed to encode the circuit!")?;
+        let encoding = fs::read_to_string(smt_file_path).context("Failed to read smt file!")?;
+        let analyzer: &'a Analyzer<F> = analyzer;
+        Ok(Repl {
+            analyzer,
+            session: SolverSession::new(analyzer.solver.as_ref(), encoding),
+        })
+    }
+
+    /// Runs a single command and returns its response.
+    pub fn execute(&mut self, command: &str) -> Result<String> {
+        let command = command.trim();
+        let (name, argument) = command
+            .split_once(char::is_whitespace)
+            .map_or((command, ""), |(name, argument)| (name, argument.trim()));
+        match name {
+            "locate" => self.locate(argument),
+            "gates-touching" => self.gates_touching(argument),
+            "degree" => self.degree(argument),
+            "assert" => {
+                self.session.assert(argument)?;
+                Ok("ok".to_owned())
+            }
+            "check-sat" => Ok(match self.session.check_sat()? {
+                Satisfiability::Satisfiable => "sat",
+                Satisfiability::Unsatisfiable => "unsat",
+                Satisfiability::Unknown => "unknown",
+            }
+            .to_owned()),
+            "help" => Ok(HELP.to_owned()),
+            _ => Err(anyhow!(
+                "Unknown command `{}`, type `help` for the list of commands!",
+                name
+            )),
+        }
+    }
+
+    /// Reads commands from `input` until it is exhausted or `quit` is typed, writing a prompt before each command
+    /// and the response (or the error) after it.
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> Result<()> {
+        write!(output, "> ").context("Failed to write prompt!")?;
+        output.flush().context("Failed to write prompt!")?;
+        for line in input.lines() {
+            let command = line.context("Failed to read command!")?;
+            match command.trim() {
+                "" => {}
+                "quit" | "exit" => return Ok(()),
+                command => match self.execute(command) {
+                    Ok(response) => writeln!(output, "{}", response),
+                    Err(error) => writeln!(output, "error: {:#}", error),
+                }
+                .context("Failed to write response!")?,
+            }
+            write!(output, "> ").context("Failed to write prompt!")?;
+            output.flush().context("Failed to write prompt!")?;
+        }
+        Ok(())
+    }
+
+    fn locate(&self, cell: &str) -> Result<String> {
+        let location = self
+            .analyzer
+            .cell_locations()
+            .remove(cell)
+            .ok_or_else(|| anyhow!("No cell named `{}` in the encoding!", cell))?;
+        let kind = format!("{:?}", location.kind).to_lowercase();
+        let region = match location.region {
+            Some(region) => region,
+            // Public inputs belong to no region.
+            None => {
+                return Ok(format!(
+                    "{} column {}, row {}",
+                    kind, location.column, location.row
+                ))
+            }
+        };
+        let region_name = self
+            .analyzer
+            .layouter
+            .regions
+            .get(region)
+            .map_or("", |region| region.name.as_str());
+        Ok(format!(
+            "{} column {}, row {} of region {} \"{}\"",
+            kind, location.column, location.row, region, region_name
+        ))
+    }
+
+    fn gates_touching(&self, argument: &str) -> Result<String> {
+        let usage = || anyhow!("Usage: gates-touching <advice|fixed|instance> <column>");
+        let (kind, column) = argument.split_once(char::is_whitespace).ok_or_else(usage)?;
+        let kind = match kind {
+            "advice" => CellKind::Advice,
+            "fixed" => CellKind::Fixed,
+            "instance" => CellKind::Instance,
+            _ => return Err(usage()),
+        };
+        let column: usize = column.trim().parse().map_err(|_| usage())?;
+        let gates: Vec<&str> = self
+            .analyzer
+            .cs
+            .gates
+            .iter()
+            .filter(|gate| {
+                gate.polynomials().iter().any(|poly| match kind {
+                    CellKind::Advice => abstract_expr::extract_columns(poly)
+                        .iter()
+                        .any(|(query, _)| query.index() == column),
+                    CellKind::Fixed => abstract_expr::extract_fixed_queries(poly)
+                        .iter()
+                        .any(|(query, _)| *query == column),
+                    CellKind::Instance => abstract_expr::extract_instance_columns(poly)
+                        .iter()
+                        .any(|(query, _)| query.index() == column),
+                })
+            })
+            .map(|gate| gate.name())
+            .collect();
+        if gates.is_empty() {
+            return Ok("none".to_owned());
+        }
+        Ok(gates.join(", "))
+    }
+
+    fn degree(&self, gate_name: &str) -> Result<String> {
+        let gate = self
+            .analyzer
+            .cs
+            .gates
+            .iter()
+            .find(|gate| gate.name() == gate_name)
+            .ok_or_else(|| anyhow!("No gate named `{}`!", gate_name))?;
+        let degree = gate
+            .polynomials()
+            .iter()
+            .map(|poly| poly.degree())
+            .max()
+            .unwrap_or(0);
+        Ok(degree.to_string())
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use halo2_proofs::arithmetic::FieldExt as Field;
+
+use crate::circuit_analyzer::analyzer::Analyzer;
+use crate::io::analyzer_io_type::AnalyzerType;
+use crate::io::circuit_registry::{CircuitParams, CircuitRegistry};
+use crate::io::json::{json_string, object_members, string_value};
+
+/// The method of the notifications carrying the findings of a pass.
+pub const FINDINGS_NOTIFICATION: &str = "korrekt/findings";
+
+/// Returns the static passes run by `analyze` requests, by name.
+///
+/// These passes only read the layout of the circuit, so they need neither the `fixed` matrix nor a solver.
+fn static_passes() -> Vec<(&'static str, AnalyzerType)> {
+    vec![
+        ("unused_gates", AnalyzerType::UnusedGates),
+        ("unused_columns", AnalyzerType::UnusedColumns),
+        ("unconstrained_cells", AnalyzerType::UnconstrainedCells),
+        ("always_violated_gates", AnalyzerType::AlwaysViolatedGates),
+        ("naming_collisions", AnalyzerType::NamingCollisions),
+        ("linear_columns", AnalyzerType::LinearColumns),
+        ("unassigned_cells", AnalyzerType::UnassignedCells),
+        ("always_on_selectors", AnalyzerType::AlwaysOnSelectors),
+        ("instance_rotations", AnalyzerType::InstanceRotations),
+        ("double_assigned_cells", AnalyzerType::DoubleAssignedCells),
+        ("noop_gates", AnalyzerType::NoopGates),
+    ]
+}
+
+/// A JSON-RPC 2.0 server running the static passes on registered circuits, e.g. for editor integration.
+///
+/// Requests are read one per line. An `analyze` request names a circuit registered with `register` or
+/// `register_registry` in its `circuit` parameter, e.g.
+/// `{"jsonrpc": "2.0", "id": 1, "method": "analyze", "params": {"circuit": "fibonacci", "k": 11}}`; its other
+/// parameters are passed to the circuits of a registry as `CircuitParams`. The findings of each pass are streamed
+/// as `korrekt/findings` notifications as soon as the pass completes, then the request is answered with the total
+/// number of findings. A `shutdown` request stops the server.
+///
+/// Only the top-level members of a request and of its `params` are read, so e.g. an `id` nested in `params` is
+/// not taken for the id of the request.
+///
+pub struct RpcServer<F: Field> {
+    circuits: BTreeMap<String, Box<dyn Fn(&CircuitParams) -> Result<Analyzer<F>>>>,
+}
+
+impl<F: Field> Default for RpcServer<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> RpcServer<F> {
+    pub fn new() -> Self {
+        RpcServer {
+            circuits: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a factory building the analyzer of a circuit, under the id used by `analyze` requests.
+    ///
+    pub fn register(&mut self, id: &str, factory: impl Fn() -> Analyzer<F> + 'static) {
+        self.circuits.insert(
+            id.to_owned(),
+            Box::new(move |_: &CircuitParams| Ok(factory())),
+        );
+    }
+
+    /// Registers every circuit of `registry` under its name, built with the parameters of the `analyze` request.
+    ///
+    pub fn register_registry(&mut self, registry: CircuitRegistry<F>)
+    where
+        F: 'static,
+    {
+        let registry = Rc::new(registry);
+        for name in registry.names() {
+            let id = name.to_owned();
+            let registry = Rc::clone(&registry);
+            let circuit = id.clone();
+            self.circuits.insert(
+                id,
+                Box::new(move |params: &CircuitParams| {
+                    Ok(registry.build(&circuit, params)?.analyzer)
+                }),
+            );
+        }
+    }
+
+    /// Serves the requests read from `input` until it is exhausted or a `shutdown` request is received, writing
+    /// responses and notifications to `output`, one per line.
+    ///
+    pub fn serve(&self, input: impl BufRead, output: &mut impl Write) -> Result<()> {
+        for line in input.lines() {
+            let request = line.context("Failed to read request!")?;
+            if request.trim().is_empty() {
+                continue;
+            }
+            let members = match object_members(&request) {
+                Some(members) => members,
+                None => {
+                    Self::respond_error(output, "null", -32700, "Parse error")?;
+                    continue;
+                }
+            };
+            let id = members
+                .get("id")
+                .copied()
+                .filter(|id| id.starts_with('"') || id.parse::<i64>().is_ok() || *id == "null")
+                .unwrap_or("null");
+            let method = members
+                .get("method")
+                .and_then(|method| string_value(method));
+            match method.as_deref() {
+                Some("analyze") => {
+                    let mut params = members
+                        .get("params")
+                        .and_then(|params| object_members(params))
+                        .unwrap_or_default();
+                    let circuit = params
+                        .remove("circuit")
+                        .and_then(string_value)
+                        .filter(|circuit| self.circuits.contains_key(circuit));
+                    let circuit = match circuit {
+                        Some(circuit) => circuit,
+                        None => {
+                            Self::respond_error(output, id, -32602, "Unknown circuit")?;
+                            continue;
+                        }
+                    };
+                    let params: CircuitParams = params
+                        .into_iter()
+                        .map(|(key, value)| {
+                            let value = string_value(value).unwrap_or_else(|| value.to_owned());
+                            (key, value)
+                        })
+                        .collect();
+                    match (self.circuits[&circuit])(&params) {
+                        Ok(analyzer) => {
+                            let count = Self::analyze(analyzer, &circuit, output)?;
+                            Self::respond(output, id, &format!("{{\"findings\": {}}}", count))?;
+                        }
+                        Err(error) => {
+                            let message = format!("Failed to build the circuit: {:#}", error);
+                            Self::respond_error(output, id, -32603, &message)?;
+                        }
+                    }
+                }
+                Some("shutdown") => {
+                    Self::respond(output, id, "null")?;
+                    return Ok(());
+                }
+                Some(_) => Self::respond_error(output, id, -32601, "Method not found")?,
+                None => Self::respond_error(output, id, -32600, "Invalid request")?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the static passes on `analyzer`, notifying the findings of each pass, and returns their number.
+    fn analyze(analyzer: Analyzer<F>, circuit: &str, output: &mut impl Write) -> Result<usize> {
+        let mut analyzer = analyzer.with_quiet(true);
+        for (pass, analyzer_type) in static_passes() {
+            let previous = analyzer.log.len();
+            analyzer
+                .dispatch_analysis(analyzer_type, vec![], "")
+                .with_context(|| format!("Failed to run pass {}!", pass))?;
+            let findings: Vec<String> = analyzer.log[previous..]
+                .iter()
+                .map(|finding| json_string(finding))
+                .collect();
+            writeln!(
+                output,
+                "{{\"jsonrpc\": \"2.0\", \"method\": {}, \"params\": {{\"circuit\": {}, \"pass\": {}, \"findings\": [{}]}}}}",
+                json_string(FINDINGS_NOTIFICATION),
+                json_string(circuit),
+                json_string(pass),
+                findings.join(", ")
+            )
+            .context("Failed to write notification!")?;
+            output.flush().context("Failed to write notification!")?;
+        }
+        Ok(analyzer.log.len())
+    }
+
+    fn respond(output: &mut impl Write, id: &str, result: &str) -> Result<()> {
+        writeln!(
+            output,
+            "{{\"jsonrpc\": \"2.0\", \"id\": {}, \"result\": {}}}",
+            id, result
+        )
+        .context("Failed to write response!")?;
+        output.flush().context("Failed to write response!")
+    }
+
+    fn respond_error(output: &mut impl Write, id: &str, code: i32, message: &str) -> Result<()> {
+        writeln!(
+            output,
+            "{{\"jsonrpc\": \"2.0\", \"id\": {}, \"error\": {{\"code\": {}, \"message\": {}}}}}",
+            id,
+            code,
+            json_string(message)
+        )
+        .context("Failed to write response!")?;
+        output.flush().context("Failed to write response!")
+    }
+}
@@ -1,4 +1,5 @@
 pub mod circuit_analyzer;
+pub mod export;
 pub mod io;
 pub mod sample_circuits;
 pub mod smt_solver;
@@ -1,25 +1,68 @@
-use halo2_proofs::dev::MockProver;
 use halo2_proofs::halo2curves::bn256;
 use halo2_proofs::halo2curves::bn256::Fr;
 
 use anyhow::{Context, Ok, Result};
-use korrekt::{circuit_analyzer, io, sample_circuits};
+use korrekt::io::circuit_registry::{self, CircuitRegistry, RegisteredCircuit};
+use korrekt::{io, sample_circuits};
 use num::{BigInt, Num};
+use std::marker::PhantomData;
+
+/// Registers the circuits that can be analyzed with `--circuit <name> [key=value ...]`.
+fn registry() -> CircuitRegistry<Fr> {
+    let mut registry = CircuitRegistry::new();
+    registry.register_circuit("lookup_underconstrained", |params| {
+        let circuit =
+            sample_circuits::lookup_circuits::lookup_underconstrained::MyCircuit::<Fr>::default();
+        let k = circuit_registry::param_or(params, "k", 6)?;
+        let public_input = vec![Fr::from(1), Fr::from(1), Fr::from(6)];
+        RegisteredCircuit::mock(&circuit, k, vec![public_input])
+    });
+    registry.register_circuit("fibonacci", |params| {
+        let circuit =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let k = circuit_registry::param_or(params, "k", 11)?;
+        let out = circuit_registry::param_or(params, "out", 3u64)?;
+        RegisteredCircuit::mock(&circuit, k, vec![vec![Fr::from(out)]])
+    });
+    registry
+}
 
 fn main() -> Result<(), anyhow::Error> {
+    // With `--rpc` the static passes are served over JSON-RPC on stdin/stdout (see `io::rpc::RpcServer`).
+    if std::env::args().any(|arg| arg == "--rpc") {
+        let mut server = io::rpc::RpcServer::<Fr>::new();
+        server.register_registry(registry());
+        return server.serve(std::io::stdin().lock(), &mut std::io::stdout());
+    }
     //How to run our analysis on a circuit.
-    let circuit =
-        sample_circuits::lookup_circuits::lookup_underconstrained::MyCircuit::<Fr>::default();
-    let mut analyzer = circuit_analyzer::analyzer::Analyzer::from(&circuit);
-    let k = 6;
-
-    let a = Fr::from(1);
-    let b = Fr::from(1);
-    let out = Fr::from(6);
-
-    let public_input = vec![a, b, out];
-
-    let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+    // With `--circuit <name> [key=value ...]` the named circuit of `registry()` is analyzed.
+    let args: Vec<String> = std::env::args().collect();
+    let (name, params) = match args.iter().position(|arg| arg == "--circuit") {
+        Some(position) => {
+            let name = args
+                .get(position + 1)
+                .context("Missing circuit name after --circuit!")?;
+            let params: Vec<String> = args[position + 2..]
+                .iter()
+                .take_while(|arg| !arg.starts_with("--"))
+                .cloned()
+                .collect();
+            (name.as_str(), io::circuit_registry::parse_params(&params)?)
+        }
+        None => ("lookup_underconstrained", Default::default()),
+    };
+    let RegisteredCircuit { analyzer, fixed } = registry()
+        .build(name, &params)
+        .context("Failed to build the circuit!")?;
+    // With `--exit-code` the analysis prints nothing and its result is reported through the exit code.
+    let exit_code_only = std::env::args().any(|arg| arg == "--exit-code");
+    // With `--strict` warnings fail the exit code as errors do.
+    let strict = std::env::args().any(|arg| arg == "--strict");
+    let mut analyzer = analyzer.with_quiet(exit_code_only);
+    // With `--ndjson` each finding is also written to stderr as a line of JSON as soon as it is found.
+    if std::env::args().any(|arg| arg == "--ndjson") {
+        analyzer = analyzer.with_ndjson_findings(std::io::stderr());
+    }
 
     let modulus = bn256::fr::MODULUS_STR;
     let without_prefix = modulus.trim_start_matches("0x");
@@ -27,11 +70,24 @@ fn main() -> Result<(), anyhow::Error> {
         .unwrap()
         .to_string();
 
+    // With `--repl` the encoding of the circuit is explored interactively (see `io::repl::Repl`).
+    if std::env::args().any(|arg| arg == "--repl") {
+        let mut repl = io::repl::Repl::new(&mut analyzer, fixed, &prime)?;
+        return repl.run(std::io::stdin().lock(), &mut std::io::stdout());
+    }
+
     let analyzer_type = io::analyzer_io::retrieve_user_input_for_analyzer_type()
         .context("Failed to retrieve the user inputs!")?;
 
-    analyzer
-        .dispatch_analysis(analyzer_type, prover.fixed, &prime)
+    let analyzer_output = analyzer
+        .dispatch_analysis(analyzer_type, fixed, &prime)
         .context("Failed to perform analysis!")?;
+    if exit_code_only {
+        std::process::exit(io::analyzer_io::exit_code(
+            &analyzer_output,
+            &analyzer.log,
+            strict,
+        ));
+    }
     Ok(())
 }
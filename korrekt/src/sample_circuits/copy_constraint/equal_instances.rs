@@ -0,0 +1,106 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{
+    Advice, Circuit, Column, ConstraintSystem, Expression, Instance, Selector,
+};
+use halo2_proofs::poly::Rotation;
+
+/// `EqualInstancesCircuit` exposes the same value in two instance columns, and its successor in a third one.
+///
+/// `b` is a copy of `a`, so `i0` and `i1` are equal in every satisfying assignment and one of them is redundant.
+///
+/// |   Row   |   a    |   b    |   c    |   i0   |   i1   |   i2   |   s    |
+/// |---------|--------|--------|--------|--------|--------|--------|--------|
+/// |   0     |   a    |   a    |  a+1   |   a    |   a    |  a+1   |   1    |
+///
+/// Gates:
+/// - next: s*(c-a-1)
+pub struct EqualInstancesCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct EqualInstancesCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    i0: Column<Instance>,
+    i1: Column<Instance>,
+    i2: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for EqualInstancesCircuit<F> {
+    fn default() -> Self {
+        EqualInstancesCircuit { a: F::from(4) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for EqualInstancesCircuit<F> {
+    type Config = EqualInstancesCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let i0 = meta.instance_column();
+        let i1 = meta.instance_column();
+        let i2 = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+        meta.enable_equality(c);
+        meta.enable_equality(i0);
+        meta.enable_equality(i1);
+        meta.enable_equality(i2);
+
+        meta.create_gate("next", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (c - a - Expression::Constant(F::one()))]
+        });
+
+        Self::Config {
+            a,
+            b,
+            c,
+            i0,
+            i1,
+            i2,
+            s,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (a, b, c) = layouter.assign_region(
+            || "equal region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                let a = region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                let b = a.copy_advice(|| "b", &mut region, config.b, 0)?;
+                let c = region.assign_advice(
+                    || "c",
+                    config.c,
+                    0,
+                    || Value::known(self.a + F::one()),
+                )?;
+                Ok((a, b, c))
+            },
+        )?;
+        layouter.constrain_instance(a.cell(), config.i0, 0)?;
+        layouter.constrain_instance(b.cell(), config.i1, 0)?;
+        layouter.constrain_instance(c.cell(), config.i2, 0)
+    }
+}
@@ -0,0 +1,77 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Fixed, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `FixedCopyCircuit` squares an advice cell copied to a fixed cell, so the whole witness is determined.
+///
+/// |   Row   |   a    |   b    |   f    |   s    |
+/// |---------|--------|--------|--------|--------|
+/// |   0     |   7    |   49   |   7    |   1    |
+///
+/// `a` is copy-constrained to `f`.
+///
+/// Gates:
+/// - square: s*(b-a*a)
+pub struct FixedCopyCircuit<F: FieldExt> {
+    f: F,
+}
+
+#[derive(Clone)]
+pub struct FixedCopyCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    f: Column<Fixed>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for FixedCopyCircuit<F> {
+    fn default() -> Self {
+        FixedCopyCircuit { f: F::from(7) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for FixedCopyCircuit<F> {
+    type Config = FixedCopyCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let f = meta.fixed_column();
+        let s = meta.selector();
+        meta.enable_equality(a);
+        meta.enable_equality(f);
+
+        meta.create_gate("square", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (b - a.clone() * a)]
+        });
+
+        Self::Config { a, b, f, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "square region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                let f = region.assign_fixed(|| "f", config.f, 0, || Value::known(self.f))?;
+                let a = region.assign_advice(|| "a", config.a, 0, || Value::known(self.f))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.f * self.f))?;
+                region.constrain_equal(a.cell(), f.cell())
+            },
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Fixed, Instance, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `FixedScaleCircuit` scales a public input by the constant of a fixed column.
+///
+/// `b` is fully determined by the public input `a` and the scale `c`, whatever value `c` is given.
+///
+/// |   Row   |   a    |   b    |   c    |   i    |   s    |
+/// |---------|--------|--------|--------|--------|--------|
+/// |   0     |   a    |  c*a   |   3    |   a    |   1    |
+///
+/// Gate: scale: s*(b-c*a)
+pub struct FixedScaleCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct FixedScaleCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Fixed>,
+    i: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for FixedScaleCircuit<F> {
+    fn default() -> Self {
+        FixedScaleCircuit { a: F::from(2) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for FixedScaleCircuit<F> {
+    type Config = FixedScaleCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.fixed_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(a);
+        meta.enable_equality(i);
+
+        meta.create_gate("scale", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_fixed(c, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (b - c * a)]
+        });
+
+        Self::Config { a, b, c, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let a = layouter.assign_region(
+            || "scale region",
+            |mut region| {
+                let scale = F::from(3);
+                config.s.enable(&mut region, 0)?;
+                region.assign_fixed(|| "c", config.c, 0, || Value::known(scale))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(scale * self.a))?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))
+            },
+        )?;
+        layouter.constrain_instance(a.cell(), config.i, 0)
+    }
+}
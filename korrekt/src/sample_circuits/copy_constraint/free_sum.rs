@@ -0,0 +1,83 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Instance, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `FreeSumCircuit` exposes the sum of two private values, which is under-constrained by design:
+/// any `a'`, `b'` with `a' + b' = x` is another valid witness for the same public input.
+///
+/// |   Row   |   a    |   b    |   x    |  i  |    s     |
+/// |---------|--------|--------|--------|-----|----------|
+/// |   0     |   a    |   b    |   x    |  i  |    1     |
+///
+/// Gate: sum: s*(a+b-x)
+pub struct FreeSumCircuit<F: FieldExt> {
+    a: F,
+    b: F,
+}
+
+#[derive(Clone)]
+pub struct FreeSumCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    x: Column<Advice>,
+    i: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for FreeSumCircuit<F> {
+    fn default() -> Self {
+        FreeSumCircuit {
+            a: F::from(2),
+            b: F::from(3),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for FreeSumCircuit<F> {
+    type Config = FreeSumCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let x = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(x);
+        meta.enable_equality(i);
+
+        meta.create_gate("sum", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let x = meta.query_advice(x, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a + b - x)]
+        });
+
+        Self::Config { a, b, x, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let out = layouter.assign_region(
+            || "sum region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.b))?;
+                region.assign_advice(|| "x", config.x, 0, || Value::known(self.a + self.b))
+            },
+        )?;
+        layouter.constrain_instance(out.cell(), config.i, 0)
+    }
+}
@@ -0,0 +1,120 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `GadgetRegionsCircuit` lays out two rounds of an s-box gadget, in regions named `poseidon_sbox_*`, followed by
+/// a region adding their outputs.
+///
+/// | Region          |   Row   |   a    |   b    |   x    | s_sbox | s_sum |
+/// |-----------------|---------|--------|--------|--------|--------|-------|
+/// | poseidon_sbox_0 |   0     |   a    |  a^3   |        |   1    |   0   |
+/// | poseidon_sbox_1 |   0     |   c    |  c^3   |        |   1    |   0   |
+/// | sum region      |   0     |  a^3   |  c^3   |   x    |   0    |   1   |
+///
+/// The inputs of the sum region are copied from the outputs of the s-boxes.
+///
+/// Gates:
+/// - sbox: s_sbox*(b-a*a*a)
+/// - sum: s_sum*(a+b-x)
+pub struct GadgetRegionsCircuit<F: FieldExt> {
+    a: F,
+    c: F,
+}
+
+#[derive(Clone)]
+pub struct GadgetRegionsCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    x: Column<Advice>,
+    s_sbox: Selector,
+    s_sum: Selector,
+}
+
+impl<F: FieldExt> Default for GadgetRegionsCircuit<F> {
+    fn default() -> Self {
+        GadgetRegionsCircuit {
+            a: F::from(2),
+            c: F::from(3),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for GadgetRegionsCircuit<F> {
+    type Config = GadgetRegionsCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let x = meta.advice_column();
+        let s_sbox = meta.selector();
+        let s_sum = meta.selector();
+
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        meta.create_gate("sbox", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s_sbox = meta.query_selector(s_sbox);
+            vec![s_sbox * (b - a.clone() * a.clone() * a)]
+        });
+
+        meta.create_gate("sum", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let x = meta.query_advice(x, Rotation::cur());
+            let s_sum = meta.query_selector(s_sum);
+            vec![s_sum * (a + b - x)]
+        });
+
+        Self::Config {
+            a,
+            b,
+            x,
+            s_sbox,
+            s_sum,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let mut outputs = vec![];
+        for (round, input) in [self.a, self.c].into_iter().enumerate() {
+            let output = layouter.assign_region(
+                || format!("poseidon_sbox_{}", round),
+                |mut region| {
+                    config.s_sbox.enable(&mut region, 0)?;
+                    region.assign_advice(|| "a", config.a, 0, || Value::known(input))?;
+                    region.assign_advice(
+                        || "b",
+                        config.b,
+                        0,
+                        || Value::known(input * input * input),
+                    )
+                },
+            )?;
+            outputs.push(output);
+        }
+        layouter.assign_region(
+            || "sum region",
+            |mut region| {
+                config.s_sum.enable(&mut region, 0)?;
+                let left = outputs[0].copy_advice(|| "a", &mut region, config.a, 0)?;
+                let right = outputs[1].copy_advice(|| "b", &mut region, config.b, 0)?;
+                let sum = left.value().copied() + right.value().copied();
+                region.assign_advice(|| "x", config.x, 0, || sum)?;
+                Ok(())
+            },
+        )
+    }
+}
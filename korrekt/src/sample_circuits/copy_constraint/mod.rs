@@ -1 +1,7 @@
+pub mod equal_instances;
 pub mod fibonacci;
+pub mod fixed_copy;
+pub mod fixed_scale;
+pub mod free_sum;
+pub mod gadget_regions;
+pub mod shared_instance;
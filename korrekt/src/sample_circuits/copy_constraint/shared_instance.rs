@@ -0,0 +1,88 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Instance, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `SharedInstanceCircuit` reads the public input `i[1]` twice: from a gate of its second
+/// region, and through `b` which its first region copies from the instance column.
+///
+/// |   Row   |   a    |   b    |   i    |   s    |
+/// |---------|--------|--------|--------|--------|
+/// |   0     |  i[0]  |  i[1]  |  i[0]  |   1    |
+/// |   1     |  i[1]  |   -    |  i[1]  |   1    |
+///
+/// Gate: public: s*(a-i[cur])
+pub struct SharedInstanceCircuit<F: FieldExt> {
+    a: F,
+    b: F,
+}
+
+#[derive(Clone)]
+pub struct SharedInstanceCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    i: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for SharedInstanceCircuit<F> {
+    fn default() -> Self {
+        SharedInstanceCircuit {
+            a: F::from(1),
+            b: F::from(2),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for SharedInstanceCircuit<F> {
+    type Config = SharedInstanceCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(b);
+        meta.enable_equality(i);
+
+        meta.create_gate("public", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let i = meta.query_instance(i, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a - i)]
+        });
+
+        Self::Config { a, b, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "first region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice_from_instance(|| "b", config.i, 1, config.b, 0)?;
+                Ok(())
+            },
+        )?;
+        layouter.assign_region(
+            || "second region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.b))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -1,3 +1,4 @@
 pub mod lookup;
 pub mod lookup_underconstrained;
 pub mod multiple_lookups;
+pub mod square_lookup;
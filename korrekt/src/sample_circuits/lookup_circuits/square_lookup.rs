@@ -0,0 +1,94 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::{
+    Advice, Circuit, Column, ConstraintSystem, Error, Selector, TableColumn,
+};
+use halo2_proofs::poly::Rotation;
+
+/// `SquareLookupCircuit` checks that `b = a^2` with a two-column lookup into a fixed table of squares.
+///
+/// |   Row   |   a    |   b    |   s    |
+/// |---------|--------|--------|--------|
+/// |   0     |   3    |   9    |   1    |
+///
+/// Lookups:
+/// - square: (s*a, s*b) in (x, x^2) for x in 0..8
+pub struct SquareLookupCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct SquareLookupCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s: Selector,
+    table: [TableColumn; 2],
+}
+
+impl<F: FieldExt> Default for SquareLookupCircuit<F> {
+    fn default() -> Self {
+        SquareLookupCircuit { a: F::from(3) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for SquareLookupCircuit<F> {
+    type Config = SquareLookupCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let s = meta.complex_selector();
+        let table = [meta.lookup_table_column(), meta.lookup_table_column()];
+
+        meta.lookup("square", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            vec![(s.clone() * a, table[0]), (s * b, table[1])]
+        });
+
+        Self::Config { a, b, s, table }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_table(
+            || "square table",
+            |mut table| {
+                for x in 0..8u64 {
+                    table.assign_cell(
+                        || "x",
+                        config.table[0],
+                        x as usize,
+                        || Value::known(F::from(x)),
+                    )?;
+                    table.assign_cell(
+                        || "x^2",
+                        config.table[1],
+                        x as usize,
+                        || Value::known(F::from(x * x)),
+                    )?;
+                }
+                Ok(())
+            },
+        )?;
+
+        layouter.assign_region(
+            || "square region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.a * self.a))?;
+                Ok(())
+            },
+        )
+    }
+}
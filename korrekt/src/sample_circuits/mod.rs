@@ -1,3 +1,4 @@
 pub mod bit_decomposition;
 pub mod copy_constraint;
 pub mod lookup_circuits;
+pub mod static_checks;
@@ -0,0 +1,98 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `AlwaysOnSelectorCircuit` contains a selector enabled on every row its gate can apply to.
+///
+/// `s_double` is enabled on both rows of the only region using `a` and `b`, so the `double` gate could drop it.
+/// `s_bool` is only enabled on the first row, so it genuinely conditions the `bool` gate.
+///
+/// |   Row   |   a    |   b    | s_double | s_bool |
+/// |---------|--------|--------|----------|--------|
+/// |   0     |   1    |   2    |    1     |   1    |
+/// |   1     |   3    |   6    |    1     |   0    |
+///
+/// Gates:
+/// - double: s_double*(b-2*a)
+/// - bool: s_bool*(a*(1-a))
+pub struct AlwaysOnSelectorCircuit<F: FieldExt> {
+    a: [F; 2],
+}
+
+#[derive(Clone)]
+pub struct AlwaysOnSelectorCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s_double: Selector,
+    s_bool: Selector,
+}
+
+impl<F: FieldExt> Default for AlwaysOnSelectorCircuit<F> {
+    fn default() -> Self {
+        AlwaysOnSelectorCircuit {
+            a: [F::one(), F::from(3)],
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for AlwaysOnSelectorCircuit<F> {
+    type Config = AlwaysOnSelectorCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let s_double = meta.selector();
+        let s_bool = meta.selector();
+
+        meta.create_gate("double", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s_double = meta.query_selector(s_double);
+            vec![s_double * (b - a * F::from(2))]
+        });
+
+        meta.create_gate("bool", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let s_bool = meta.query_selector(s_bool);
+            vec![s_bool * (a.clone() * (Expression::Constant(F::one()) - a))]
+        });
+
+        Self::Config {
+            a,
+            b,
+            s_double,
+            s_bool,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "double region",
+            |mut region| {
+                config.s_bool.enable(&mut region, 0)?;
+                for (row, a) in self.a.iter().enumerate() {
+                    config.s_double.enable(&mut region, row)?;
+                    region.assign_advice(|| "a", config.a, row, || Value::known(*a))?;
+                    region.assign_advice(
+                        || "b",
+                        config.b,
+                        row,
+                        || Value::known(*a * F::from(2)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `AlwaysViolatedGateCircuit` contains a gate that can never be satisfied once its selector is enabled.
+///
+/// The witness term of the `always_violated` gate is multiplied by zero, so the gate reduces to the
+/// nonzero constant `5` wherever `s` is enabled.
+///
+/// |   Row   |   x    |    s     |
+/// |---------|--------|----------|
+/// |   0     |   x    |    1     |
+///
+/// Gate: always_violated: s*(0*x+5)
+/// Gate:        is_one:   s*(x-1)
+pub struct AlwaysViolatedGateCircuit<F: FieldExt> {
+    x: F,
+}
+
+#[derive(Clone)]
+pub struct AlwaysViolatedGateCircuitConfig {
+    x: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for AlwaysViolatedGateCircuit<F> {
+    fn default() -> Self {
+        AlwaysViolatedGateCircuit { x: F::one() }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for AlwaysViolatedGateCircuit<F> {
+    type Config = AlwaysViolatedGateCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let x = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("always_violated", |meta| {
+            let x = meta.query_advice(x, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (x * F::zero() + Expression::Constant(F::from(5)))]
+        });
+        meta.create_gate("is_one", |meta| {
+            let x = meta.query_advice(x, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (x - Expression::Constant(F::one()))]
+        });
+
+        Self::Config { x, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "violated region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "x", config.x, 0, || Value::known(self.x))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Fixed};
+use halo2_proofs::poly::Rotation;
+
+/// `BooleanFixedColumnCircuit` gates its constraint with a fixed column `q` holding only 0 and 1, which behaves
+/// like a selector, and scales it with a fixed column `c` holding several values.
+///
+/// |   Row   |   a    |   b    |   q    |   c    |
+/// |---------|--------|--------|--------|--------|
+/// |   0     |   a    |  1*a   |   1    |   1    |
+/// |   1     |   a    |  2*a   |   1    |   2    |
+/// |   2     |   a    |  3*a   |   1    |   3    |
+///
+/// Gates:
+/// - scale: q*(b-c*a)
+pub struct BooleanFixedColumnCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct BooleanFixedColumnCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    q: Column<Fixed>,
+    c: Column<Fixed>,
+}
+
+impl<F: FieldExt> Default for BooleanFixedColumnCircuit<F> {
+    fn default() -> Self {
+        BooleanFixedColumnCircuit { a: F::from(5) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for BooleanFixedColumnCircuit<F> {
+    type Config = BooleanFixedColumnCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let q = meta.fixed_column();
+        let c = meta.fixed_column();
+
+        meta.create_gate("scale", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let q = meta.query_fixed(q, Rotation::cur());
+            let c = meta.query_fixed(c, Rotation::cur());
+            vec![q * (b - c * a)]
+        });
+
+        Self::Config { a, b, q, c }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "scale region",
+            |mut region| {
+                for row in 0..3 {
+                    let scale = F::from(row as u64 + 1);
+                    region.assign_advice(|| "a", config.a, row, || Value::known(self.a))?;
+                    region.assign_advice(|| "b", config.b, row, || Value::known(scale * self.a))?;
+                    region.assign_fixed(|| "q", config.q, row, || Value::known(F::one()))?;
+                    region.assign_fixed(|| "c", config.c, row, || Value::known(scale))?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
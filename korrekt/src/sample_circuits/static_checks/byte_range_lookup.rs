@@ -0,0 +1,87 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector, TableColumn};
+use halo2_proofs::poly::Rotation;
+
+/// `ByteRangeLookupCircuit` range checks `a` against a table holding every byte.
+///
+/// The lookup only constrains `a` if it can hold values outside of a byte. When `a` is known to be a byte
+/// (e.g. it comes from a decomposition checked elsewhere), the lookup never fails.
+///
+/// |   Row   |   a    |    q     |   byte   |
+/// |---------|--------|----------|----------|
+/// |   0     |   a    |    1     |    0     |
+/// |   ...   |        |          |   ...    |
+/// |   255   |        |          |   255    |
+///
+/// Lookup: q*a in byte
+pub struct ByteRangeLookupCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct ByteRangeLookupCircuitConfig {
+    a: Column<Advice>,
+    q: Selector,
+    byte: TableColumn,
+}
+
+impl<F: FieldExt> Default for ByteRangeLookupCircuit<F> {
+    fn default() -> Self {
+        ByteRangeLookupCircuit { a: F::from(42) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for ByteRangeLookupCircuit<F> {
+    type Config = ByteRangeLookupCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let q = meta.complex_selector();
+        let byte = meta.lookup_table_column();
+
+        meta.lookup("byte", |meta| {
+            let q = meta.query_selector(q);
+            let a = meta.query_advice(a, Rotation::cur());
+            vec![(q * a, byte)]
+        });
+
+        Self::Config { a, q, byte }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_table(
+            || "byte table",
+            |mut table| {
+                for value in 0..256 {
+                    table.assign_cell(
+                        || "byte",
+                        config.byte,
+                        value,
+                        || Value::known(F::from(value as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )?;
+
+        layouter.assign_region(
+            || "byte region",
+            |mut region| {
+                config.q.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                Ok(())
+            },
+        )
+    }
+}
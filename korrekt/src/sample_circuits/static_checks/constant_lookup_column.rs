@@ -0,0 +1,91 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{
+    Advice, Circuit, Column, ConstraintSystem, Expression, Selector, TableColumn,
+};
+use halo2_proofs::poly::Rotation;
+
+/// `ConstantLookupColumnCircuit` range checks `a` against a table with a redundant `tag` column.
+///
+/// The `tag` column holds `1` on every table row and is looked up with the constant `1`, so it never
+/// discriminates membership and could be dropped from the table.
+///
+/// |   Row   |   a    |    q     |   value   |   tag   |
+/// |---------|--------|----------|-----------|---------|
+/// |   0     |   a    |    1     |     0     |    1    |
+/// |   ...   |        |          |    ...    |    1    |
+/// |   7     |        |          |     7     |    1    |
+///
+/// Lookup: (q*a, 1) in (value, tag)
+pub struct ConstantLookupColumnCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct ConstantLookupColumnCircuitConfig {
+    a: Column<Advice>,
+    q: Selector,
+    value: TableColumn,
+    tag: TableColumn,
+}
+
+impl<F: FieldExt> Default for ConstantLookupColumnCircuit<F> {
+    fn default() -> Self {
+        ConstantLookupColumnCircuit { a: F::from(3) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for ConstantLookupColumnCircuit<F> {
+    type Config = ConstantLookupColumnCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let q = meta.complex_selector();
+        let value = meta.lookup_table_column();
+        let tag = meta.lookup_table_column();
+
+        meta.lookup("tagged range", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let q = meta.query_selector(q);
+            vec![(q * a, value), (Expression::Constant(F::one()), tag)]
+        });
+
+        Self::Config { a, q, value, tag }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_table(
+            || "tagged range table",
+            |mut table| {
+                for i in 0..8 {
+                    table.assign_cell(
+                        || "value",
+                        config.value,
+                        i,
+                        || Value::known(F::from(i as u64)),
+                    )?;
+                    table.assign_cell(|| "tag", config.tag, i, || Value::known(F::one()))?;
+                }
+                Ok(())
+            },
+        )?;
+        layouter.assign_region(
+            || "range checked region",
+            |mut region| {
+                config.q.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                Ok(())
+            },
+        )
+    }
+}
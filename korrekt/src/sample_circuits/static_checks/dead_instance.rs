@@ -0,0 +1,86 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Fixed, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `DeadInstanceCircuit` reads two public inputs in gates, each with a coefficient that is always zero.
+///
+/// |   Row   |   a    |   b    |   f    |   i0   |   i1   |    s     |
+/// |---------|--------|--------|--------|--------|--------|----------|
+/// |   0     |   0    |   0    |   0    |   i0   |   i1   |    1     |
+///
+/// Gate: literal: s*(a-0*i0)
+/// Gate:   fixed: s*(b-f*i1)
+pub struct DeadInstanceCircuit<F: FieldExt> {
+    a: F,
+    b: F,
+}
+
+#[derive(Clone)]
+pub struct DeadInstanceCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    f: Column<Fixed>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for DeadInstanceCircuit<F> {
+    fn default() -> Self {
+        DeadInstanceCircuit {
+            a: F::zero(),
+            b: F::zero(),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for DeadInstanceCircuit<F> {
+    type Config = DeadInstanceCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let f = meta.fixed_column();
+        let i0 = meta.instance_column();
+        let i1 = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("literal", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let i0 = meta.query_instance(i0, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a - Expression::Constant(F::zero()) * i0)]
+        });
+        meta.create_gate("fixed", |meta| {
+            let b = meta.query_advice(b, Rotation::cur());
+            let f = meta.query_fixed(f, Rotation::cur());
+            let i1 = meta.query_instance(i1, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (b - f * i1)]
+        });
+
+        Self::Config { a, b, f, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "dead region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.b))?;
+                region.assign_fixed(|| "f", config.f, 0, || Value::known(F::zero()))?;
+                Ok(())
+            },
+        )
+    }
+}
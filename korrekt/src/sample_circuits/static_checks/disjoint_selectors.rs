@@ -0,0 +1,84 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `DisjointSelectorsCircuit` enables two selectors on different rows of the same region.
+///
+/// The `both` gate is only a constant where both selectors are enabled, which never happens. The `folded` gate
+/// reduces to `2-1`, a nonzero constant, on the row where `s1` is enabled.
+///
+/// |   Row   |   x    |   s0   |   s1   |
+/// |---------|--------|--------|--------|
+/// |   0     |   1    |   1    |   0    |
+/// |   1     |   1    |   0    |   1    |
+///
+/// Gate: both: s0*s1*5
+/// Gate: folded: s1*(2-1)
+/// Gate: is_one: s0*(x-1)
+pub struct DisjointSelectorsCircuit<F: FieldExt> {
+    x: F,
+}
+
+#[derive(Clone)]
+pub struct DisjointSelectorsCircuitConfig {
+    x: Column<Advice>,
+    s0: Selector,
+    s1: Selector,
+}
+
+impl<F: FieldExt> Default for DisjointSelectorsCircuit<F> {
+    fn default() -> Self {
+        DisjointSelectorsCircuit { x: F::one() }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for DisjointSelectorsCircuit<F> {
+    type Config = DisjointSelectorsCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let x = meta.advice_column();
+        let s0 = meta.selector();
+        let s1 = meta.selector();
+
+        meta.create_gate("both", |meta| {
+            let s0 = meta.query_selector(s0);
+            let s1 = meta.query_selector(s1);
+            vec![s0 * s1 * Expression::Constant(F::from(5))]
+        });
+        meta.create_gate("folded", |meta| {
+            let s1 = meta.query_selector(s1);
+            vec![s1 * (Expression::Constant(F::from(2)) - Expression::Constant(F::one()))]
+        });
+        meta.create_gate("is_one", |meta| {
+            let x = meta.query_advice(x, Rotation::cur());
+            let s0 = meta.query_selector(s0);
+            vec![s0 * (x - Expression::Constant(F::one()))]
+        });
+
+        Self::Config { x, s0, s1 }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "disjoint region",
+            |mut region| {
+                config.s0.enable(&mut region, 0)?;
+                config.s1.enable(&mut region, 1)?;
+                region.assign_advice(|| "x", config.x, 0, || Value::known(self.x))?;
+                region.assign_advice(|| "x", config.x, 1, || Value::known(self.x))?;
+                Ok(())
+            },
+        )
+    }
+}
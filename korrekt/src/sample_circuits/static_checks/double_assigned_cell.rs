@@ -0,0 +1,76 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `DoubleAssignedCellCircuit` assigns the same cell twice within a region.
+///
+/// `a` is first assigned `a`, then overwritten with `b`: only the second value reaches the witness.
+///
+/// |   Row   |   a    |   b    |    s     |
+/// |---------|--------|--------|----------|
+/// |   0     |  a, b  |   b    |    1     |
+///
+/// Gate: equal: s*(a-b)
+pub struct DoubleAssignedCellCircuit<F: FieldExt> {
+    a: F,
+    b: F,
+}
+
+#[derive(Clone)]
+pub struct DoubleAssignedCellCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for DoubleAssignedCellCircuit<F> {
+    fn default() -> Self {
+        DoubleAssignedCellCircuit {
+            a: F::one(),
+            b: F::from(2),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for DoubleAssignedCellCircuit<F> {
+    type Config = DoubleAssignedCellCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("equal", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a - b)]
+        });
+
+        Self::Config { a, b, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "equal region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.b))?;
+                region.assign_advice(|| "a again", config.a, 0, || Value::known(self.b))?;
+                Ok(())
+            },
+        )
+    }
+}
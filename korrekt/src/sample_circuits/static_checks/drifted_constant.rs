@@ -0,0 +1,106 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{
+    Advice, Circuit, Column, ConstraintSystem, Expression, Selector, TableColumn,
+};
+use halo2_proofs::poly::Rotation;
+
+/// `DriftedConstantCircuit` complements a nibble with a constant drifted from its range table.
+///
+/// `a` is range checked against a table holding every nibble, and `b` is meant to be its complement `15 - a`. The
+/// `complement` gate uses 16 instead, so `b` is off by one and no longer a nibble.
+///
+/// |   Row   |   a    |   b    |   s    |   q    | nibble |
+/// |---------|--------|--------|--------|--------|--------|
+/// |   0     |   a    | 16-a   |   1    |   1    |   0    |
+/// |   ...   |        |        |        |        |  ...   |
+/// |   15    |        |        |        |        |   15   |
+///
+/// Gates:
+/// - complement: s*(a+b-16)
+///
+/// Lookup: q*a in nibble
+pub struct DriftedConstantCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct DriftedConstantCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s: Selector,
+    q: Selector,
+    nibble: TableColumn,
+}
+
+impl<F: FieldExt> Default for DriftedConstantCircuit<F> {
+    fn default() -> Self {
+        DriftedConstantCircuit { a: F::from(5) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for DriftedConstantCircuit<F> {
+    type Config = DriftedConstantCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let s = meta.selector();
+        let q = meta.complex_selector();
+        let nibble = meta.lookup_table_column();
+
+        meta.lookup("nibble", |meta| {
+            let q = meta.query_selector(q);
+            let a = meta.query_advice(a, Rotation::cur());
+            vec![(q * a, nibble)]
+        });
+
+        meta.create_gate("complement", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s = meta.query_selector(s);
+            // Should be 15.
+            vec![s * (a + b - Expression::Constant(F::from(16)))]
+        });
+
+        Self::Config { a, b, s, q, nibble }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_table(
+            || "nibble table",
+            |mut table| {
+                for value in 0..16 {
+                    table.assign_cell(
+                        || "nibble",
+                        config.nibble,
+                        value,
+                        || Value::known(F::from(value as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )?;
+
+        layouter.assign_region(
+            || "complement region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                config.q.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(F::from(16) - self.a))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `ExclusiveSelectorsCircuit` enables two mutually exclusive selectors on the same row.
+///
+/// Each row either adds or multiplies `a` and `b`, and the `exclusive` gate forbids doing both. The second region
+/// enables both selectors anyway: its values satisfy `add` and `mul`, but not `exclusive`.
+///
+/// | Region  |   a    |   b    |   c    | s_add  | s_mul  |
+/// |---------|--------|--------|--------|--------|--------|
+/// | add     |   1    |   2    |   3    |   1    |   0    |
+/// | both    |   2    |   2    |   4    |   1    |   1    |
+///
+/// Gates:
+/// - add: s_add*(c-a-b)
+/// - mul: s_mul*(c-a*b)
+/// - exclusive: s_add*s_mul
+pub struct ExclusiveSelectorsCircuit<F: FieldExt> {
+    a: [F; 2],
+}
+
+#[derive(Clone)]
+pub struct ExclusiveSelectorsCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    s_add: Selector,
+    s_mul: Selector,
+}
+
+impl<F: FieldExt> Default for ExclusiveSelectorsCircuit<F> {
+    fn default() -> Self {
+        ExclusiveSelectorsCircuit {
+            a: [F::from(1), F::from(2)],
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for ExclusiveSelectorsCircuit<F> {
+    type Config = ExclusiveSelectorsCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        // Simple selectors cannot be multiplied together.
+        let s_add = meta.complex_selector();
+        let s_mul = meta.complex_selector();
+
+        meta.create_gate("add", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let s_add = meta.query_selector(s_add);
+            vec![s_add * (c - a - b)]
+        });
+
+        meta.create_gate("mul", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let s_mul = meta.query_selector(s_mul);
+            vec![s_mul * (c - a * b)]
+        });
+
+        meta.create_gate("exclusive", |meta| {
+            let s_add = meta.query_selector(s_add);
+            let s_mul = meta.query_selector(s_mul);
+            vec![s_add * s_mul]
+        });
+
+        Self::Config {
+            a,
+            b,
+            c,
+            s_add,
+            s_mul,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "add",
+            |mut region| {
+                config.s_add.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a[0]))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(F::from(2)))?;
+                region.assign_advice(
+                    || "c",
+                    config.c,
+                    0,
+                    || Value::known(self.a[0] + F::from(2)),
+                )?;
+                Ok(())
+            },
+        )?;
+        layouter.assign_region(
+            || "both",
+            |mut region| {
+                config.s_add.enable(&mut region, 0)?;
+                // Should not be enabled with `s_add`.
+                config.s_mul.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a[1]))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(F::from(2)))?;
+                region.assign_advice(
+                    || "c",
+                    config.c,
+                    0,
+                    || Value::known(self.a[1] * F::from(2)),
+                )?;
+                Ok(())
+            },
+        )
+    }
+}
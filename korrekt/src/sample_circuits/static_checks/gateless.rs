@@ -0,0 +1,65 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem};
+
+/// `GatelessCircuit` assigns a product without defining any gate, so nothing relates its cells.
+///
+/// |   Row   |   a    |   b    |   c    |
+/// |---------|--------|--------|--------|
+/// |   0     |   a    |   b    |  a*b   |
+///
+/// Gates: none
+pub struct GatelessCircuit<F: FieldExt> {
+    a: F,
+    b: F,
+}
+
+#[derive(Clone)]
+pub struct GatelessCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+}
+
+impl<F: FieldExt> Default for GatelessCircuit<F> {
+    fn default() -> Self {
+        GatelessCircuit {
+            a: F::from(2),
+            b: F::from(3),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for GatelessCircuit<F> {
+    type Config = GatelessCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+
+        Self::Config { a, b, c }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "product region",
+            |mut region| {
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.b))?;
+                region.assign_advice(|| "c", config.c, 0, || Value::known(self.a * self.b))?;
+                Ok(())
+            },
+        )
+    }
+}
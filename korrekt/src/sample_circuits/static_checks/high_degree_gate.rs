@@ -0,0 +1,91 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `HighDegreeGateCircuit` checks a product of three values in a single gate of degree 4.
+///
+/// Computing `a*b` in a helper column would lower the degree of the circuit to 3.
+///
+/// |   Row   |   a    |   b    |   c    |   d    |   s    |
+/// |---------|--------|--------|--------|--------|--------|
+/// |   0     |   2    |   3    |   4    |   24   |   1    |
+///
+/// Gates:
+/// - product: s*(a*b*c-d)
+pub struct HighDegreeGateCircuit<F: FieldExt> {
+    a: F,
+    b: F,
+    c: F,
+}
+
+#[derive(Clone)]
+pub struct HighDegreeGateCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    d: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for HighDegreeGateCircuit<F> {
+    fn default() -> Self {
+        HighDegreeGateCircuit {
+            a: F::from(2),
+            b: F::from(3),
+            c: F::from(4),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for HighDegreeGateCircuit<F> {
+    type Config = HighDegreeGateCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let d = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("product", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let d = meta.query_advice(d, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a * b * c - d)]
+        });
+
+        Self::Config { a, b, c, d, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "product region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.b))?;
+                region.assign_advice(|| "c", config.c, 0, || Value::known(self.c))?;
+                region.assign_advice(
+                    || "d",
+                    config.d,
+                    0,
+                    || Value::known(self.a * self.b * self.c),
+                )?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Fixed, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `InertGateCircuit` contains a gate multiplied by a fixed column that is zero on every row.
+///
+/// The `equal` gate looks like it forces `b` to equal `a`, but `f` is always zero, so it never constrains anything.
+///
+/// |   Row   |   a    |   b    |   f    |   s    |
+/// |---------|--------|--------|--------|--------|
+/// |   0     |   1    |   2    |   0    |   1    |
+///
+/// Gates:
+/// - equal: s*f*(b-a)
+/// - double: s*(b-2*a)
+pub struct InertGateCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct InertGateCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    f: Column<Fixed>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for InertGateCircuit<F> {
+    fn default() -> Self {
+        InertGateCircuit { a: F::one() }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for InertGateCircuit<F> {
+    type Config = InertGateCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let f = meta.fixed_column();
+        let s = meta.selector();
+
+        meta.create_gate("equal", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let f = meta.query_fixed(f, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * f * (b - a)]
+        });
+
+        meta.create_gate("double", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (b - a * F::from(2))]
+        });
+
+        Self::Config { a, b, f, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "double region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.a * F::from(2)))?;
+                region.assign_fixed(|| "f", config.f, 0, || Value::known(F::zero()))?;
+                Ok(())
+            },
+        )
+    }
+}
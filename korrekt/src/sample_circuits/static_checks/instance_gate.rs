@@ -0,0 +1,73 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `InstanceGateCircuit` constrains an advice cell to a public input inside a gate instead of a copy constraint.
+///
+/// |   Row   |   a    |   i    |    s     |
+/// |---------|--------|--------|----------|
+/// |   0     |   a    |   i    |    1     |
+///
+/// Gate: equals_instance: s*(a-i)
+/// Gate:      bool_check: s*a*(1-a)
+pub struct InstanceGateCircuit<F: FieldExt> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct InstanceGateCircuitConfig {
+    a: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for InstanceGateCircuit<F> {
+    fn default() -> Self {
+        InstanceGateCircuit { a: F::one() }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for InstanceGateCircuit<F> {
+    type Config = InstanceGateCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("equals_instance", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let i = meta.query_instance(i, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a - i)]
+        });
+        meta.create_gate("bool_check", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * a.clone() * (Expression::Constant(F::one()) - a)]
+        });
+
+        Self::Config { a, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "instance region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                Ok(())
+            },
+        )
+    }
+}
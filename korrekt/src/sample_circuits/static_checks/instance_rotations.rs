@@ -0,0 +1,69 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `InstanceRotationsCircuit` reads a public input at two rotations, as accumulators do.
+///
+/// |   Row   |   d    |   i    |    s     |
+/// |---------|--------|--------|----------|
+/// |   0     |   d    |  i[0]  |    1     |
+/// |   1     |   -    |  i[1]  |    0     |
+///
+/// Gate: step: s*(i[next]-i[cur]-d)
+pub struct InstanceRotationsCircuit<F: FieldExt> {
+    d: F,
+}
+
+#[derive(Clone)]
+pub struct InstanceRotationsCircuitConfig {
+    d: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for InstanceRotationsCircuit<F> {
+    fn default() -> Self {
+        InstanceRotationsCircuit { d: F::from(2) }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for InstanceRotationsCircuit<F> {
+    type Config = InstanceRotationsCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let d = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("step", |meta| {
+            let d = meta.query_advice(d, Rotation::cur());
+            let cur = meta.query_instance(i, Rotation::cur());
+            let next = meta.query_instance(i, Rotation::next());
+            let s = meta.query_selector(s);
+            vec![s * (next - cur - d)]
+        });
+
+        Self::Config { d, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "step region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "d", config.d, 0, || Value::known(self.d))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `MisplacedSelectorCircuit` enables the selector of a gate in a region that has none of its columns.
+///
+/// The `double` gate checks `b = 2*a` in "main", which assigns `a` and `b`, and enables `s` there only if
+/// `ENABLED_IN_MAIN`. "other" only assigns `c` but always enables `s`, so the gate constrains the unassigned `a` and
+/// `b` cells of "other". See `OrphanedSelectorCircuit` and `SelectorBleedCircuit`.
+///
+/// | Region  |   a    |   b    |   c    |   s              |
+/// |---------|--------|--------|--------|------------------|
+/// | main    |   3    |   6    |        | ENABLED_IN_MAIN  |
+/// | other   |        |        |   5    |   1              |
+///
+/// Gates:
+/// - double: s*(b-2*a)
+pub struct MisplacedSelectorCircuit<F: FieldExt, const ENABLED_IN_MAIN: bool> {
+    a: F,
+}
+
+#[derive(Clone)]
+pub struct MisplacedSelectorCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt, const ENABLED_IN_MAIN: bool> Default
+    for MisplacedSelectorCircuit<F, ENABLED_IN_MAIN>
+{
+    fn default() -> Self {
+        MisplacedSelectorCircuit { a: F::from(3) }
+    }
+}
+
+impl<F: FieldExt, const ENABLED_IN_MAIN: bool> Circuit<F>
+    for MisplacedSelectorCircuit<F, ENABLED_IN_MAIN>
+{
+    type Config = MisplacedSelectorCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("double", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (b - a * Expression::Constant(F::from(2)))]
+        });
+
+        Self::Config { a, b, c, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "main",
+            |mut region| {
+                if ENABLED_IN_MAIN {
+                    config.s.enable(&mut region, 0)?;
+                }
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(self.a.double()))?;
+                Ok(())
+            },
+        )?;
+        layouter.assign_region(
+            || "other",
+            |mut region| {
+                // Should not enable `s` here.
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "c", config.c, 0, || Value::known(F::from(5)))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,23 @@
+pub mod always_on_selector;
+pub mod always_violated_gate;
+pub mod boolean_fixed_column;
+pub mod byte_range_lookup;
+pub mod constant_lookup_column;
+pub mod dead_instance;
+pub mod disjoint_selectors;
+pub mod double_assigned_cell;
+pub mod drifted_constant;
+pub mod exclusive_selectors;
+pub mod gateless;
+pub mod high_degree_gate;
+pub mod inert_gate;
+pub mod instance_gate;
+pub mod instance_rotations;
+pub mod misplaced_selector;
+pub mod noop_gate;
+pub mod off_by_one_selector;
+pub mod orphaned_selector;
+pub mod pinned_cell;
+pub mod selector_bleed;
+pub mod unassigned_cell;
+pub mod uniform_column;
@@ -0,0 +1,75 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `NoopGateCircuit` contains a gate whose polynomial is identically zero.
+///
+/// The `noop` gate subtracts `x` from itself, so it holds for any witness even though its selector is enabled.
+///
+/// |   Row   |   x    |   s    |
+/// |---------|--------|--------|
+/// |   0     |   1    |   1    |
+///
+/// Gates:
+/// - noop: s*(x-x)
+/// - bool: s*(x*(1-x))
+pub struct NoopGateCircuit<F: FieldExt> {
+    x: F,
+}
+
+#[derive(Clone)]
+pub struct NoopGateCircuitConfig {
+    x: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for NoopGateCircuit<F> {
+    fn default() -> Self {
+        NoopGateCircuit { x: F::one() }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for NoopGateCircuit<F> {
+    type Config = NoopGateCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let x = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("noop", |meta| {
+            let x = meta.query_advice(x, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (x.clone() - x)]
+        });
+
+        meta.create_gate("bool", |meta| {
+            let x = meta.query_advice(x, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (x.clone() * (Expression::Constant(F::one()) - x))]
+        });
+
+        Self::Config { x, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "noop region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "x", config.x, 0, || Value::known(self.x))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `OffByOneSelectorCircuit` enables a selector one row too early for a gate reading the previous row.
+///
+/// The `increment` gate checks `b = a + 1` against the `a` of the previous row, so it should be enabled on rows 1
+/// and 2. It is enabled on rows 0 and 1 instead: on row 0 it reads the row above the region, and the increment
+/// of row 2 is never checked.
+///
+/// |   Row   |   a    |   b    |   s    |
+/// |---------|--------|--------|--------|
+/// |   0     |   1    |        |   1    |
+/// |   1     |   5    |   2    |   1    |
+/// |   2     |        |   6    |   0    |
+///
+/// Gates:
+/// - increment: s*(b-a_prev-1)
+pub struct OffByOneSelectorCircuit<F: FieldExt> {
+    a: [F; 2],
+}
+
+#[derive(Clone)]
+pub struct OffByOneSelectorCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for OffByOneSelectorCircuit<F> {
+    fn default() -> Self {
+        OffByOneSelectorCircuit {
+            a: [F::from(1), F::from(5)],
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for OffByOneSelectorCircuit<F> {
+    type Config = OffByOneSelectorCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("increment", |meta| {
+            let a_prev = meta.query_advice(a, Rotation::prev());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (b - a_prev - Expression::Constant(F::one()))]
+        });
+
+        Self::Config { a, b, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "increment region",
+            |mut region| {
+                for (row, a) in self.a.iter().enumerate() {
+                    // Should be `row + 1`.
+                    config.s.enable(&mut region, row)?;
+                    region.assign_advice(|| "a", config.a, row, || Value::known(*a))?;
+                    region.assign_advice(
+                        || "b",
+                        config.b,
+                        row + 1,
+                        || Value::known(*a + F::one()),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use crate::sample_circuits::static_checks::misplaced_selector::MisplacedSelectorCircuit;
+
+/// `OrphanedSelectorCircuit` enables the selector of a gate only in a region that has none of its columns.
+///
+/// The `double` gate checks `b = 2*a`, but the region assigning `a` and `b` does not enable `s`. The other region
+/// does, while only assigning `c`, so the gate never constrains `a` and `b`.
+///
+/// | Region  |   a    |   b    |   c    |   s    |
+/// |---------|--------|--------|--------|--------|
+/// | main    |   3    |   6    |        |   0    |
+/// | other   |        |        |   5    |   1    |
+///
+/// Gates:
+/// - double: s*(b-2*a)
+pub type OrphanedSelectorCircuit<F> = MisplacedSelectorCircuit<F, false>;
@@ -0,0 +1,93 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `PinnedCellCircuit` contains a cell pinned to a constant by one gate and read by another.
+///
+/// The `pin` gate forces `a` to 5 on the first row, where the `square` gate also reads it: `square` could use the
+/// constant 25 there instead. On the second row `a` is free.
+///
+/// |   Row   |   a    |   b    | s_pin | s_square |
+/// |---------|--------|--------|-------|----------|
+/// |   0     |   5    |   25   |   1   |    1     |
+/// |   1     |   3    |   9    |   0   |    1     |
+///
+/// Gates:
+/// - pin: s_pin*(a-5)
+/// - square: s_square*(b-a*a)
+pub struct PinnedCellCircuit<F: FieldExt> {
+    a: [F; 2],
+}
+
+#[derive(Clone)]
+pub struct PinnedCellCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s_pin: Selector,
+    s_square: Selector,
+}
+
+impl<F: FieldExt> Default for PinnedCellCircuit<F> {
+    fn default() -> Self {
+        PinnedCellCircuit {
+            a: [F::from(5), F::from(3)],
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for PinnedCellCircuit<F> {
+    type Config = PinnedCellCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let s_pin = meta.selector();
+        let s_square = meta.selector();
+
+        meta.create_gate("pin", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let s_pin = meta.query_selector(s_pin);
+            vec![s_pin * (a - Expression::Constant(F::from(5)))]
+        });
+
+        meta.create_gate("square", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let s_square = meta.query_selector(s_square);
+            vec![s_square * (b - a.clone() * a)]
+        });
+
+        Self::Config {
+            a,
+            b,
+            s_pin,
+            s_square,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "square region",
+            |mut region| {
+                config.s_pin.enable(&mut region, 0)?;
+                for (row, a) in self.a.iter().enumerate() {
+                    config.s_square.enable(&mut region, row)?;
+                    region.assign_advice(|| "a", config.a, row, || Value::known(*a))?;
+                    region.assign_advice(|| "b", config.b, row, || Value::known(*a * *a))?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use crate::sample_circuits::static_checks::misplaced_selector::MisplacedSelectorCircuit;
+
+/// `SelectorBleedCircuit` enables the selector of a gate both in the region it was meant for and in another one.
+///
+/// The `double` gate checks `b = 2*a` in "main", which assigns `a` and `b`. "other" only assigns `c` but enables `s`
+/// as well, so the gate also constrains the unassigned `a` and `b` cells of "other".
+///
+/// | Region  |   a    |   b    |   c    |   s    |
+/// |---------|--------|--------|--------|--------|
+/// | main    |   3    |   6    |        |   1    |
+/// | other   |        |        |   5    |   1    |
+///
+/// Gates:
+/// - double: s*(b-2*a)
+pub type SelectorBleedCircuit<F> = MisplacedSelectorCircuit<F, true>;
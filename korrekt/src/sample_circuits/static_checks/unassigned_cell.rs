@@ -0,0 +1,78 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `UnassignedCellCircuit` contains a gate reading a cell that the region never assigns.
+///
+/// The `sum` gate reads `b`, but the region only assigns `a` and `c`, so the prover is free to pick any
+/// value for `b`.
+///
+/// |   Row   |   a    |   b    |   c    |    s     |
+/// |---------|--------|--------|--------|----------|
+/// |   0     |   a    |   -    |   c    |    1     |
+///
+/// Gate: sum: s*(a+b-c)
+pub struct UnassignedCellCircuit<F: FieldExt> {
+    a: F,
+    c: F,
+}
+
+#[derive(Clone)]
+pub struct UnassignedCellCircuitConfig {
+    a: Column<Advice>,
+    c: Column<Advice>,
+    s: Selector,
+}
+
+impl<F: FieldExt> Default for UnassignedCellCircuit<F> {
+    fn default() -> Self {
+        UnassignedCellCircuit {
+            a: F::one(),
+            c: F::from(3),
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for UnassignedCellCircuit<F> {
+    type Config = UnassignedCellCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let s = meta.selector();
+
+        meta.create_gate("sum", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let s = meta.query_selector(s);
+            vec![s * (a + b - c)]
+        });
+
+        Self::Config { a, c, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "sum region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a))?;
+                region.assign_advice(|| "c", config.c, 0, || Value::known(self.c))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,104 @@
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
+use halo2_proofs::plonk::Error;
+use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Expression, Selector};
+use halo2_proofs::poly::Rotation;
+
+/// `UniformColumnCircuit` constrains the `a` column the same way in every region.
+///
+/// Both regions enable `s_bool`, so `a` is boolean everywhere it is assigned. Only the first region enables
+/// `s_double`, so the constraints on `b` differ between the regions, and `c` is assigned in the first region only.
+///
+/// | Region  |   a    |   b    |   c    | s_bool | s_double |
+/// |---------|--------|--------|--------|--------|----------|
+/// | first   |   1    |   4    |   2    |   1    |    1     |
+/// | second  |   0    |   7    |        |   1    |    0     |
+///
+/// Gates:
+/// - bool: s_bool*a*(1-a)
+/// - double: s_double*(b-2*c)
+pub struct UniformColumnCircuit<F: FieldExt> {
+    a: [F; 2],
+}
+
+#[derive(Clone)]
+pub struct UniformColumnCircuitConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    s_bool: Selector,
+    s_double: Selector,
+}
+
+impl<F: FieldExt> Default for UniformColumnCircuit<F> {
+    fn default() -> Self {
+        UniformColumnCircuit {
+            a: [F::one(), F::zero()],
+        }
+    }
+}
+
+impl<F: FieldExt> Circuit<F> for UniformColumnCircuit<F> {
+    type Config = UniformColumnCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let s_bool = meta.selector();
+        let s_double = meta.selector();
+
+        meta.create_gate("bool", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let s_bool = meta.query_selector(s_bool);
+            vec![s_bool * a.clone() * (Expression::Constant(F::one()) - a)]
+        });
+
+        meta.create_gate("double", |meta| {
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let s_double = meta.query_selector(s_double);
+            vec![s_double * (b - c * Expression::Constant(F::from(2)))]
+        });
+
+        Self::Config {
+            a,
+            b,
+            c,
+            s_bool,
+            s_double,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "first",
+            |mut region| {
+                config.s_bool.enable(&mut region, 0)?;
+                config.s_double.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a[0]))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(F::from(4)))?;
+                region.assign_advice(|| "c", config.c, 0, || Value::known(F::from(2)))?;
+                Ok(())
+            },
+        )?;
+        layouter.assign_region(
+            || "second",
+            |mut region| {
+                config.s_bool.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || Value::known(self.a[1]))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(F::from(7)))?;
+                Ok(())
+            },
+        )
+    }
+}
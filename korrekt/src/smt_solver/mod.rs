@@ -1,2 +1,4 @@
 pub mod smt;
 pub mod smt_parser;
+pub mod smt_validator;
+pub mod solver;
@@ -1,13 +1,17 @@
-use std::collections::HashMap;
+use anyhow::{anyhow, Context, Result};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::io::Write;
-use anyhow::{anyhow, Context, Result};
 
 use crate::circuit_analyzer::analyzer::{self, NodeType};
+use crate::circuit_analyzer::name_formatter;
 
 pub struct Printer<'a, W: 'a> {
     writer: &'a mut W,
-    pub vars: HashMap<String, bool>,
+    pub vars: BTreeMap<String, bool>,
+    pub definitions: BTreeSet<String>,
+    /// The commands written since declarations were deferred, see `write_start_grouped`.
+    deferred: Option<Vec<u8>>,
 }
 
 fn get_logic_string() -> String {
@@ -18,7 +22,16 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     pub fn new(writer: &'a mut W) -> Self {
         Self {
             writer,
-            vars: HashMap::new(),
+            vars: BTreeMap::new(),
+            definitions: BTreeSet::new(),
+            deferred: None,
+        }
+    }
+    /// Returns where commands are written: the writer, or the buffer of deferred commands.
+    fn sink(&mut self) -> &mut dyn Write {
+        match self.deferred.as_mut() {
+            Some(buffer) => buffer,
+            None => &mut *self.writer,
         }
     }
     /// Constructs a term string based on the provided operator and operands.
@@ -34,25 +47,8 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         right: String,
         ntr: analyzer::NodeType,
     ) -> String {
-        let l = if (matches!(ntl, NodeType::Advice)
-            || matches!(ntl, NodeType::Instance)
-            || matches!(ntl, NodeType::Fixed)
-            || matches!(ntl, NodeType::Constant))
-        {
-            left
-        } else {
-            format!("({})", left)
-        };
-
-        let r = if (matches!(ntr, NodeType::Advice)
-            || matches!(ntr, NodeType::Instance)
-            || matches!(ntr, NodeType::Fixed)
-            || matches!(ntr, NodeType::Constant))
-        {
-            right
-        } else {
-            format!("({})", right)
-        };
+        let l = wrap_operand(left, &ntl);
+        let r = wrap_operand(right, &ntr);
         let t = format!("ff.{} {} {}", op, l, r);
         t
     }
@@ -62,25 +58,20 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     /// including the SMT-LIB version, category, options, logic, and the definition of the finite field.
     ///
     fn write_start(&mut self, prime: String) {
-        writeln!(&mut self.writer, "(set-info :smt-lib-version 2.6)").unwrap();
-        writeln!(&mut self.writer, "(set-info :category \"crafted\")").unwrap();
-        writeln!(&mut self.writer, "(set-option :produce-models true)").unwrap();
-        writeln!(&mut self.writer, "(set-option :incremental true)").unwrap();
+        writeln!(self.sink(), "(set-info :smt-lib-version 2.6)").unwrap();
+        writeln!(self.sink(), "(set-info :category \"crafted\")").unwrap();
+        writeln!(self.sink(), "(set-option :produce-models true)").unwrap();
+        writeln!(self.sink(), "(set-option :incremental true)").unwrap();
 
-        writeln!(&mut self.writer, "(set-logic {})", get_logic_string()).unwrap();
-        writeln!(
-            &mut self.writer,
-            "(define-sort F () (_ FiniteField {}))",
-            prime
-        )
-        .unwrap();
+        writeln!(self.sink(), "(set-logic {})", get_logic_string()).unwrap();
+        writeln!(self.sink(), "(define-sort F () (_ FiniteField {}))", prime).unwrap();
     }
     /// Writes the end of the SMT-LIB file.
     ///
     /// This function writes the `(check-sat)` command at the end of the SMT-LIB file.
     ///
     fn write_end(&mut self) {
-        writeln!(&mut self.writer, "(check-sat)").unwrap();
+        writeln!(self.sink(), "(check-sat)").unwrap();
     }
     /// Writes a variable declaration in the SMT-LIB file.
     ///
@@ -88,17 +79,105 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     /// in the SMT-LIB file. The variable is declared to be of sort `F` (finite field).
     /// If a variable with the same name has already been declared, this function does nothing.
     ///
+    /// While declarations are deferred (see `write_start_grouped`), the variable is only recorded.
+    ///
     fn write_var(&mut self, name: String) {
         if self.vars.contains_key(&name) {
             return;
         }
         self.vars.insert(name.clone(), true);
-        writeln!(&mut self.writer, "(declare-fun {} () F)", name).unwrap();
+        if self.deferred.is_none() {
+            writeln!(self.sink(), "(declare-fun {} () F)", name).unwrap();
+        }
+    }
+    /// Writes the deferred declarations as a single block, followed by the commands written since.
+    ///
+    /// Variables are grouped by region, public inputs first, and sorted by column and row within each group;
+    /// variables that are not named after a cell with the default scheme come last, sorted by name. Afterwards
+    /// variables are declared as they are encountered again. This function does nothing if declarations are not
+    /// deferred.
+    ///
+    fn write_declarations(&mut self) {
+        let body = match self.deferred.take() {
+            Some(body) => body,
+            None => return,
+        };
+        let mut cells = vec![];
+        let mut others = vec![];
+        for name in self.vars.keys() {
+            match name_formatter::parse_default_name(name) {
+                Some(location) => cells.push((location, name)),
+                None => others.push(name),
+            }
+        }
+        cells.sort_by_key(|(location, _)| {
+            (
+                location.region,
+                location.kind,
+                location.column,
+                location.row,
+            )
+        });
+        let mut region = None;
+        for (location, name) in cells {
+            if region != Some(location.region) {
+                region = Some(location.region);
+                match location.region {
+                    Some(index) => writeln!(self.writer, "; region {}", index).unwrap(),
+                    None => writeln!(self.writer, "; public inputs").unwrap(),
+                }
+            }
+            writeln!(self.writer, "(declare-fun {} () F)", name).unwrap();
+        }
+        if !others.is_empty() {
+            writeln!(self.writer, "; other variables").unwrap();
+        }
+        for name in others {
+            writeln!(self.writer, "(declare-fun {} () F)", name).unwrap();
+        }
+        self.writer.write_all(&body).unwrap();
+    }
+    /// Writes a constant definition in the SMT-LIB file.
+    ///
+    /// This function writes a `define-fun` binding `name` to the term `value` of sort `F`, so that it can be referenced
+    /// by name. Definitions are not variables of the model and are tracked apart from `vars`.
+    /// If a definition with the same name has already been written, this function does nothing.
+    ///
+    fn write_define(&mut self, name: String, value: String) {
+        if !self.definitions.insert(name.clone()) {
+            return;
+        }
+        writeln!(self.sink(), "(define-fun {} () F {})", name, value).unwrap();
+    }
+    /// Writes constraints restricting a variable to the range `[0, 2^bits)`.
+    ///
+    /// Finite fields have no ordering, so the variable is decomposed into `bits` fresh boolean variables
+    /// `{name}-bit-{i}` that must sum up to it, `bits` being the number of `weights`, the literals of the powers of
+    /// two. `zero` is the literal the variable equals when there are no bits. Quoted names keep their bits inside
+    /// the quotes.
+    ///
+    fn write_range(&mut self, name: String, weights: &[String], zero: String) {
+        let mut weighted_bits = vec![];
+        for (i, weight) in weights.iter().enumerate() {
+            let bit = match name.strip_prefix('|').and_then(|n| n.strip_suffix('|')) {
+                Some(inner) => format!("|{}-bit-{}|", inner, i),
+                None => format!("{}-bit-{}", name, i),
+            };
+            self.write_var(bit.clone());
+            writeln!(self.sink(), "(assert (= (ff.mul {} {}) {}))", bit, bit, bit).unwrap();
+            weighted_bits.push(format!("(ff.mul {} {})", weight, bit));
+        }
+        let sum = match weighted_bits.len() {
+            0 => zero,
+            1 => weighted_bits.remove(0),
+            _ => format!("(ff.add {})", weighted_bits.join(" ")),
+        };
+        writeln!(self.sink(), "(assert (= {} {}))", name, sum).unwrap();
     }
     /// Writes an assertion in the SMT-LIB file.
     ///
     /// This function writes an assertion in the SMT-LIB file based on the given polynomial, value,
-    /// node type, and operation. The polynomial and value are converted to SMT-LIB syntax.
+    /// node type, and operation. The value is the literal of a field element (see `FieldEncoder`).
     /// If the node type is advice, instance, or fixed, the polynomial is used directly;
     /// otherwise, it is wrapped in parentheses. The operation determines whether the assertion
     /// is an equality or inequality.
@@ -110,23 +189,11 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         nt: analyzer::NodeType,
         op: analyzer::Operation,
     ) {
-        let a = if (matches!(nt, NodeType::Advice)
-            || matches!(nt, NodeType::Instance)
-            || matches!(nt, NodeType::Fixed))
-        {
-            poly
-        } else {
-            format!("({})", poly)
-        };
+        let a = wrap_operand(poly, &nt);
         if matches!(op, analyzer::Operation::Equal) {
-            writeln!(&mut self.writer, "(assert ( = {} (as ff{} F)))", a, value).unwrap();
+            writeln!(self.sink(), "(assert ( = {} {}))", a, value).unwrap();
         } else if matches!(op, analyzer::Operation::NotEqual) {
-            writeln!(
-                &mut self.writer,
-                "(assert (not ( = {} (as ff{} F))))",
-                a, value
-            )
-            .unwrap();
+            writeln!(self.sink(), "(assert (not ( = {} {})))", a, value).unwrap();
         }
     }
     /// Writes a boolean assertion in the SMT-LIB file.
@@ -137,17 +204,22 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     ///
     fn write_assert_bool(&mut self, poly: String, op: analyzer::Operation) {
         if matches!(op, analyzer::Operation::Or) {
-            writeln!(&mut self.writer, "(assert (or {}))", poly).unwrap();
+            writeln!(self.sink(), "(assert (or {}))", poly).unwrap();
         } else if matches!(op, analyzer::Operation::And) {
-            writeln!(&mut self.writer, "(assert (and {}))", poly).unwrap();
+            writeln!(self.sink(), "(assert (and {}))", poly).unwrap();
         }
     }
+    /// Writes an assertion of the negation of a boolean term in the SMT-LIB format.
+    ///
+    fn write_assert_not(&mut self, term: String) {
+        writeln!(self.sink(), "(assert (not {}))", term).unwrap();
+    }
     /// Returns a string representing an assertion in the SMT-LIB format.
     ///
     /// This function returns a string representing an assertion in the SMT-LIB format based on the given
     /// polynomial, value, node type, and operation. The polynomial represents an expression, the value is
-    /// the target value for the assertion, the node type specifies the type of the expression, and the
-    /// operation determines whether it is an equality or inequality assertion.
+    /// the literal of the target value for the assertion, the node type specifies the type of the expression,
+    /// and the operation determines whether it is an equality or inequality assertion.
     ///
     fn get_assert(
         &mut self,
@@ -156,29 +228,50 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         nt: analyzer::NodeType,
         op: analyzer::Operation,
     ) -> Result<String> {
-        let a = if (matches!(nt, NodeType::Advice)
-            || matches!(nt, NodeType::Instance)
-            || matches!(nt, NodeType::Fixed))
-        {
-            poly
-        } else {
-            format!("({})", poly)
-        };
+        let a = wrap_operand(poly, &nt);
         if matches!(op, analyzer::Operation::Equal) {
-            Ok(format!("( = {} (as ff{} F))", a, value))
+            Ok(format!("( = {} {})", a, value))
         } else if matches!(op, analyzer::Operation::NotEqual) {
-            Ok(format!("(not ( = {} (as ff{} F)))", a, value))
+            Ok(format!("(not ( = {} {}))", a, value))
         } else {
             Err(anyhow!("Invalid Operation: {:?}.", op))
         }
     }
+    /// Returns a string representing the equality of two terms in the SMT-LIB format.
+    ///
+    /// Unlike `get_assert`, which compares a term with a constant, both sides are terms wrapped according to their
+    /// node types, e.g. an expression and a parameter of the circuit.
+    ///
+    fn get_equal(
+        &mut self,
+        left: String,
+        left_nt: analyzer::NodeType,
+        right: String,
+        right_nt: analyzer::NodeType,
+    ) -> String {
+        format!(
+            "( = {} {})",
+            wrap_operand(left, &left_nt),
+            wrap_operand(right, &right_nt)
+        )
+    }
+    /// Writes a complete SMT-LIB command, e.g. an assertion saved by an earlier analysis.
+    ///
+    fn write_command(&mut self, command: String) {
+        writeln!(self.sink(), "{}", command).unwrap();
+    }
+    /// Writes a comment line in the SMT-LIB file.
+    ///
+    fn write_comment(&mut self, comment: String) {
+        writeln!(self.sink(), "; {}", comment).unwrap();
+    }
     /// Writes a "get-value" command in the SMT-LIB file to retrieve the value of a variable.
     ///
     /// This function writes a "get-value" command in the SMT-LIB file to retrieve the value of the specified variable.
     /// The variable's value will be included in the model result when solving the SMT problem.
     ///
     pub fn write_get_value(&mut self, var: String) {
-        writeln!(&mut self.writer, "(get-value ({}))", var).unwrap();
+        writeln!(self.sink(), "(get-value ({}))", var).unwrap();
     }
     /// Writes a "push" command in the SMT-LIB file to create a new scope.
     ///
@@ -188,9 +281,9 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     ///
     pub fn write_push(&mut self, number: u8) {
         if number == 1 {
-            writeln!(&mut self.writer, "(push)").unwrap();
+            writeln!(self.sink(), "(push)").unwrap();
         } else {
-            writeln!(&mut self.writer, "(push {})", number).unwrap();
+            writeln!(self.sink(), "(push {})", number).unwrap();
         }
     }
     /// Writes a "pop" command in the SMT-LIB file to remove one or more levels from the current scope.
@@ -201,9 +294,9 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     ///
     pub fn write_pop(&mut self, number: u8) {
         if number == 1 {
-            writeln!(&mut self.writer, "(pop)").unwrap();
+            writeln!(self.sink(), "(pop)").unwrap();
         } else {
-            writeln!(&mut self.writer, "(pop {})", number).unwrap();
+            writeln!(self.sink(), "(pop {})", number).unwrap();
         }
     }
     /// Returns a string representing a logical OR operation in the SMT-LIB format.
@@ -224,12 +317,38 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     }
 }
 
+/// Returns `term` as an operand, wrapping it in parentheses unless its node type is atomic.
+///
+pub fn wrap_operand(term: String, node_type: &NodeType) -> String {
+    if node_type.is_atomic() {
+        term
+    } else {
+        format!("({})", term)
+    }
+}
+
 pub fn write_start<W: Write>(w: &mut W, prime: String) -> Printer<W> {
     let mut p = Printer::new(w);
     p.write_start(prime);
     p
 }
 
+/// Writes the start of the SMT-LIB file like `write_start`, but defers variable declarations.
+///
+/// Instead of declaring each variable when it is first encountered, the printer buffers the following commands
+/// until `write_declarations` emits all the variables as a single block, grouped by region, ahead of them. Some
+/// solvers are faster on such files. The buffered commands are lost if `write_declarations` is never called.
+///
+pub fn write_start_grouped<W: Write>(w: &mut W, prime: String) -> Printer<W> {
+    let mut p = write_start(w, prime);
+    p.deferred = Some(vec![]);
+    p
+}
+
+pub fn write_declarations<W: Write>(p: &mut Printer<W>) {
+    p.write_declarations();
+}
+
 pub fn write_end<W: Write>(p: &mut Printer<W>) {
     p.write_end();
 }
@@ -238,6 +357,14 @@ pub fn write_var(p: &mut Printer<File>, name: String) {
     p.write_var(name);
 }
 
+pub fn write_define(p: &mut Printer<File>, name: String, value: String) {
+    p.write_define(name, value);
+}
+
+pub fn write_range(p: &mut Printer<File>, name: String, weights: &[String], zero: String) {
+    p.write_range(name, weights, zero);
+}
+
 pub fn write_term<W: Write>(
     p: &mut Printer<W>,
     op: String,
@@ -263,6 +390,10 @@ pub fn write_assert_bool(p: &mut Printer<File>, poly: String, op: analyzer::Oper
     p.write_assert_bool(poly, op);
 }
 
+pub fn write_assert_not(p: &mut Printer<File>, term: String) {
+    p.write_assert_not(term);
+}
+
 pub fn get_assert(
     p: &mut Printer<File>,
     poly: String,
@@ -273,6 +404,24 @@ pub fn get_assert(
     p.get_assert(poly, value, nt, op)
 }
 
+pub fn get_equal(
+    p: &mut Printer<File>,
+    left: String,
+    left_nt: analyzer::NodeType,
+    right: String,
+    right_nt: analyzer::NodeType,
+) -> String {
+    p.get_equal(left, left_nt, right, right_nt)
+}
+
+pub fn write_command(p: &mut Printer<File>, command: String) {
+    p.write_command(command);
+}
+
+pub fn write_comment(p: &mut Printer<File>, comment: String) {
+    p.write_comment(comment);
+}
+
 pub fn write_get_value(p: &mut Printer<File>, var: String) {
     p.write_get_value(var);
 }
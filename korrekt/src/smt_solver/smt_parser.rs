@@ -1,12 +1,15 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::str;
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Satisfiability {
     Satisfiable,
     Unsatisfiable,
+    Unknown,
 }
 use anyhow::{anyhow, Context, Result};
+use num::BigInt;
 use regex::Regex;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -24,21 +27,57 @@ pub struct Variable {
 #[derive(Debug, PartialEq, Eq)]
 pub struct ModelResult {
     pub sat: Satisfiability,
-    pub result: HashMap<String, Variable>,
+    pub result: BTreeMap<String, Variable>,
 }
 /// Parses a field element from a string representation.
 ///
-/// This function parses a string representation of a field element and constructs a `FieldElement`
-/// struct containing the element value and its order.
+/// Solvers print field values in different forms depending on their version and options: `#f5m11`, `(as ff5 F)`,
+/// `(_ ff5 11)` or a bare `5`. This function accepts all of them and constructs a `FieldElement` whose element is
+/// a canonical decimal string. When the order is known, the element is reduced to `[0, order)`, so that `#f-6m11`
+/// also yields `5`. Otherwise the order is left empty.
 ///
 fn parse_field_element_from_string(value: &str) -> Result<FieldElement> {
-    // Remove #f and split by "m" to get (field element, field prime).
-    let mut elements = value[2..].split('m');
-    let value_str = elements.next().context("Failed to parse smt result!")?;
-    let order_str = elements.next().context("Failed to parse smt result!")?;
+    let value = value.trim();
+    let (element_str, order_str) = if let Some(literal) = value.strip_prefix("#f") {
+        // #f{element}m{order}
+        let mut elements = literal.split('m');
+        let element_str = elements.next().context("Failed to parse smt result!")?;
+        let order_str = elements.next().context("Failed to parse smt result!")?;
+        (element_str, order_str)
+    } else if let Some(term) = value
+        .strip_prefix("(as ")
+        .and_then(|term| term.strip_suffix(')'))
+    {
+        // (as ff{element} F)
+        let literal = term.split_whitespace().next().unwrap_or_default();
+        let element_str = literal
+            .strip_prefix("ff")
+            .context("Failed to parse smt result!")?;
+        (element_str, "")
+    } else if let Some(term) = value
+        .strip_prefix("(_ ")
+        .and_then(|term| term.strip_suffix(')'))
+    {
+        // (_ ff{element} {order}), the order being optional.
+        let mut parts = term.split_whitespace();
+        let element_str = parts
+            .next()
+            .and_then(|literal| literal.strip_prefix("ff"))
+            .context("Failed to parse smt result!")?;
+        (element_str, parts.next().unwrap_or_default())
+    } else {
+        (value, "")
+    };
+
+    let mut element =
+        BigInt::from_str(element_str).context("Failed to parse field element value!")?;
+    if !order_str.is_empty() {
+        let order = BigInt::from_str(order_str).context("Failed to parse field order!")?;
+        element = ((element % &order) + &order) % &order;
+    }
     Ok(FieldElement {
         order: order_str.to_owned(),
-        element: value_str.to_owned(),
+        element: element.to_string(),
     })
 }
 /// Extracts the model response from the SMT solver output.
@@ -48,10 +87,11 @@ fn parse_field_element_from_string(value: &str) -> Result<FieldElement> {
 pub fn extract_model_response(stream: String) -> Result<ModelResult> {
     let mut lines = stream.split('\n');
     // Initializing values
-    let mut variables: HashMap<String, Variable> = HashMap::new();
+    let mut variables: BTreeMap<String, Variable> = BTreeMap::new();
     let first_line = lines.next().context("Failed to parse smt result!")?;
     if first_line.trim() == "sat" {
-        let re = Regex::new(r"\(\((\S+)\s+(\S+)\)\)").context("Failed to compile regex!")?;
+        let re =
+            Regex::new(r"\(\((\|[^|]*\||\S+)\s+(.+)\)\)").context("Failed to compile regex!")?;
         for line in lines {
             let trimmed_line = line.trim();
             if trimmed_line.is_empty() {
@@ -87,6 +127,11 @@ pub fn extract_model_response(stream: String) -> Result<ModelResult> {
             sat: Satisfiability::Unsatisfiable,
             result: variables,
         })
+    } else if first_line.trim() == "unknown" {
+        Ok(ModelResult {
+            sat: Satisfiability::Unknown,
+            result: variables,
+        })
     } else {
         Err(anyhow!("SMT Solver Error: {}", first_line.trim()))
     }
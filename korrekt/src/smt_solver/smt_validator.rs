@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+
+use anyhow::{Context, Result};
+
+/// A structural problem found in an SMT-LIB script, with the (1-based) line it occurs on.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+#[derive(Debug)]
+enum SExpr {
+    Atom(String, usize),
+    List(Vec<SExpr>, usize),
+}
+
+impl SExpr {
+    fn line(&self) -> usize {
+        match self {
+            SExpr::Atom(_, line) | SExpr::List(_, line) => *line,
+        }
+    }
+}
+
+/// Symbols that do not need to be declared, with the number of operands they take (`None` if any).
+fn builtin(symbol: &str) -> Option<(usize, Option<usize>)> {
+    match symbol {
+        "ff.add" | "ff.mul" | "=" | "distinct" => Some((2, None)),
+        "ff.neg" | "not" => Some((1, Some(1))),
+        "ff.bitsum" | "and" | "or" | "xor" | "=>" => Some((0, None)),
+        "ite" => Some((3, Some(3))),
+        "true" | "false" => Some((0, Some(0))),
+        _ => None,
+    }
+}
+
+/// Returns whether `symbol` is a numeral or a `#f{value}m{order}` finite field literal, e.g. `#f5m11`.
+fn is_literal(symbol: &str) -> bool {
+    let is_numeral = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    match symbol
+        .strip_prefix("#f")
+        .and_then(|literal| literal.split_once('m'))
+    {
+        Some((value, order)) => {
+            is_numeral(value.strip_prefix('-').unwrap_or(value)) && is_numeral(order)
+        }
+        None => is_numeral(symbol),
+    }
+}
+
+/// Splits a script into s-expressions, checking that parentheses, string literals and quoted symbols are balanced.
+fn parse(smt: &str) -> Result<Vec<SExpr>, SyntaxError> {
+    // Each open list keeps its elements and the line of its opening parenthesis.
+    let mut stack: Vec<(Vec<SExpr>, usize)> = vec![(vec![], 0)];
+    for (line_no, line) in smt.lines().enumerate() {
+        let line_no = line_no + 1;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                ';' => break,
+                '(' => stack.push((vec![], line_no)),
+                ')' => {
+                    if stack.len() == 1 {
+                        return Err(SyntaxError {
+                            line: line_no,
+                            message: "unmatched closing parenthesis".to_owned(),
+                        });
+                    }
+                    let (elements, open_line) = stack.pop().unwrap();
+                    stack
+                        .last_mut()
+                        .unwrap()
+                        .0
+                        .push(SExpr::List(elements, open_line));
+                }
+                '"' | '|' => {
+                    // String literals and quoted symbols are kept as a single atom.
+                    let mut literal = String::from(c);
+                    loop {
+                        match chars.next() {
+                            Some(next) if next == c => break,
+                            Some(next) => literal.push(next),
+                            None => {
+                                let kind = if c == '"' {
+                                    "string literal"
+                                } else {
+                                    "quoted symbol"
+                                };
+                                return Err(SyntaxError {
+                                    line: line_no,
+                                    message: format!("unterminated {}", kind),
+                                });
+                            }
+                        }
+                    }
+                    literal.push(c);
+                    stack
+                        .last_mut()
+                        .unwrap()
+                        .0
+                        .push(SExpr::Atom(literal, line_no));
+                }
+                c if c.is_whitespace() => {}
+                c => {
+                    let mut atom = String::from(c);
+                    while let Some(&next) = chars.peek() {
+                        if next.is_whitespace() || next == '(' || next == ')' || next == ';' {
+                            break;
+                        }
+                        atom.push(next);
+                        chars.next();
+                    }
+                    stack.last_mut().unwrap().0.push(SExpr::Atom(atom, line_no));
+                }
+            }
+        }
+    }
+    if stack.len() > 1 {
+        let (_, open_line) = stack.pop().unwrap();
+        return Err(SyntaxError {
+            line: open_line,
+            message: "unclosed parenthesis".to_owned(),
+        });
+    }
+    Ok(stack.pop().unwrap().0)
+}
+
+/// Checks that a term only uses declared symbols and that operators get a valid number of operands.
+fn check_term(term: &SExpr, declared: &HashSet<String>) -> Result<(), SyntaxError> {
+    let error = |line, message: String| Err(SyntaxError { line, message });
+    match term {
+        SExpr::Atom(symbol, line) => {
+            if is_literal(symbol) || declared.contains(symbol) || builtin(symbol).is_some() {
+                Ok(())
+            } else {
+                error(*line, format!("`{}` is used before being declared", symbol))
+            }
+        }
+        SExpr::List(elements, line) => {
+            let (head, operands) = match elements.split_first() {
+                Some(split) => split,
+                None => return error(*line, "empty term".to_owned()),
+            };
+            let head = match head {
+                SExpr::Atom(head, _) => head.as_str(),
+                SExpr::List(..) => {
+                    return error(*line, "term does not start with an operator".to_owned())
+                }
+            };
+            match head {
+                // `(as ff5 F)` and `(_ FiniteField p)` are literals, `let` binds its own names.
+                "as" | "_" | "let" => return Ok(()),
+                _ => {}
+            }
+            match builtin(head) {
+                Some((min, max)) => {
+                    if operands.len() < min || max.map_or(false, |max| operands.len() > max) {
+                        let expected = match max {
+                            Some(max) if max == min => min.to_string(),
+                            _ => format!("at least {}", min),
+                        };
+                        return error(
+                            *line,
+                            format!(
+                                "`{}` expects {} operand(s), found {}",
+                                head,
+                                expected,
+                                operands.len()
+                            ),
+                        );
+                    }
+                }
+                None if declared.contains(head) => {}
+                None => return error(*line, format!("unknown operator `{}`", head)),
+            }
+            for operand in operands {
+                check_term(operand, declared)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Validates the structure of an SMT-LIB script without running a solver.
+///
+/// The validator checks that parentheses and string literals are balanced, that terms are not empty, that the
+/// arithmetic and boolean operators get a valid number of operands and that every symbol is declared (with
+/// `declare-fun`, `define-fun` or `define-sort`) before being used. It returns the first problem found.
+///
+pub fn validate(smt: &str) -> Result<(), SyntaxError> {
+    let mut declared = HashSet::new();
+    for command in parse(smt)? {
+        let elements = match &command {
+            SExpr::List(elements, _) => elements,
+            SExpr::Atom(atom, line) => {
+                return Err(SyntaxError {
+                    line: *line,
+                    message: format!("`{}` is not a command", atom),
+                })
+            }
+        };
+        let name = match elements.first() {
+            Some(SExpr::Atom(name, _)) => name.as_str(),
+            _ => {
+                return Err(SyntaxError {
+                    line: command.line(),
+                    message: "command does not start with a name".to_owned(),
+                })
+            }
+        };
+        match name {
+            "declare-fun" | "declare-const" | "define-sort" | "define-fun" => {
+                if let Some(SExpr::Atom(symbol, _)) = elements.get(1) {
+                    if name == "define-fun" {
+                        if let Some(body) = elements.get(4) {
+                            check_term(body, &declared)?;
+                        }
+                    }
+                    declared.insert(symbol.clone());
+                }
+            }
+            "assert" => match elements.get(1) {
+                Some(term) if elements.len() == 2 => check_term(term, &declared)?,
+                _ => {
+                    return Err(SyntaxError {
+                        line: command.line(),
+                        message: "`assert` expects a single term".to_owned(),
+                    })
+                }
+            },
+            "get-value" => {
+                if let Some(SExpr::List(terms, _)) = elements.get(1) {
+                    for term in terms {
+                        check_term(term, &declared)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Validates the structure of the SMT-LIB file at `smt_file_path`, see `validate`.
+///
+pub fn validate_file(smt_file_path: &str) -> Result<()> {
+    let smt = fs::read_to_string(smt_file_path).context("Failed to read smt file!")?;
+    validate(&smt).with_context(|| format!("Malformed smt file {}", smt_file_path))
+}
@@ -0,0 +1,405 @@
+use crate::io::analyzer_io_type::AnalyzerError;
+use crate::smt_solver::{
+    smt_parser::{self, Satisfiability},
+    smt_validator,
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fmt::Debug,
+    io::{ErrorKind, Read, Write},
+    panic::{self, AssertUnwindSafe},
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How often running solvers check whether they were cancelled.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A backend able to decide an SMT-LIB v2 script.
+///
+/// `solve` receives the complete script (declarations, assertions, `(check-sat)` and `(get-value ...)` commands)
+/// and returns the raw solver output, which is parsed by `smt_parser::extract_model_response`. Implementing this
+/// trait allows plugging in solvers that cannot be spawned as a subprocess, e.g. a WASM build driven by a callback.
+pub trait Solver: Debug {
+    fn solve(&self, smt: &str) -> Result<String>;
+
+    /// Like `solve`, but gives up as soon as `cancel` is set. Solvers that cannot be interrupted simply run to
+    /// completion, which is the default.
+    fn solve_cancellable(&self, smt: &str, cancel: &AtomicBool) -> Result<String> {
+        let _ = cancel;
+        self.solve(smt)
+    }
+}
+
+/// Runs a solver binary as a subprocess and feeds it the script on standard input.
+#[derive(Debug, Clone)]
+pub struct CommandSolver {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl CommandSolver {
+    /// Creates a solver that runs `cvc5` from `PATH`.
+    pub fn cvc5() -> Self {
+        CommandSolver {
+            program: "cvc5".to_owned(),
+            args: vec!["--lang".to_owned(), "smt2".to_owned()],
+        }
+    }
+
+    /// Creates a solver that runs `z3` from `PATH`.
+    pub fn z3() -> Self {
+        CommandSolver {
+            program: "z3".to_owned(),
+            args: vec!["-in".to_owned(), "-smt2".to_owned()],
+        }
+    }
+}
+
+impl CommandSolver {
+    /// Adds an argument to the command line, e.g. `--seed=1` for `cvc5`.
+    pub fn with_arg(mut self, arg: &str) -> Self {
+        self.args.push(arg.to_owned());
+        self
+    }
+
+    /// Returns the first line printed by `program --version`, if any.
+    pub fn version(&self) -> Option<String> {
+        let output = Command::new(&self.program).arg("--version").output().ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .map(|line| line.trim().to_owned())
+    }
+}
+
+/// Checks the output of a solver for errors caused by missing finite field support.
+///
+/// Solver builds without the finite field theory reject the `QF_FF` logic or the `FiniteField` sort, which are
+/// both declared at the top of every script. In that case `AnalyzerError::SolverMissingFeature` is returned, with the version reported by
+/// `solver_version`, which is only queried when the error is detected.
+///
+pub fn check_finite_field_support(
+    output: &str,
+    solver_version: impl FnOnce() -> Option<String>,
+) -> Result<(), AnalyzerError> {
+    let lacks_finite_fields = output.lines().any(|line| {
+        let line = line.to_lowercase();
+        line.contains("error") && (line.contains("qf_ff") || line.contains("finitefield"))
+    });
+    if lacks_finite_fields {
+        return Err(AnalyzerError::SolverMissingFeature {
+            feature: "finite fields",
+            solver_version: solver_version(),
+        });
+    }
+    Ok(())
+}
+
+impl Default for CommandSolver {
+    fn default() -> Self {
+        Self::cvc5()
+    }
+}
+
+impl Solver for CommandSolver {
+    fn solve(&self, smt: &str) -> Result<String> {
+        self.solve_cancellable(smt, &AtomicBool::new(false))
+    }
+
+    fn solve_cancellable(&self, smt: &str, cancel: &AtomicBool) -> Result<String> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to run solver `{}`!", self.program))?;
+        // Read the outputs and write the script on their own threads, so that neither the solver nor we can block
+        // on a full pipe while we poll for cancellation.
+        let mut stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("Failed to open solver stdout!"))?;
+        let reader = thread::spawn(move || {
+            let mut output = String::new();
+            stdout.read_to_string(&mut output).map(|_| output)
+        });
+        let mut stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| anyhow!("Failed to open solver stderr!"))?;
+        let error_reader = thread::spawn(move || {
+            let mut output = String::new();
+            stderr.read_to_string(&mut output).map(|_| output)
+        });
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("Failed to open solver stdin!"))?;
+        let script = smt.to_owned();
+        // Dropping stdin once written closes it, which tells the solver the script is complete.
+        let writer = thread::spawn(move || stdin.write_all(script.as_bytes()));
+        while child
+            .try_wait()
+            .context("Failed to wait for solver!")?
+            .is_none()
+        {
+            if cancel.load(Ordering::SeqCst) {
+                child.kill().context("Failed to kill solver!")?;
+                child.wait().context("Failed to wait for solver!")?;
+                return Err(anyhow!("Solver `{}` was cancelled!", self.program));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        let written = writer
+            .join()
+            .map_err(|_| anyhow!("Failed to write to solver stdin!"))?;
+        let output = reader
+            .join()
+            .map_err(|_| anyhow!("Failed to read solver output!"))?
+            .context("Failed to read solver output!")?;
+        let error_output = error_reader
+            .join()
+            .map_err(|_| anyhow!("Failed to read solver output!"))?
+            .context("Failed to read solver output!")?;
+        check_finite_field_support(&format!("{}{}", output, error_output), || self.version())?;
+        // A solver that gives up early, e.g. on an unknown logic, stops reading the script. Its output tells why.
+        if let Err(error) = written {
+            if error.kind() != ErrorKind::BrokenPipe {
+                return Err(error).context("Failed to write to solver stdin!");
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Runs several solvers in parallel and returns the first conclusive output.
+///
+/// An output is conclusive if it starts with `sat` or `unsat`. Once one is received the other solvers are
+/// cancelled. If no solver is conclusive, the last output (or error) received is returned.
+///
+/// `parallelism` bounds how many solvers run at once, all of them by default. The remaining solvers are started in
+/// order as the running ones give up, so a parallelism of 1 tries the solvers one after the other.
+#[derive(Debug, Clone, Default)]
+pub struct PortfolioSolver {
+    pub solvers: Vec<Arc<dyn Solver + Send + Sync>>,
+    pub parallelism: Option<usize>,
+}
+
+impl PortfolioSolver {
+    /// Creates a portfolio of `cvc5` and `z3`.
+    pub fn new() -> Self {
+        PortfolioSolver::default()
+            .with_solver(CommandSolver::cvc5())
+            .with_solver(CommandSolver::z3())
+    }
+
+    /// Adds a solver to the portfolio.
+    pub fn with_solver(mut self, solver: impl Solver + Send + Sync + 'static) -> Self {
+        self.solvers.push(Arc::new(solver));
+        self
+    }
+
+    /// Runs at most `parallelism` solvers at once, or all of them if `None`.
+    pub fn with_parallelism(mut self, parallelism: Option<usize>) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+}
+
+/// Returns true if the solver output decides satisfiability.
+fn is_conclusive(output: &str) -> bool {
+    matches!(
+        output.lines().next().map(str::trim),
+        Some("sat") | Some("unsat")
+    )
+}
+
+impl Solver for PortfolioSolver {
+    fn solve(&self, smt: &str) -> Result<String> {
+        self.solve_cancellable(smt, &AtomicBool::new(false))
+    }
+
+    fn solve_cancellable(&self, smt: &str, cancel: &AtomicBool) -> Result<String> {
+        let cancel_rest = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel();
+        let mut pending = self.solvers.iter().cloned();
+        let start = |solver: Arc<dyn Solver + Send + Sync>| {
+            let sender = sender.clone();
+            let smt = smt.to_owned();
+            let cancel_rest = cancel_rest.clone();
+            thread::spawn(move || {
+                // A solver that panics gives up, so that the next one gets its turn.
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    solver.solve_cancellable(&smt, &cancel_rest)
+                }))
+                .unwrap_or_else(|_| Err(anyhow!("Solver panicked!")));
+                // The receiver is gone once a result was chosen, losing results are dropped.
+                let _ = sender.send(result);
+            });
+        };
+        let parallelism = self.parallelism.unwrap_or(self.solvers.len()).max(1);
+        let mut running = 0;
+        for solver in pending.by_ref().take(parallelism) {
+            start(solver);
+            running += 1;
+        }
+
+        let mut last = Err(anyhow!("The solver portfolio is empty!"));
+        while running > 0 {
+            if cancel.load(Ordering::SeqCst) {
+                cancel_rest.store(true, Ordering::SeqCst);
+                return Err(anyhow!("Solver portfolio was cancelled!"));
+            }
+            match receiver.recv_timeout(POLL_INTERVAL) {
+                Ok(Ok(output)) if is_conclusive(&output) => {
+                    cancel_rest.store(true, Ordering::SeqCst);
+                    return Ok(output);
+                }
+                Ok(result) => {
+                    last = result;
+                    running -= 1;
+                    if let Some(solver) = pending.next() {
+                        start(solver);
+                        running += 1;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return last,
+            }
+        }
+        last
+    }
+}
+
+/// Cancels and restarts a solver that makes no progress.
+///
+/// Solvers only print their output once done, so a solver that has not answered after `timeout` is considered
+/// stuck. It is then cancelled and the next attempt is started, typically the same solver with another random seed
+/// or other options (see `CommandSolver::with_arg`). Restarts are reported on stderr and counted in `restarts`. If
+/// the last attempt makes no progress either, solving fails.
+#[derive(Debug, Clone)]
+pub struct WatchdogSolver {
+    pub attempts: Vec<Arc<dyn Solver + Send + Sync>>,
+    pub timeout: Duration,
+    pub restarts: Arc<AtomicUsize>,
+}
+
+impl WatchdogSolver {
+    pub fn new(solver: impl Solver + Send + Sync + 'static, timeout: Duration) -> Self {
+        WatchdogSolver {
+            attempts: vec![Arc::new(solver)],
+            timeout,
+            restarts: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Adds a solver to restart with when the previous attempts made no progress.
+    pub fn with_restart(mut self, solver: impl Solver + Send + Sync + 'static) -> Self {
+        self.attempts.push(Arc::new(solver));
+        self
+    }
+
+    /// Returns the number of restarts so far.
+    pub fn restarts(&self) -> usize {
+        self.restarts.load(Ordering::SeqCst)
+    }
+}
+
+impl Solver for WatchdogSolver {
+    fn solve(&self, smt: &str) -> Result<String> {
+        self.solve_cancellable(smt, &AtomicBool::new(false))
+    }
+
+    fn solve_cancellable(&self, smt: &str, cancel: &AtomicBool) -> Result<String> {
+        for (attempt, solver) in self.attempts.iter().cloned().enumerate() {
+            if attempt > 0 {
+                let restarts = self.restarts.fetch_add(1, Ordering::SeqCst) + 1;
+                eprintln!(
+                    "Solver made no progress in {:?}, restarting it (restart {}).",
+                    self.timeout, restarts
+                );
+            }
+            let stop = Arc::new(AtomicBool::new(false));
+            let (sender, receiver) = mpsc::channel();
+            let smt = smt.to_owned();
+            let solver_stop = stop.clone();
+            thread::spawn(move || {
+                // The receiver is gone once the attempt was given up, its result is dropped.
+                let _ = sender.send(solver.solve_cancellable(&smt, &solver_stop));
+            });
+            let started = Instant::now();
+            loop {
+                if cancel.load(Ordering::SeqCst) {
+                    stop.store(true, Ordering::SeqCst);
+                    return Err(anyhow!("Solver watchdog was cancelled!"));
+                }
+                match receiver.recv_timeout(POLL_INTERVAL) {
+                    Ok(result) => return result,
+                    Err(RecvTimeoutError::Timeout) if started.elapsed() < self.timeout => continue,
+                    Err(RecvTimeoutError::Timeout) => {
+                        stop.store(true, Ordering::SeqCst);
+                        break;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        return Err(anyhow!("Solver stopped without output!"))
+                    }
+                }
+            }
+        }
+        Err(anyhow!(
+            "Solver made no progress in {:?} after {} restarts!",
+            self.timeout,
+            self.attempts.len() - 1
+        ))
+    }
+}
+
+/// A live conversation with a solver over an SMT-LIB script.
+///
+/// The session starts from a script (typically the encoding of a circuit) and accumulates the assertions added
+/// with `assert`. Solvers are run once per query, so `check_sat` replays the whole script followed by
+/// `(check-sat)`.
+#[derive(Debug)]
+pub struct SolverSession<'a> {
+    solver: &'a dyn Solver,
+    script: String,
+}
+
+impl<'a> SolverSession<'a> {
+    pub fn new(solver: &'a dyn Solver, script: String) -> Self {
+        SolverSession { solver, script }
+    }
+
+    /// Returns the script sent to the solver by `check_sat`, without the final `(check-sat)`.
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    /// Adds the assertion `(assert term)` to the script. Malformed terms, e.g. with unbalanced parentheses or
+    /// undeclared variables, are rejected and leave the script unchanged.
+    pub fn assert(&mut self, term: &str) -> Result<()> {
+        let script = format!("{}(assert {})\n", self.script, term);
+        smt_validator::validate(&script).context("Malformed assertion!")?;
+        self.script = script;
+        Ok(())
+    }
+
+    /// Decides the assertions of the script.
+    pub fn check_sat(&self) -> Result<Satisfiability> {
+        let output = self
+            .solver
+            .solve(&format!("{}(check-sat)\n", self.script))
+            .context("Failed to run solver!")?;
+        Ok(smt_parser::extract_model_response(output)
+            .context("Failed to parse smt result!")?
+            .sat)
+    }
+}
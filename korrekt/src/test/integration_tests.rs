@@ -1,17 +1,45 @@
 #[cfg(test)]
 mod tests {
-    use crate::circuit_analyzer::analyzer::Analyzer;
+    use crate::circuit_analyzer::abstract_expr;
+    use crate::circuit_analyzer::analyzer::{
+        field_to_decimal, Analyzer, Checkpoint, FixedMatrix, LookupInfo, LookupTableKind, NodeType,
+        SolvedModel,
+    };
+    use crate::circuit_analyzer::field_encoder::FieldEncoder;
+    use crate::circuit_analyzer::name_formatter::{
+        CellKind, CellLocation, CellRef, DefaultNameFormatter, NameFormatter, RegionNameFormatter,
+    };
+    use crate::export::{html, junit, lean, r1cs};
     use crate::io::{
-        analyzer_io_type,
-        analyzer_io_type::{AnalyzerOutputStatus, VerificationInput, VerificationMethod},
+        analyzer_io, analyzer_io_type,
+        analyzer_io_type::{
+            AnalyzerError, AnalyzerOutputStatus, AnalyzerType, Severity, VerificationInput,
+            VerificationMethod,
+        },
+        circuit_registry, repl, rpc,
     };
     use crate::sample_circuits;
-    use halo2_proofs::dev::MockProver;
+    use crate::smt_solver::smt;
+    use crate::smt_solver::smt_parser::{
+        self, FieldElement, ModelResult, Satisfiability, Variable,
+    };
+    use crate::smt_solver::smt_validator::{self, SyntaxError};
+    use crate::smt_solver::solver::{
+        check_finite_field_support, CommandSolver, PortfolioSolver, Solver, WatchdogSolver,
+    };
+    use halo2_proofs::dev::{CellValue, MockProver};
     use halo2_proofs::halo2curves::bn256;
     use halo2_proofs::halo2curves::bn256::Fr;
+    use halo2_proofs::plonk::{Circuit, ConstraintSystem};
+    use halo2_proofs::poly::Rotation;
     use num::{BigInt, Num};
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
     use std::marker::PhantomData;
+    use std::panic::Location;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn create_two_bit_decomp_circuit() {
@@ -63,6 +91,183 @@ mod tests {
         assert!(instance_cols.iter().next().unwrap().1.eq(&0));
     }
 
+    #[test]
+    fn encode_instance_binding_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+        assert!(analyzer.layouter.eq_table["A-0-2-0"].eq("I-0-0"));
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/instance_binding.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        smt::write_end(&mut printer);
+
+        // x is equal to the public input it is bound to.
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt.contains("(declare-fun I-0-0 () F)"));
+        assert!(smt.contains("(assert ( = (ff.add A-0-2-0 (ff.neg I-0-0)) (as ff0 F)))"));
+        assert!(smt_validator::validate(&smt).is_ok());
+    }
+
+    #[test]
+    fn encode_shared_instance_test() {
+        let circuit =
+            sample_circuits::copy_constraint::shared_instance::SharedInstanceCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let instances = vec![vec![Fr::from(1), Fr::from(2)]];
+        let prover = MockProver::<Fr>::run(k, &circuit, instances).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+        // The second region is laid out right after the first one.
+        assert!(analyzer.layouter.regions[1].row_offset.eq(&1));
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/shared_instance.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        smt::write_end(&mut printer);
+
+        // The gate of the second region and the copy of the first one read the same public input.
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt.matches("(declare-fun I-0-1 () F)").count().eq(&1));
+        assert!(smt.contains("A-1-0-0 (ff.neg I-0-1)"));
+        assert!(smt.contains("(ff.add I-0-1 (ff.neg A-0-1-0))"));
+        assert!(!smt.contains("I-1-"));
+        assert!(smt_validator::validate(&smt).is_ok());
+    }
+
+    /// Writes constants as cvc5 finite field literals, e.g. `#f255m{order}`.
+    #[derive(Debug)]
+    struct FiniteFieldLiteralEncoder {
+        order: String,
+    }
+
+    impl FieldEncoder<Fr> for FiniteFieldLiteralEncoder {
+        fn encode(&self, value: &Fr) -> String {
+            format!("#f{}m{}", field_to_decimal(value), self.order)
+        }
+    }
+
+    #[test]
+    fn field_encoder_test() {
+        let circuit = sample_circuits::static_checks::off_by_one_selector::OffByOneSelectorCircuit::<
+            Fr,
+        >::default();
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit).with_field_encoder(FiniteFieldLiteralEncoder {
+            order: prime.clone(),
+        });
+        let a = analyzer.cs.advice_queries[0].0;
+        analyzer.add_gate("byte", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            vec![a - halo2_proofs::plonk::Expression::Constant(Fr::from(255))]
+        });
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/field_encoder.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, prime.clone());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        smt::write_end(&mut printer);
+
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt.contains(&format!("(ff.neg #f255m{})", prime)));
+        assert!(smt.contains(&format!("(define-fun S-0-0-0 () F #f1m{})", prime)));
+        assert!(smt.contains(&format!(" #f0m{}))", prime)));
+        assert!(!smt.contains("(as ff"));
+        assert!(smt_validator::validate(&smt).is_ok());
+
+        // Lookup tables, range checks and the values of models are literals too, which the solver must accept.
+        let circuit =
+            sample_circuits::lookup_circuits::lookup_underconstrained::MyCircuit::<Fr>(PhantomData);
+        let k = 11;
+        let prover = MockProver::<Fr>::run(
+            k,
+            &circuit,
+            vec![vec![Fr::from(1), Fr::from(1), Fr::from(21)]],
+        )
+        .unwrap();
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_field_encoder(FiniteFieldLiteralEncoder {
+                order: prime.clone(),
+            })
+            .with_witness_bound(0, 64)
+            .with_quiet(true);
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let analyzer_input = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 5,
+            },
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+        let smt = std::fs::read_to_string("src/output/out.smt2").unwrap();
+        assert!(smt.contains(&format!("(ff.mul #f2m{} A-", prime)));
+        assert!(!smt.contains("(as ff"));
+        assert!(smt_validator::validate(&smt).is_ok());
+    }
+
+    #[test]
+    fn encode_fixed_copy_test() {
+        let circuit =
+            sample_circuits::copy_constraint::fixed_copy::FixedCopyCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+        let mut analyzer = Analyzer::from(&circuit);
+        assert!(analyzer.layouter.regions[0].advice_eq_table["A-0-0-0"].eq("F-0-0-0"));
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/fixed_copy.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+
+        // The advice cell is pinned to the value of the fixed cell, which is not a variable.
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt.contains("(assert ( = A-0-0-0 (as ff7 F)))"));
+        assert!(!smt.contains("F-0-0-0"));
+        assert!(smt_validator::validate(&smt).is_ok());
+
+        let variables = BTreeSet::from(["A-0-0-0".to_owned(), "A-0-1-0".to_owned()]);
+        let model =
+            Analyzer::<Fr>::solve_existing(smt_file_path, &variables, &CommandSolver::cvc5())
+                .unwrap();
+        assert!(model.sat.eq(&Satisfiability::Satisfiable));
+        assert!(model.result["A-0-0-0"].value.element.eq("7"));
+        assert!(model.result["A-0-1-0"].value.element.eq("5"));
+
+        // No other value of the advice cell is accepted.
+        let negated_file_path = "src/output/fixed_copy_negated.smt2";
+        std::fs::write(
+            negated_file_path,
+            smt + "(assert (not ( = A-0-0-0 (as ff7 F))))\n",
+        )
+        .unwrap();
+        let model =
+            Analyzer::<Fr>::solve_existing(negated_file_path, &variables, &CommandSolver::cvc5())
+                .unwrap();
+        assert!(model.sat.eq(&Satisfiability::Unsatisfiable));
+    }
+
     #[test]
     fn set_user_input_test() {
         let circuit =
@@ -199,7 +404,7 @@ mod tests {
 
         let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
         assert!(instance_cols.len().eq(&1));
-        let mut specified_instance_cols = HashMap::new();
+        let mut specified_instance_cols = BTreeMap::new();
         for var in instance_cols.iter() {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
@@ -239,7 +444,7 @@ mod tests {
 
         let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
         assert!(instance_cols.len().eq(&1));
-        let mut specified_instance_cols = HashMap::new();
+        let mut specified_instance_cols = BTreeMap::new();
         for var in instance_cols.iter() {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
@@ -353,7 +558,7 @@ mod tests {
         let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
 
         assert!(instance_cols.len().eq(&1));
-        let mut specified_instance_cols = HashMap::new();
+        let mut specified_instance_cols = BTreeMap::new();
         for var in instance_cols.iter() {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
@@ -395,7 +600,7 @@ mod tests {
         let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
 
         assert!(instance_cols.len().eq(&1));
-        let mut specified_instance_cols = HashMap::new();
+        let mut specified_instance_cols = BTreeMap::new();
         for var in instance_cols.iter() {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
@@ -426,6 +631,82 @@ mod tests {
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
     }
 
+    #[test]
+    fn format_model_test() {
+        let mut result = BTreeMap::new();
+        for (name, element) in [
+            ("A-1-0-0", "7"),
+            ("A-0-1-0", "2"),
+            ("A-0-0-1", "3"),
+            ("A-0-0-0", "1"),
+            ("I-0-0", "5"),
+        ] {
+            result.insert(
+                name.to_owned(),
+                Variable {
+                    name: name.to_owned(),
+                    value: FieldElement {
+                        order: "11".to_owned(),
+                        element: element.to_owned(),
+                    },
+                },
+            );
+        }
+        let model = ModelResult {
+            sat: Satisfiability::Satisfiable,
+            result,
+        };
+        assert_eq!(
+            analyzer_io::format_model(&model),
+            "region 0:\n  A-0-0-0 (column 0, row 0) : 1\n  A-0-0-1 (column 0, row 1) : 3\n  A-0-1-0 (column 1, row 0) : 2\nregion 1:\n  A-1-0-0 (column 0, row 0) : 7\nother:\n  I-0-0 : 5\n"
+        );
+    }
+
+    #[test]
+    fn format_model_with_limit_test() {
+        let mut result = BTreeMap::new();
+        for (name, element) in [
+            ("A-1-0-0", "7"),
+            ("A-0-1-0", "2"),
+            ("A-0-0-1", "3"),
+            ("A-0-0-0", "123456789"),
+            ("I-0-0", "5"),
+        ] {
+            result.insert(
+                name.to_owned(),
+                Variable {
+                    name: name.to_owned(),
+                    value: FieldElement {
+                        order: "11".to_owned(),
+                        element: element.to_owned(),
+                    },
+                },
+            );
+        }
+        let model = ModelResult {
+            sat: Satisfiability::Satisfiable,
+            result,
+        };
+        let limit = analyzer_io::ModelPrintLimit {
+            max_assignments: 3,
+            max_value_width: 4,
+        };
+        assert_eq!(
+            analyzer_io::format_model_with_limit(&model, Some(&limit)),
+            "region 0:\n  A-0-0-0 (column 0, row 0) : 1234...\n  A-0-0-1 (column 0, row 1) : 3\n  A-0-1-0 (column 1, row 0) : 2\n... and 2 more\n"
+        );
+
+        // Models within the limit are printed in full.
+        let limit = analyzer_io::ModelPrintLimit {
+            max_assignments: 5,
+            max_value_width: 9,
+        };
+        assert_eq!(
+            analyzer_io::format_model_with_limit(&model, Some(&limit)),
+            analyzer_io::format_model(&model)
+        );
+    }
+
     #[test]
     fn analyze_unused_columns_test() {
         let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
@@ -449,6 +730,160 @@ mod tests {
         assert!(analyzer.log().len().gt(&0))
     }
 
+    #[test]
+    fn analyze_unused_custom_gates_after_add_gate_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_unused_custom_gates().unwrap();
+        assert!(analyzer.log().len().eq(&1));
+
+        let selector = analyzer.cs.selector();
+        analyzer.add_gate("added", |meta| vec![meta.query_selector(selector)]);
+        assert!(analyzer.log().is_empty());
+
+        analyzer.analyze_unused_custom_gates().unwrap();
+        assert!(analyzer.log().len().eq(&2));
+        assert!(analyzer.log()[1].contains("\"added\""));
+    }
+
+    #[test]
+    fn findings_test() {
+        let circuit = sample_circuits::static_checks::inert_gate::InertGateCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_inert_gates(&prover.fixed).unwrap();
+        analyzer
+            .analyze_boolean_fixed_columns(&prover.fixed)
+            .unwrap();
+
+        let findings = analyzer.findings();
+        assert!(findings.len().eq(&1));
+        assert!(findings[0].pass.eq(&AnalyzerType::InertGates));
+        assert!(findings[0].message.starts_with("inert gate: \"equal\""));
+        assert!(findings[0].to_string().eq(&findings[0].message));
+    }
+
+    #[test]
+    fn no_gates_warning_test() {
+        let circuit = sample_circuits::static_checks::gateless::GatelessCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer
+            .dispatch_analysis(AnalyzerType::UnusedColumns, vec![], "")
+            .unwrap();
+        analyzer
+            .dispatch_analysis(AnalyzerType::UnconstrainedCells, vec![], "")
+            .unwrap();
+
+        // The warning comes first, and only once.
+        let findings = analyzer.findings();
+        assert!(findings[0].pass.eq(&AnalyzerType::UnusedColumns));
+        assert!(findings[0]
+            .message
+            .starts_with("no gates: the circuit defines no gates"));
+        assert!(findings
+            .iter()
+            .filter(|finding| finding.starts_with("no gates:"))
+            .count()
+            .eq(&1));
+
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer
+            .dispatch_analysis(AnalyzerType::UnusedColumns, vec![], "")
+            .unwrap();
+        assert!(!analyzer
+            .findings()
+            .iter()
+            .any(|finding| finding.starts_with("no gates:")));
+    }
+
+    /// A writer shared with the test, standing in for stderr.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn ndjson_findings_test() {
+        let circuit = sample_circuits::static_checks::gateless::GatelessCircuit::<Fr>::default();
+        let stream = SharedBuffer::default();
+        let mut analyzer = Analyzer::from(&circuit).with_ndjson_findings(stream.clone());
+        analyzer
+            .dispatch_analysis(AnalyzerType::UnusedColumns, vec![], "")
+            .unwrap();
+        // Findings are streamed as they are logged, before the next pass runs.
+        let streamed = stream.0.lock().unwrap().len();
+        assert!(streamed > 0);
+        analyzer.analyze_naming_collisions().unwrap();
+
+        let output = String::from_utf8(stream.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines.len().eq(&analyzer.findings().len()));
+        for (line, finding) in lines.iter().zip(analyzer.findings()) {
+            let parsed = analyzer_io_type::Finding::from_json(line).unwrap();
+            assert!(parsed.eq(finding));
+        }
+        assert!(analyzer_io_type::Finding::from_json("not json").is_err());
+        assert!(
+            analyzer_io_type::Finding::from_json(r#"{"pass": "Nope", "message": ""}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn analyze_unused_custom_gates_with_location_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let location = Location::caller();
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_gate_locations(HashMap::from([("add".to_owned(), location)]));
+        analyzer.analyze_unused_custom_gates().unwrap();
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains(&format!("defined at {}:{}", file!(), location.line())));
+    }
+
+    #[test]
+    fn disabled_gates_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+
+        let encode = |analyzer: &mut Analyzer<Fr>| {
+            let smt_file_path = "src/output/disabled_gates.smt2";
+            let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+            let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+            analyzer
+                .encode_circuit(&mut printer, prover.fixed.clone())
+                .unwrap();
+            std::fs::read_to_string(smt_file_path)
+                .unwrap()
+                .matches("(assert")
+                .count()
+        };
+
+        // Two regions of two rows, each row encodes the polynomial of both gates.
+        let mut analyzer = Analyzer::from(&circuit);
+        assert!(encode(&mut analyzer).eq(&8));
+
+        let mut analyzer =
+            Analyzer::from(&circuit).with_disabled_gates(HashSet::from(["add".to_owned()]));
+        assert!(encode(&mut analyzer).eq(&4));
+        analyzer.analyze_unused_custom_gates().unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
     #[test]
     fn analyze_unconstrained_cells() {
         let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
@@ -463,132 +898,2428 @@ mod tests {
     }
 
     #[test]
-    fn analyze_underconstrained_fibonacci_test() {
-        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
-            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+    fn analyze_unassigned_cells_test() {
+        let circuit =
+            sample_circuits::static_checks::unassigned_cell::UnassignedCellCircuit::<Fr>::default();
         let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer.analyze_unassigned_cells().unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::UnassignedCells));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("index: 1"));
+        assert!(analyzer.log()[0].contains("\"sum\""));
+    }
 
-        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
-        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
-            verification_method: VerificationMethod::Random,
-            verification_input: VerificationInput {
-                instances_string: instance_cols,
-                iterations: 5,
-            },
-        };
-        let k: u32 = 11;
+    #[test]
+    fn analyze_always_on_selectors_test() {
+        let circuit = sample_circuits::static_checks::always_on_selector::AlwaysOnSelectorCircuit::<
+            Fr,
+        >::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().eq(&Ok(())));
 
-        let public_input = vec![Fr::from(3)];
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_always_on_selectors()
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::AlwaysOnSelectors));
+        // Only s_double, s_bool is off on the second row.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("Selector(0,"));
+    }
 
-        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+    #[test]
+    fn analyze_double_assigned_cells_test() {
+        let circuit = sample_circuits::static_checks::double_assigned_cell::DoubleAssignedCellCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        assert!(analyzer.layouter.regions[0].assignments.len().eq(&3));
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .analyze_double_assigned_cells()
             .unwrap()
             .output_status;
-        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+        assert!(output_status.eq(&AnalyzerOutputStatus::DoubleAssignedCells));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("index: 0"));
+        assert!(analyzer.log()[0].contains("assigned 2 times"));
     }
 
     #[test]
-    fn analyze_underconstrained_single_lookup_test() {
+    fn lookup_summary_test() {
         let circuit =
-            sample_circuits::lookup_circuits::lookup_underconstrained::MyCircuit::<Fr>(PhantomData);
+            sample_circuits::lookup_circuits::square_lookup::SquareLookupCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let analyzer = Analyzer::from(&circuit);
+        let summary = analyzer.lookup_summary();
+        assert!(summary.len().eq(&1));
+        let LookupInfo {
+            index,
+            input_count,
+            table_columns,
+            table_kind,
+            selector,
+        } = &summary[0];
+        assert!(index.eq(&0));
+        assert!(input_count.eq(&2));
+        assert!(table_columns.eq(&vec![0, 1]));
+        assert!(table_kind.eq(&LookupTableKind::Fixed));
+        assert!(selector.map(|selector| selector.0).eq(&Some(0)));
+    }
+
+    #[test]
+    fn symbolic_fixed_test() {
+        let circuit =
+            sample_circuits::lookup_circuits::square_lookup::SquareLookupCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
         let mut analyzer = Analyzer::from(&circuit);
+        // The square of 3 in the table becomes a parameter.
+        let fixed = FixedMatrix::from(prover.fixed).with_symbolic(1, 3, "square_3");
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/symbolic_fixed.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, fixed).unwrap();
+        smt::write_end(&mut printer);
+
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(printer.vars.contains_key("square_3"));
+        assert!(smt.contains("(declare-fun square_3 () F)"));
+        assert!(smt.contains("( = (ff.mul S-0-0-0 A-0-1-0) square_3)"));
+        assert!(!smt.contains("(as ff9 F)"));
+        assert!(smt_validator::validate(&smt).is_ok());
+    }
 
-        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+    #[test]
+    fn symbolic_fixed_gate_test() {
+        let circuit =
+            sample_circuits::copy_constraint::fixed_scale::FixedScaleCircuit::<Fr>::default();
         let modulus = bn256::fr::MODULUS_STR;
         let without_prefix = modulus.trim_start_matches("0x");
         let prime = BigInt::from_str_radix(without_prefix, 16)
             .unwrap()
             .to_string();
-
-        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(2)]]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        // Known fixed cells are constants.
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/symbolic_fixed_gate.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, prime.clone());
+        Analyzer::from(&circuit)
+            .encode_circuit(&mut printer, prover.fixed.clone())
+            .unwrap();
+        smt::write_end(&mut printer);
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt.contains("(ff.mul (as ff3 F) A-0-0-0)"));
+        assert!(!smt.contains("F-0-0-0"));
+
+        // The scale becomes a parameter.
+        let fixed = FixedMatrix::from(prover.fixed).with_symbolic(0, 0, "scale");
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, prime.clone());
+        Analyzer::from(&circuit)
+            .encode_circuit(&mut printer, fixed.clone())
+            .unwrap();
+        smt::write_end(&mut printer);
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt.contains("(declare-fun scale () F)"));
+        assert!(smt.contains("(ff.mul scale A-0-0-0)"));
+        assert!(smt_validator::validate(&smt).is_ok());
+
+        // `b` is determined once the scale is chosen, so a second witness cannot pick another scale.
+        let mut analyzer = Analyzer::from(&circuit).with_quiet(true);
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let analyzer_input = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
                 instances_string: instance_cols,
-                iterations: 5,
+                iterations: 2,
             },
         };
-        let k = 11;
-
-        let a = Fr::from(1);
-        let b = Fr::from(1);
-        let out = Fr::from(21);
-
-        let public_input = vec![a, b, out];
-        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .analyze_underconstrained(analyzer_input, fixed.clone(), &prime)
             .unwrap()
             .output_status;
-        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+
+        // Static passes need known values.
+        let error = fixed.to_concrete().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Fixed column 0 holds the parameter \"scale\""));
     }
 
     #[test]
-    fn analyze_underconstrained_multiple_lookup_test() {
+    fn wide_fixed_value_test() {
         let circuit =
-            sample_circuits::lookup_circuits::multiple_lookups::MyCircuit::<Fr>(PhantomData);
-        let mut analyzer = Analyzer::from(&circuit);
-
-        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            sample_circuits::copy_constraint::fixed_scale::FixedScaleCircuit::<Fr>::default();
         let modulus = bn256::fr::MODULUS_STR;
         let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
+        let prime = BigInt::from_str_radix(without_prefix, 16).unwrap();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(2)]]).unwrap();
+
+        // -1 does not fit in 128 bits, its literal must be the full p - 1.
+        let mut fixed = prover.fixed.clone();
+        fixed[0][0] = CellValue::Assigned(-Fr::one());
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/wide_fixed_value.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, prime.to_string());
+        Analyzer::from(&circuit)
+            .encode_circuit(&mut printer, fixed)
+            .unwrap();
+        smt::write_end(&mut printer);
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        let minus_one = prime - BigInt::from(1);
+        assert!(smt.contains(&format!("(ff.mul (as ff{} F) A-0-0-0)", minus_one)));
+    }
+
+    #[test]
+    fn analyze_non_constraining_lookups_test() {
+        let circuit = sample_circuits::static_checks::byte_range_lookup::ByteRangeLookupCircuit::<Fr>::default();
+        let k = 9;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        // `a` is known to be a byte, so the byte table covers its whole domain.
+        let mut analyzer = Analyzer::from(&circuit).with_witness_bound(0, 8);
+        let output_status = analyzer
+            .analyze_non_constraining_lookups(&prover.fixed)
             .unwrap()
-            .to_string();
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NonConstrainingLookups));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("8-bit range"));
+
+        // A wider domain is constrained by the lookup.
+        let mut analyzer = Analyzer::from(&circuit).with_witness_bound(0, 9);
+        analyzer
+            .analyze_non_constraining_lookups(&prover.fixed)
+            .unwrap();
+        assert!(analyzer.log().is_empty());
+    }
 
-        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
-            verification_method: VerificationMethod::Random,
-            verification_input: VerificationInput {
-                instances_string: instance_cols,
-                iterations: 5,
-            },
-        };
+    #[test]
+    fn analyze_non_constraining_lookups_without_bounds_test() {
+        let circuit = sample_circuits::static_checks::byte_range_lookup::ByteRangeLookupCircuit::<Fr>::default();
+        let k = 9;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
 
-        let k = 11;
+        // Without a bound `a` may be any field element, which the byte table does not cover.
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer
+            .analyze_non_constraining_lookups(&prover.fixed)
+            .unwrap();
+        assert!(analyzer.log().is_empty());
+        // Neither does a bound on another column.
+        let mut analyzer = Analyzer::from(&circuit).with_witness_bound(1, 8);
+        analyzer
+            .analyze_non_constraining_lookups(&prover.fixed)
+            .unwrap();
+        assert!(analyzer.log().is_empty());
+    }
 
-        let a = Fr::from(1);
-        let b = Fr::from(1);
-        let out = Fr::from(21);
+    #[test]
+    fn analyze_noop_gates_test() {
+        let circuit = sample_circuits::static_checks::noop_gate::NoopGateCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer.analyze_noop_gates().unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NoopGates));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"noop\""));
+        assert!(analyzer.log()[0].contains("[0]"));
+    }
 
-        let public_input = vec![a, b, out];
-        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+    #[test]
+    fn analyze_high_degree_gates_test() {
+        let circuit =
+            sample_circuits::static_checks::high_degree_gate::HighDegreeGateCircuit::<Fr>::default(
+            );
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer.analyze_high_degree_gates(3).unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::HighDegreeGates));
+        // `s*(a*b*c-d)` has degree 4, computing `a*b` in a helper column leaves two gates of degree 3.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"product\""));
+        assert!(analyzer.log()[0].contains("has degree 4 (target 3)"));
+        assert!(analyzer.log()[0]
+            .contains("computing (adv 0 row * adv 1 row) in a helper advice column"));
+        assert!(analyzer.log()[0].contains("gates of degree 3 and 3"));
+
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_high_degree_gates(4).unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
+    #[test]
+    fn analyze_off_by_one_selectors_test() {
+        let circuit = sample_circuits::static_checks::off_by_one_selector::OffByOneSelectorCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .analyze_off_by_one_selectors()
             .unwrap()
             .output_status;
-        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+        assert!(output_status.eq(&AnalyzerOutputStatus::OffByOneSelectors));
+        // The gate reads the previous row of `a`, so the selector enabled on rows 0 and 1 belongs on rows 1 and 2.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("enabled on rows [0, 1] of region \"increment region\""));
+        assert!(analyzer.log()[0].contains("assigned on rows [1, 2] (shift by +1)"));
     }
+
     #[test]
-    fn analyze_not_underconstrained_lookup_test() {
+    fn analyze_unranged_lookup_inputs_test() {
+        let circuit = sample_circuits::static_checks::byte_range_lookup::ByteRangeLookupCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_unranged_lookup_inputs()
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::UnrangedLookupInputs));
+        // Nothing but the lookup constrains `a`.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("lookup 0 reads advice column 0"));
+
+        let a = analyzer.cs.advice_queries[0].0;
+        let selector = analyzer.cs.selector();
+        analyzer.add_gate("bool", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let s = meta.query_selector(selector);
+            vec![s * a.clone() * (halo2_proofs::plonk::Expression::Constant(Fr::from(1)) - a)]
+        });
+        analyzer.analyze_unranged_lookup_inputs().unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
+    #[test]
+    fn analyze_uniform_columns_test() {
         let circuit =
-            sample_circuits::lookup_circuits::multiple_lookups::MyCircuit::<Fr>(PhantomData);
+            sample_circuits::static_checks::uniform_column::UniformColumnCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
         let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer.analyze_uniform_columns().unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::UniformColumns));
+        // `a` is boolean in both regions, `b` is only doubled in the first one and `c` is assigned once.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains(
+            "advice column 0 is constrained by the same gates {\"bool\"} in all the 2 regions"
+        ));
+    }
 
-        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
-        assert!(instance_cols.len().eq(&1));
-        let mut specified_instance_cols = HashMap::new();
-        specified_instance_cols.insert("A-0-2-7".to_owned(), 21);
-        specified_instance_cols.insert("I-0-0-1".to_owned(), 1);
-        specified_instance_cols.insert("I-0-0-0".to_owned(), 1);
+    #[test]
+    fn analyze_mutually_exclusive_selectors_test() {
+        let circuit =
+            sample_circuits::static_checks::exclusive_selectors::ExclusiveSelectorsCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_mutually_exclusive_selectors()
             .unwrap()
-            .to_string();
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::MutuallyExclusiveSelectors));
+        // Only "both" enables `s_add` and `s_mul` together.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0]
+            .contains("region \"both\" enables selectors {0, 1} together on rows [0]"));
+        assert!(analyzer.log()[0].contains("gate \"exclusive\""));
+    }
 
-        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
-            verification_method: VerificationMethod::Specific,
-            verification_input: VerificationInput {
-                instances_string: specified_instance_cols,
-                iterations: 1,
+    #[test]
+    fn analyze_sparse_regions_test() {
+        let circuit =
+            sample_circuits::static_checks::uniform_column::UniformColumnCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        // `b` is not doubled in "second", where nothing else constrains it.
+        let density = analyzer.constraint_density();
+        assert!(density.len().eq(&2));
+        assert!(density[0].0.eq("first") && (density[0].1 - 1.0).abs() < 1e-9);
+        assert!(density[1].0.eq("second") && (density[1].1 - 0.5).abs() < 1e-9);
+
+        let output_status = analyzer.analyze_sparse_regions(0.5).unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SparseRegions));
+        assert!(analyzer.log().is_empty());
+        analyzer.analyze_sparse_regions(0.75).unwrap();
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0]
+            .contains("only 1 of the 2 advice cells assigned in region \"second\""));
+    }
+
+    #[test]
+    fn analyze_dead_instances_test() {
+        let circuit =
+            sample_circuits::static_checks::dead_instance::DeadInstanceCircuit::<Fr>::default();
+        let k = 5;
+        let prover =
+            MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(1)], vec![Fr::from(2)]]).unwrap();
+        // Neither public input has any bearing on the witness.
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_dead_instances(&prover.fixed)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::DeadInstances));
+        assert!(analyzer.log().len().eq(&2));
+        assert!(analyzer.log()[0].contains("instance column 0 is only read by gates {\"literal\"}"));
+        assert!(analyzer.log()[1].contains("instance column 1 is only read by gates {\"fixed\"}"));
+
+        // A public input read with a non-zero coefficient is live.
+        let circuit =
+            sample_circuits::static_checks::instance_gate::InstanceGateCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_dead_instances(&[]).unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
+    #[test]
+    fn analyze_orphaned_selectors_test() {
+        let circuit = sample_circuits::static_checks::orphaned_selector::OrphanedSelectorCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer.analyze_orphaned_selectors().unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::OrphanedSelectors));
+        // `s` is only enabled in "other", which assigns none of `a` and `b`.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("gate \"double\" uses Selector(0)"));
+        assert!(analyzer.log()[0]
+            .contains("regions [\"other\"] that assign none of its advice columns [0, 1]"));
+
+        let circuit =
+            sample_circuits::static_checks::uniform_column::UniformColumnCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_orphaned_selectors().unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
+    #[test]
+    fn analyze_selector_bleed_test() {
+        let circuit =
+            sample_circuits::static_checks::selector_bleed::SelectorBleedCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer.analyze_selector_bleed().unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SelectorBleed));
+        // `s` is meant for "main" but also enabled in "other", which assigns neither `a` nor `b`.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(
+            analyzer.log()[0].contains("region \"other\" enables Selector(0) of gate \"double\"")
+        );
+        assert!(analyzer.log()[0].contains("does not assign its advice columns {0, 1}"));
+        // The gate still constrains "main", so it is not orphaned.
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_orphaned_selectors().unwrap();
+        assert!(analyzer.log().is_empty());
+
+        // Selectors enabled nowhere near their columns are left to the orphaned selectors pass.
+        let circuit = sample_circuits::static_checks::orphaned_selector::OrphanedSelectorCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_selector_bleed().unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
+    #[test]
+    fn analyze_constant_drift_test() {
+        let circuit =
+            sample_circuits::static_checks::drifted_constant::DriftedConstantCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_constant_drift(&prover.fixed)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::ConstantDrift));
+        // `complement` shares `a` with the nibble lookup and uses 16, the table stops at 15.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("gate \"complement\""));
+        assert!(analyzer.log()[0].contains("uses the constant 16"));
+        assert!(analyzer.log()[0].contains("although it contains 15"));
+    }
+
+    #[test]
+    fn analyze_constant_propagation_test() {
+        let circuit =
+            sample_circuits::static_checks::pinned_cell::PinnedCellCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_constant_propagation()
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::ConstantPropagation));
+        // `pin` forces `a` to 5 on row 0 only, the row 1 read of `square` is not pinned.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("gate \"square\""));
+        assert!(analyzer.log()[0]
+            .contains("reads advice column 0 on rows {0} of region \"square region\""));
+        assert!(analyzer.log()[0].contains("pinned to 5 by gate \"pin\""));
+    }
+
+    #[test]
+    fn pinned_advice_zero_factor_test() {
+        let circuit =
+            sample_circuits::static_checks::pinned_cell::PinnedCellCircuit::<Fr>::default();
+        let analyzer = Analyzer::from(&circuit);
+        let pin = analyzer.cs.gates[0].polynomials()[0].clone();
+        assert!(abstract_expr::pinned_advice(&pin).eq(&Some((0, 0, Fr::from(5)))));
+
+        // `0 * s * (a - 5)` holds for any `a`.
+        let zeroed = halo2_proofs::plonk::Expression::Constant(Fr::zero()) * pin.clone();
+        assert!(abstract_expr::pinned_advice(&zeroed).is_none());
+        assert!(abstract_expr::pinned_advice(&(pin * Fr::zero())).is_none());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_export_test() {
+        use crate::export::sqlite;
+
+        let circuit = sample_circuits::static_checks::off_by_one_selector::OffByOneSelectorCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_off_by_one_selectors().unwrap();
+        let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+        let first = sqlite::insert_run(&mut connection, "circuit", 1, &analyzer.log).unwrap();
+        let second = sqlite::insert_run(&mut connection, "circuit", 2, &[]).unwrap();
+
+        let findings = sqlite::run_findings(&connection, first).unwrap();
+        assert!(findings.eq(&analyzer.log.to_vec()));
+        assert!(sqlite::run_findings(&connection, second)
+            .unwrap()
+            .is_empty());
+        // The trend of a circuit: its finding count per run, oldest first.
+        let mut statement = connection
+            .prepare(
+                "SELECT runs.timestamp, COUNT(findings.run_id) FROM runs \
+                 LEFT JOIN findings ON findings.run_id = runs.id \
+                 WHERE runs.circuit_hash = ?1 GROUP BY runs.id ORDER BY runs.timestamp",
+            )
+            .unwrap();
+        let trend: Vec<(i64, i64)> = statement
+            .query_map(["circuit"], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+        assert!(trend.eq(&vec![(1, 1), (2, 0)]));
+    }
+
+    #[test]
+    fn junit_report_test() {
+        let circuit = sample_circuits::static_checks::off_by_one_selector::OffByOneSelectorCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let mut results = vec![];
+        for pass in [
+            AnalyzerType::OffByOneSelectors,
+            AnalyzerType::NoopGates,
+            AnalyzerType::UnusedColumns,
+        ] {
+            results.push((pass, analyzer.dispatch_analysis(pass, vec![], "").unwrap()));
+        }
+        analyzer.log.push(analyzer_io_type::Finding::new(
+            AnalyzerType::NoopGates,
+            "no-op gate: \"a<b\" has polynomial(s) [0] that are identically zero.".to_owned(),
+        ));
+
+        let xml = junit::render(&results, &analyzer.log, false);
+        assert!(xml.matches("<testcase ").count().eq(&3));
+        assert!(xml.contains("tests=\"3\" failures=\"1\""));
+        assert!(xml.contains(
+            "<testcase classname=\"halo2-analyzer\" name=\"OffByOneSelectors\">\n      <failure"
+        ));
+        // Warnings do not fail a pass, unless strict.
+        assert!(xml.contains("<system-out>no-op gate: &quot;a&lt;b&quot;"));
+        let xml = junit::render(&results, &analyzer.log, true);
+        assert!(xml.contains("tests=\"3\" failures=\"2\""));
+        assert!(xml.contains(
+            "<testcase classname=\"halo2-analyzer\" name=\"UnusedColumns\">\n    </testcase>"
+        ));
+    }
+
+    #[test]
+    fn html_report_test() {
+        let circuit = sample_circuits::static_checks::off_by_one_selector::OffByOneSelectorCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_off_by_one_selectors().unwrap();
+        let mut findings = analyzer.log.to_vec();
+        findings.push(analyzer_io_type::Finding::new(
+            AnalyzerType::NoopGates,
+            "no-op gate: \"a<b\" has polynomial(s) [0] that are identically zero.".to_owned(),
+        ));
+
+        let html = html::render(&findings, Some("<svg></svg>")).unwrap();
+        // A section per pass, errors before warnings.
+        assert!(html.contains("<section id=\"OffByOneSelectors\">"));
+        assert!(html.contains("<section id=\"NoopGates\">"));
+        assert!(html.find("<h2>Errors</h2>").unwrap() < html.find("<h2>Warnings</h2>").unwrap());
+        assert!(html.contains("<h4>Region &quot;increment region&quot;</h4>"));
+        assert!(html.contains("&quot;a&lt;b&quot;"));
+        assert!(html.contains("<svg></svg>"));
+    }
+
+    #[test]
+    fn lookup_encoding_limit_test() {
+        let circuit = sample_circuits::static_checks::byte_range_lookup::ByteRangeLookupCircuit::<Fr>::default();
+        let k = 9;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit).with_lookup_encoding_limit(100);
+        // One region row against the 256 rows of the byte table.
+        assert!(analyzer
+            .lookup_encoding_size(&FixedMatrix::from(prover.fixed.clone()))
+            .eq(&256));
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let mut smt_file = std::fs::File::create("src/output/lookup_encoding_limit.smt2").unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        let error = analyzer
+            .encode_circuit(&mut printer, prover.fixed)
+            .unwrap_err();
+        assert!(error.to_string().contains("above the limit of 100"));
+        assert!(printer.vars.is_empty());
+    }
+
+    #[test]
+    fn analyze_inert_gates_test() {
+        let circuit = sample_circuits::static_checks::inert_gate::InertGateCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_inert_gates(&prover.fixed)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::InertGates));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"equal\""));
+        assert!(analyzer.log()[0].contains("{0}"));
+    }
+
+    #[test]
+    fn analyze_fixed_trivial_gates_test() {
+        let circuit = sample_circuits::static_checks::inert_gate::InertGateCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_fixed_trivial_gates(&prover.fixed)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::FixedTrivialGates));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"equal\""));
+
+        // Once `f` is set on a row, the gate constrains that row.
+        let mut fixed = prover.fixed.clone();
+        fixed[0][3] = CellValue::Assigned(Fr::one());
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_fixed_trivial_gates(&fixed).unwrap();
+        assert!(analyzer.log().is_empty());
+    }
+
+    #[test]
+    fn analyze_boolean_fixed_columns_test() {
+        let circuit = sample_circuits::static_checks::boolean_fixed_column::BooleanFixedColumnCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_boolean_fixed_columns(&prover.fixed)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::BooleanFixedColumns));
+        // `q` is reported, but not the multi-valued `c`.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("fixed column 0 "));
+        assert!(analyzer.log()[0].contains("1 on 3 row(s)"));
+    }
+
+    #[test]
+    fn analyze_always_violated_gates_test() {
+        let circuit: sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit<Fr> =
+            sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_always_violated_gates()
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::AlwaysViolatedGates));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"always_violated\""));
+
+        // Selectors are evaluated row by row, and constants are folded.
+        let circuit = sample_circuits::static_checks::disjoint_selectors::DisjointSelectorsCircuit::<
+            Fr,
+        >::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_always_violated_gates().unwrap();
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"folded\""));
+        assert!(analyzer.log()[0].contains("region at row 1"));
+    }
+
+    #[test]
+    fn circuit_registry_test() {
+        let mut registry = circuit_registry::CircuitRegistry::<Fr>::new();
+        registry.register_circuit("inert_gate", |params| {
+            let circuit =
+                sample_circuits::static_checks::inert_gate::InertGateCircuit::<Fr>::default();
+            let k = circuit_registry::param_or(params, "k", 5)?;
+            circuit_registry::RegisteredCircuit::mock(&circuit, k, vec![])
+        });
+        registry.register_circuit("noop_gate", |params| {
+            let circuit =
+                sample_circuits::static_checks::noop_gate::NoopGateCircuit::<Fr>::default();
+            let k = circuit_registry::param_or(params, "k", 5)?;
+            circuit_registry::RegisteredCircuit::mock(&circuit, k, vec![])
+        });
+        assert!(registry.names().eq(&vec!["inert_gate", "noop_gate"]));
+
+        let params = circuit_registry::parse_params(&["k=6".to_owned()]).unwrap();
+        let (output, findings) = registry
+            .analyze("inert_gate", &params, AnalyzerType::InertGates, "")
+            .unwrap();
+        assert!(output.output_status.eq(&AnalyzerOutputStatus::InertGates));
+        assert!(findings.len().eq(&1));
+        assert!(findings[0].contains("\"equal\""));
+
+        let (output, findings) = registry
+            .analyze("noop_gate", &BTreeMap::new(), AnalyzerType::NoopGates, "")
+            .unwrap();
+        assert!(output.output_status.eq(&AnalyzerOutputStatus::NoopGates));
+        assert!(findings.len().eq(&1));
+        assert!(findings[0].pass.eq(&AnalyzerType::NoopGates));
+
+        assert!(registry
+            .analyze("unknown", &BTreeMap::new(), AnalyzerType::NoopGates, "")
+            .is_err());
+        assert!(circuit_registry::parse_params(&["k".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn repl_test() {
+        let circuit = sample_circuits::copy_constraint::free_sum::FreeSumCircuit::<Fr>::default();
+        let prover: MockProver<Fr> = MockProver::run(5, &circuit, vec![vec![Fr::from(5)]]).unwrap();
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let mut analyzer = Analyzer::from(&circuit);
+        let mut repl = repl::Repl::new(&mut analyzer, prover.fixed, &prime).unwrap();
+
+        let commands = [
+            "locate A-0-1-0",
+            "gates-touching advice 2",
+            "gates-touching fixed 0",
+            "degree sum",
+            "frobnicate",
+            "assert (= A-0-0-0 (as ff2 F))",
+            "check-sat",
+            "assert (not (= A-0-0-0 (as ff2 F)))",
+            "check-sat",
+            "quit",
+            "check-sat",
+        ]
+        .join("\n");
+        let mut output = vec![];
+        repl.run(commands.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let responses: Vec<&str> = output
+            .split("> ")
+            .filter(|response| !response.is_empty())
+            .map(str::trim_end)
+            .collect();
+        assert_eq!(
+            responses,
+            vec![
+                "advice column 1, row 0 of region 0 \"sum region\"",
+                "sum",
+                "none",
+                "2",
+                "error: Unknown command `frobnicate`, type `help` for the list of commands!",
+                "ok",
+                "sat",
+                "ok",
+                "unsat",
+            ]
+        );
+    }
+
+    #[test]
+    fn rpc_server_test() {
+        let mut server = rpc::RpcServer::<Fr>::new();
+        server.register("always_violated_gate", || {
+            let circuit = sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit::<Fr>::default();
+            Analyzer::from(&circuit)
+        });
+
+        let requests = [
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "analyze", "params": {"circuit": "always_violated_gate"}}"#,
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "analyze", "params": {"circuit": "unknown"}}"#,
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "shutdown"}"#,
+            r#"{"jsonrpc": "2.0", "id": 4, "method": "analyze", "params": {"circuit": "always_violated_gate"}}"#,
+        ]
+        .join("\n");
+        let mut output = vec![];
+        server.serve(requests.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        // A notification per pass, then the response, streamed in order.
+        let notifications: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| line.contains(rpc::FINDINGS_NOTIFICATION))
+            .collect();
+        assert!(notifications.len().eq(&11));
+        let violated = notifications
+            .iter()
+            .find(|line| line.contains("\"pass\": \"always_violated_gates\""))
+            .unwrap();
+        assert!(violated.contains("always violated gate: \\\"always_violated\\\""));
+        assert!(lines[11].starts_with(r#"{"jsonrpc": "2.0", "id": 1, "result": {"findings": "#));
+        assert!(lines[12].contains(r#""id": 2, "error": {"code": -32602"#));
+        assert!(lines[13].eq(r#"{"jsonrpc": "2.0", "id": 3, "result": null}"#));
+        assert!(lines.len().eq(&14));
+    }
+
+    #[test]
+    fn rpc_server_registry_test() {
+        let mut registry = circuit_registry::CircuitRegistry::<Fr>::new();
+        registry.register_circuit("inert_gate", |params| {
+            let circuit =
+                sample_circuits::static_checks::inert_gate::InertGateCircuit::<Fr>::default();
+            let k = circuit_registry::param_or(params, "k", 5)?;
+            circuit_registry::RegisteredCircuit::mock(&circuit, k, vec![])
+        });
+        registry.register_circuit("noop_gate", |params| {
+            let circuit =
+                sample_circuits::static_checks::noop_gate::NoopGateCircuit::<Fr>::default();
+            let k = circuit_registry::param_or(params, "k", 5)?;
+            circuit_registry::RegisteredCircuit::mock(&circuit, k, vec![])
+        });
+        let mut server = rpc::RpcServer::<Fr>::new();
+        server.register_registry(registry);
+
+        // The id nested in the parameters is not the id of the request, which comes after them.
+        let requests = [
+            r#"{"jsonrpc": "2.0", "method": "analyze", "params": {"circuit": "noop_gate", "k": 6, "meta": {"id": 9}}, "id": "a"}"#,
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "analyze", "params": {"circuit": "inert_gate", "k": "x"}}"#,
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "#,
+        ]
+        .join("\n");
+        let mut output = vec![];
+        server.serve(requests.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert!(lines[..11]
+            .iter()
+            .all(|line| line.contains(r#""circuit": "noop_gate""#)));
+        assert!(lines[11].starts_with(r#"{"jsonrpc": "2.0", "id": "a", "result": {"findings": "#));
+        assert!(lines[12].contains(r#""id": 2, "error": {"code": -32603"#));
+        assert!(lines[12].contains("circuit parameter k"));
+        assert!(lines[13].contains(r#""id": null, "error": {"code": -32700"#));
+        assert!(lines.len().eq(&14));
+    }
+
+    #[test]
+    fn analyze_constant_lookup_columns_test() {
+        let circuit = sample_circuits::static_checks::constant_lookup_column::ConstantLookupColumnCircuit::<Fr>::default();
+        let k = 5;
+
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().eq(&Ok(())));
+
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer
+            .analyze_constant_lookup_columns(&prover.fixed)
+            .unwrap();
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("fixed column 1 "));
+    }
+
+    #[test]
+    fn exit_code_test() {
+        let code = |output_status| {
+            analyzer_io::exit_code(
+                &analyzer_io_type::AnalyzerOutput { output_status },
+                &[],
+                false,
+            )
+        };
+        assert!(code(AnalyzerOutputStatus::NotUnderconstrained).eq(&0));
+        assert!(code(AnalyzerOutputStatus::NotUnderconstrainedLocal).eq(&0));
+        assert!(code(AnalyzerOutputStatus::Invalid).eq(&1));
+        assert!(code(AnalyzerOutputStatus::Underconstrained).eq(&2));
+        assert!(code(AnalyzerOutputStatus::Overconstrained).eq(&3));
+        assert!(code(AnalyzerOutputStatus::UnusedColumns).eq(&0));
+        // Every static pass fails on an error, not only the ones listed when the exit code was introduced.
+        let error = analyzer_io_type::Finding::new(AnalyzerType::SelectorBleed, "error".to_owned());
+        for output_status in [
+            AnalyzerOutputStatus::ConstantDrift,
+            AnalyzerOutputStatus::UnrangedLookupInputs,
+            AnalyzerOutputStatus::UniformColumns,
+            AnalyzerOutputStatus::OrphanedSelectors,
+            AnalyzerOutputStatus::MutuallyExclusiveSelectors,
+            AnalyzerOutputStatus::SparseRegions,
+            AnalyzerOutputStatus::DeadInstances,
+            AnalyzerOutputStatus::SelectorBleed,
+        ] {
+            assert!(output_status.static_pass().is_some());
+            let output = analyzer_io_type::AnalyzerOutput { output_status };
+            assert!(analyzer_io::exit_code(&output, &[error.clone()], false).eq(&4));
+        }
+        assert!(AnalyzerOutputStatus::UnusedCustomGates
+            .static_pass()
+            .eq(&Some(AnalyzerType::UnusedGates)));
+        assert!(AnalyzerOutputStatus::Underconstrained
+            .static_pass()
+            .is_none());
+
+        let circuit =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit).with_quiet(true);
+        let output = analyzer.analyze_unused_custom_gates().unwrap();
+        assert!(analyzer_io::exit_code(&output, analyzer.log(), false).eq(&4));
+    }
+
+    #[test]
+    fn strict_exit_code_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit).with_quiet(true);
+        let output = analyzer.analyze_unused_columns().unwrap();
+        assert!(!analyzer.log().is_empty());
+        assert!(analyzer.log()[0].severity().eq(&Severity::Warning));
+        // Unused columns are only warnings, which fail the exit code in strict mode.
+        assert!(analyzer_io::exit_code(&output, analyzer.log(), false).eq(&0));
+        assert!(analyzer_io::exit_code(&output, analyzer.log(), true).eq(&4));
+    }
+
+    #[test]
+    fn analyze_naming_collisions_test() {
+        let circuit: sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit<Fr> =
+            sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit::default();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_naming_collisions().unwrap();
+        assert!(analyzer.log().is_empty());
+
+        analyzer.layouter.regions[0].name = "is_one".to_owned();
+        analyzer.analyze_naming_collisions().unwrap();
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("region \"is_one\""));
+    }
+
+    #[test]
+    fn analyze_linear_columns_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.analyze_linear_columns().unwrap();
+        // x only appears in s*(2*b1+b0-x), b0 and b1 also appear in their quadratic binary checks.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("index: 2"));
+    }
+
+    #[test]
+    fn gate_instance_dependencies_test() {
+        let circuit =
+            sample_circuits::static_checks::instance_gate::InstanceGateCircuit::<Fr>::default();
+        let analyzer = Analyzer::from(&circuit);
+        let dependencies = analyzer.gate_instance_dependencies();
+        assert!(dependencies.len().eq(&2));
+        assert!(dependencies["equals_instance"].eq(&HashSet::from([0])));
+        assert!(dependencies["bool_check"].is_empty());
+    }
+
+    #[test]
+    fn analyze_instance_rotations_test() {
+        let circuit = sample_circuits::static_checks::instance_rotations::InstanceRotationsCircuit::<
+            Fr,
+        >::default();
+        let k = 5;
+        let prover =
+            MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(1), Fr::from(3)]]).unwrap();
+        assert!(prover.verify().eq(&Ok(())));
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let rotations = analyzer.instance_rotations();
+        assert!(rotations.len().eq(&1));
+        assert!(rotations[&0].eq(&BTreeSet::from([0, 1])));
+
+        let output_status = analyzer.analyze_instance_rotations().unwrap().output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::InstanceRotations));
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("instance column 0 is read at rotations {0, 1}"));
+    }
+
+    #[test]
+    fn export_lean_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let analyzer = Analyzer::from(&circuit);
+        let exported = lean::export_gates(&analyzer.cs);
+        for gate in ["b0_binary_check", "b1_binary_check", "equality"] {
+            assert!(exported.contains(&format!("def gate_{}_0 ", gate)));
+            assert!(exported.contains(&format!("def gate_{}_holds ", gate)));
+        }
+        assert!(exported.contains("((sel 0 row * adv 0 row) * (1 + (-(adv 0 row))))"));
+    }
+
+    #[test]
+    fn export_r1cs_test() {
+        let mut cs = ConstraintSystem::<Fr>::default();
+        let a = cs.advice_column();
+        let b = cs.advice_column();
+        let s = cs.selector();
+        cs.create_gate("mul", |meta| {
+            let lhs = meta.query_advice(a, Rotation::cur());
+            let rhs = meta.query_advice(b, Rotation::cur());
+            let out = meta.query_advice(a, Rotation::next());
+            let s = meta.query_selector(s);
+            vec![s * (lhs * rhs - out)]
+        });
+        cs.create_gate("cube", |meta| {
+            let x = meta.query_advice(a, Rotation::cur());
+            let out = meta.query_advice(b, Rotation::next());
+            let s = meta.query_selector(s);
+            vec![s * (x.clone() * x.clone() * x - out)]
+        });
+
+        let exported = r1cs::export_gates(&cs);
+        assert!(exported.constraints.len().eq(&1));
+        let constraint = &exported.constraints[0];
+        assert!(constraint.gate.eq("mul"));
+        assert!(r1cs::render_linear_combination(&constraint.a).eq("adv0[0]"));
+        assert!(r1cs::render_linear_combination(&constraint.b).eq("adv1[0]"));
+        assert!(r1cs::render_linear_combination(&constraint.c).eq("adv0[1]"));
+
+        assert!(exported.unsupported.len().eq(&1));
+        assert!(exported.unsupported[0].gate.eq("cube"));
+        assert!(exported.unsupported[0].reason.eq("degree 3 polynomial"));
+        assert!(r1cs::render(&exported)
+            .contains("; unsupported: gate \"cube\", polynomial 0 (degree 3 polynomial)"));
+    }
+
+    #[test]
+    fn constants_test() {
+        let circuit: sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit<Fr> =
+            sample_circuits::static_checks::always_violated_gate::AlwaysViolatedGateCircuit::default();
+        let analyzer = Analyzer::from(&circuit);
+        let constants = analyzer.constants();
+        assert!(constants.len().eq(&3));
+        assert!(constants.contains(&Fr::zero()));
+        assert!(constants.contains(&Fr::one()));
+        assert!(constants.contains(&Fr::from(5)));
+    }
+
+    #[test]
+    fn analyze_underconstrained_fibonacci_test() {
+        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let mut analyzer = Analyzer::from(&circuit);
+
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 5,
+            },
+        };
+        let k: u32 = 11;
+
+        let public_input = vec![Fr::from(3)];
+
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[derive(Debug)]
+    struct CannedSolver(&'static str);
+
+    impl Solver for CannedSolver {
+        fn solve(&self, smt: &str) -> anyhow::Result<String> {
+            assert!(smt.contains("(check-sat)"));
+            Ok(self.0.to_owned())
+        }
+    }
+
+    /// Answers every check with the same model, without any equivalent model inside a `(push)` scope.
+    #[derive(Debug)]
+    struct EchoSolver(Arc<AtomicUsize>);
+
+    impl Solver for EchoSolver {
+        fn solve(&self, smt: &str) -> anyhow::Result<String> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            if smt.matches("(push)").count() > smt.matches("(pop)").count() {
+                return Ok("unsat\n".to_owned());
+            }
+            let mut output = "sat\n".to_owned();
+            for line in smt.lines() {
+                if let Some(var) = line.strip_prefix("(get-value (") {
+                    output.push_str(&format!("(({} #f1m11))\n", var.trim_end_matches("))")));
+                }
+            }
+            Ok(output)
+        }
+    }
+
+    #[test]
+    fn solved_model_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let mut analyzer =
+            Analyzer::from(&circuit).with_solver(EchoSolver(Arc::new(AtomicUsize::new(0))));
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/solved_model.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        let variables: BTreeSet<String> = printer.vars.keys().cloned().collect();
+
+        let SolvedModel { assignments } = analyzer
+            .solve_and_get_solved_model(smt_file_path.to_owned(), &variables)
+            .unwrap();
+        // b0, b1 and x in the only region, and the instance cell x is bound to.
+        let cell = |kind, column, row| CellLocation {
+            kind,
+            region: if kind == CellKind::Instance {
+                None
+            } else {
+                Some(0)
+            },
+            column,
+            row,
+        };
+        assert!(assignments.eq(&vec![
+            (cell(CellKind::Advice, 0, 0), Fr::from(1)),
+            (cell(CellKind::Advice, 1, 0), Fr::from(1)),
+            (cell(CellKind::Advice, 2, 0), Fr::from(1)),
+            (cell(CellKind::Instance, 0, 0), Fr::from(1)),
+        ]));
+    }
+
+    #[test]
+    fn grouped_declarations_test() {
+        let circuit =
+            sample_circuits::copy_constraint::gadget_regions::GadgetRegionsCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/grouped_declarations.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start_grouped(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        smt::write_declarations(&mut printer);
+        let variables: Vec<String> = printer.vars.keys().cloned().collect();
+        drop(printer);
+
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt_validator::validate(&smt).is_ok());
+        let lines: Vec<&str> = smt.lines().collect();
+        let declarations: Vec<&str> = lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("(declare-fun "))
+            .map(|line| line.trim_end_matches(" () F)"))
+            .collect();
+        // Every variable is declared once, in a block ahead of the first assertion, grouped by region.
+        let mut sorted_declarations = declarations.clone();
+        sorted_declarations.sort();
+        assert!(sorted_declarations.eq(&variables));
+        let last_declaration = lines
+            .iter()
+            .rposition(|line| line.starts_with("(declare-fun"))
+            .unwrap();
+        let first_assertion = lines
+            .iter()
+            .position(|line| line.starts_with("(assert"))
+            .unwrap();
+        assert!(last_declaration < first_assertion);
+        assert!(smt.contains("; region 0\n(declare-fun A-0-0-0 () F)"));
+        assert!(smt.contains("; region 2\n"));
+        let regions: Vec<usize> = declarations
+            .iter()
+            .map(|name| name.split('-').nth(1).unwrap().parse().unwrap())
+            .collect();
+        assert!(regions.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn grouped_declarations_verdict_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::general_bit_decomp::BitDecompositonUnderConstrained::<
+                Fr,
+                2,
+            >::default();
+        let k = 11;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let verdict = |analyzer: Analyzer<Fr>| {
+            let mut analyzer = analyzer.with_quiet(true);
+            let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            let analyzer_input = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: instance_cols,
+                    iterations: 1,
+                },
+            };
+            analyzer
+                .analyze_underconstrained(analyzer_input, prover.fixed.clone(), &prime)
+                .unwrap()
+                .output_status
+        };
+        // Both encodings of the benchmark circuit reach the same verdict.
+        assert!(verdict(Analyzer::from(&circuit)).eq(&verdict(
+            Analyzer::from(&circuit).with_grouped_declarations()
+        )));
+    }
+
+    #[test]
+    fn degree_annotations_test() {
+        let circuit =
+            sample_circuits::copy_constraint::gadget_regions::GadgetRegionsCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit).with_degree_annotations();
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/degree_annotations.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        drop(printer);
+
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt_validator::validate(&smt).is_ok());
+        let lines: Vec<&str> = smt.lines().collect();
+        let mut annotations = 0;
+        for (line_no, line) in lines.iter().enumerate() {
+            if let Some(annotation) = line.strip_prefix("; degree ") {
+                annotations += 1;
+                assert!(lines[line_no + 1].starts_with("(assert"));
+                let (degree, gate) = annotation.split_once(" (gate ").unwrap();
+                let gate = analyzer
+                    .cs
+                    .gates
+                    .iter()
+                    .find(|candidate| gate.starts_with(&format!("{:?}", candidate.name())))
+                    .unwrap();
+                let expected = abstract_expr::witness_degree(&gate.polynomials()[0]);
+                assert!(degree.parse::<usize>().unwrap().eq(&expected));
+            }
+        }
+        // Both gates are encoded on the row of each of the three regions.
+        assert!(annotations.eq(&6));
+        assert!(smt.contains("; degree 3 (gate \"sbox\", polynomial 0)"));
+        assert!(smt.contains("; degree 1 (gate \"sum\", polynomial 0)"));
+    }
+
+    #[test]
+    fn distinguishable_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+
+        let witness = |b0: u64, b1: u64| {
+            let cell = |column| CellLocation {
+                kind: CellKind::Advice,
+                region: Some(0),
+                column,
+                row: 0,
+            };
+            BTreeMap::from([
+                (cell(0), Fr::from(b0)),
+                (cell(1), Fr::from(b1)),
+                (cell(2), Fr::from(b0 + 2 * b1)),
+            ])
+        };
+        // x = 1 and x = 3 are both valid decompositions, exposing different public inputs.
+        assert!(analyzer
+            .distinguishable(&witness(1, 0), &witness(1, 1), prover.fixed.clone(), "11")
+            .unwrap());
+        assert!(!analyzer
+            .distinguishable(&witness(1, 0), &witness(1, 0), prover.fixed.clone(), "11")
+            .unwrap());
+        // b0 = 2 is not a bit.
+        assert!(!analyzer
+            .distinguishable(&witness(1, 0), &witness(2, 0), prover.fixed, "11")
+            .unwrap());
+
+        // Witnesses fixing only `a` leave the public sum free, so they can expose different values.
+        let circuit = sample_circuits::copy_constraint::free_sum::FreeSumCircuit::<Fr>::default();
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(5)]]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+        let witness = BTreeMap::from([(
+            CellLocation {
+                kind: CellKind::Advice,
+                region: Some(0),
+                column: 0,
+                row: 0,
+            },
+            Fr::from(2),
+        )]);
+        assert!(analyzer
+            .distinguishable(&witness, &witness, prover.fixed, "11")
+            .unwrap());
+    }
+
+    #[test]
+    fn analyze_underconstrained_resume_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let checkpoint_path = "src/output/checkpoint.txt";
+        let _ = std::fs::remove_file(checkpoint_path);
+
+        let run = |iterations, resume| {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let mut analyzer = Analyzer::from(&circuit)
+                .with_solver(EchoSolver(calls.clone()))
+                .with_checkpoint(checkpoint_path, resume);
+            let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: instance_cols,
+                    iterations,
+                },
+            };
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input, prover.fixed.clone(), "11")
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+            calls.load(Ordering::SeqCst)
+        };
+
+        // Interrupted after two iterations: the initial check, then two solver calls per iteration.
+        assert!(run(2, false).eq(&5));
+        // Resumed up to four iterations, the first two models are not examined again.
+        assert!(run(4, true).eq(&5));
+        let checkpoint = Checkpoint::load(checkpoint_path).unwrap();
+        assert!(checkpoint.iteration.eq(&4));
+        assert!(checkpoint.blocking_clauses.len().eq(&4));
+    }
+
+    #[test]
+    fn analyze_underconstrained_blocking_clauses_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        std::fs::create_dir_all("src/output/").unwrap();
+        let clauses_path = "src/output/blocking_clauses.smt2";
+
+        // Each run starts from a fresh analyzer, as after a restart of the process.
+        let run = |iterations, resume| {
+            let mut analyzer = Analyzer::from(&circuit);
+            if resume {
+                analyzer.load_blocking_clauses(clauses_path).unwrap();
+            }
+            let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: instance_cols,
+                    iterations,
+                },
+            };
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input, prover.fixed.clone(), "11")
+                .unwrap()
+                .output_status;
+            analyzer.save_blocking_clauses(clauses_path).unwrap();
+            (output_status, analyzer.blocking_clauses)
+        };
+
+        // Paused after two of the four possible public inputs.
+        let (output_status, first) = run(2, false);
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+        assert!(first.len().eq(&2));
+        // Saved as a checkpoint, so that the same clauses are asserted the same way on both paths.
+        let saved = Checkpoint::load(clauses_path).unwrap();
+        assert!(saved.blocking_clauses.eq(&first));
+        assert!(first.iter().all(|clause| !clause.starts_with("(assert")));
+
+        // Resumed, the two models found before are not found again.
+        let (output_status, second) = run(2, true);
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+        assert!(second[..2].eq(&first[..]));
+        assert!(second.iter().collect::<HashSet<_>>().len().eq(&4));
+
+        // Every public input has been examined.
+        let (output_status, third) = run(1, true);
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        assert!(third.eq(&second));
+
+        let mut analyzer = Analyzer::from(&circuit);
+        std::fs::write(clauses_path, "(check-sat)\n").unwrap();
+        assert!(analyzer.load_blocking_clauses(clauses_path).is_err());
+    }
+
+    #[test]
+    fn analyze_underconstrained_early_stop_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_solver(EchoSolver(calls.clone()))
+            .with_early_stop(2);
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 10,
+            },
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, "11")
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+        // Every model behaves the same: the first one is new, the next two are not.
+        assert!(analyzer.iterations_used.eq(&3));
+        assert!(calls.load(Ordering::SeqCst).eq(&7));
+    }
+
+    #[test]
+    fn analyze_underconstrained_with_custom_solver_test() {
+        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let mut analyzer = Analyzer::from(&circuit).with_solver(CannedSolver("unsat\n"));
+
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 5,
+            },
+        };
+        let k: u32 = 11;
+
+        let public_input = vec![Fr::from(3)];
+
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Overconstrained));
+    }
+
+    /// Answers `unsat`, keeping the last formula it was asked to solve.
+    #[derive(Debug)]
+    struct RecordingSolver(Arc<Mutex<String>>);
+
+    impl Solver for RecordingSolver {
+        fn solve(&self, smt: &str) -> anyhow::Result<String> {
+            *self.0.lock().unwrap() = smt.to_owned();
+            Ok("unsat\n".to_owned())
+        }
+    }
+
+    #[test]
+    fn analyze_gadget_test() {
+        let circuit =
+            sample_circuits::copy_constraint::gadget_regions::GadgetRegionsCircuit::<Fr>::default();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let smt = Arc::new(Mutex::new(String::new()));
+        let mut analyzer = Analyzer::from(&circuit).with_solver(RecordingSolver(smt.clone()));
+        let analyzer_input = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: BTreeMap::new(),
+                iterations: 5,
+            },
+        };
+        let output_status = analyzer
+            .analyze_gadget("poseidon_", analyzer_input, prover.fixed.clone(), "11")
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Overconstrained));
+        assert!(analyzer.region_prefix.is_none());
+
+        // Only the cells of the two `poseidon_sbox_*` regions are encoded, not the sum region they are copied to.
+        let smt = smt.lock().unwrap();
+        assert!(smt.contains("A-0-1-0"));
+        assert!(smt.contains("A-1-1-0"));
+        assert!(!smt.contains("A-2-"));
+
+        let analyzer_input = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: BTreeMap::new(),
+                iterations: 5,
+            },
+        };
+        assert!(analyzer
+            .analyze_gadget("sha256_", analyzer_input, prover.fixed, "11")
+            .is_err());
+    }
+
+    #[test]
+    fn verdict_policy_test() {
+        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let k: u32 = 11;
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let analyzer_input = || {
+            let analyzer = Analyzer::from(&circuit);
+            analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: analyzer
+                        .extract_instance_cols(analyzer.layouter.eq_table.clone()),
+                    iterations: 5,
+                },
+            }
+        };
+
+        // By default, a solver giving up fails the analysis.
+        let mut analyzer = Analyzer::from(&circuit).with_solver(CannedSolver("unknown\n"));
+        assert!(analyzer
+            .analyze_underconstrained(analyzer_input(), prover.fixed.clone(), "11")
+            .is_err());
+
+        // A security policy treats it as a failure of the circuit instead.
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_solver(CannedSolver("unknown\n"))
+            .with_verdict_policy(|sat| match sat {
+                Satisfiability::Unknown => Some(AnalyzerOutputStatus::Underconstrained),
+                _ => None,
+            });
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input(), prover.fixed.clone(), "11")
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+
+        // Verdicts the policy does not map are handled as usual.
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_solver(CannedSolver("unsat\n"))
+            .with_verdict_policy(|sat| match sat {
+                Satisfiability::Unknown => Some(AnalyzerOutputStatus::Underconstrained),
+                _ => None,
+            });
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input(), prover.fixed, "11")
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Overconstrained));
+    }
+
+    #[test]
+    fn analyze_equal_instance_columns_test() {
+        let circuit =
+            sample_circuits::copy_constraint::equal_instances::EqualInstancesCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let instances = vec![vec![Fr::from(4)], vec![Fr::from(4)], vec![Fr::from(5)]];
+        let prover = MockProver::<Fr>::run(k, &circuit, instances).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let output_status = analyzer
+            .analyze_equal_instance_columns(prover.fixed, "11")
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::EqualInstanceColumns));
+        // `i0` and `i1` are bound to copies of the same cell, `i2` is always their successor.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("instance columns 0 and 1 are equal on all 1 row(s)"));
+    }
+
+    #[test]
+    fn inlined_copies_test() {
+        let circuit =
+            sample_circuits::copy_constraint::equal_instances::EqualInstancesCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let instances = vec![vec![Fr::from(4)], vec![Fr::from(4)], vec![Fr::from(5)]];
+        let prover = MockProver::<Fr>::run(k, &circuit, instances).unwrap();
+
+        // `a`, `b`, `i0` and `i1` form a class, `c` and `i2` another, both represented by an instance cell.
+        let mut analyzer = Analyzer::from(&circuit).with_inlined_copies();
+        let classes = analyzer.copy_classes();
+        let mut members: BTreeMap<CellLocation, Vec<CellLocation>> = BTreeMap::new();
+        for (member, (representative, _)) in classes.iter() {
+            members.entry(*representative).or_default().push(*member);
+        }
+        assert!(members.len().eq(&2));
+        assert!(members
+            .keys()
+            .all(|representative| representative.kind == CellKind::Instance));
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/inlined_copies.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer
+            .encode_circuit(&mut printer, prover.fixed.clone())
+            .unwrap();
+        smt::write_end(&mut printer);
+
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        let declared: HashSet<&str> = smt
+            .lines()
+            .filter_map(|line| line.strip_prefix("(declare-fun "))
+            .filter_map(|line| line.split(' ').next())
+            .collect();
+        for (representative, class) in members.iter() {
+            let names: Vec<String> = class
+                .iter()
+                .map(|location| {
+                    DefaultNameFormatter.cell_name(&CellRef {
+                        kind: location.kind,
+                        region: location.region,
+                        region_name: "",
+                        column: location.column,
+                        row: location.row,
+                    })
+                })
+                .collect();
+            assert!(names
+                .iter()
+                .filter(|name| declared.contains(name.as_str()))
+                .count()
+                .eq(&1));
+            assert!(declared.contains(format!("I-{}-0", representative.column).as_str()));
+        }
+        assert!(!smt.contains("(ff.neg I-"));
+        assert!(smt_validator::validate(&smt).is_ok());
+
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let verdict = |analyzer: Analyzer<Fr>| {
+            let mut analyzer = analyzer.with_quiet(true);
+            let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            let analyzer_input = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: instance_cols,
+                    iterations: 2,
+                },
+            };
+            analyzer
+                .analyze_underconstrained(analyzer_input, prover.fixed.clone(), &prime)
+                .unwrap()
+                .output_status
+        };
+        assert!(verdict(Analyzer::from(&circuit))
+            .eq(&verdict(Analyzer::from(&circuit).with_inlined_copies())));
+    }
+
+    #[test]
+    fn analyze_determined_cells_test() {
+        let circuit = sample_circuits::copy_constraint::free_sum::FreeSumCircuit::<Fr>::default();
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(5)]]).unwrap();
+        assert!(prover.verify().is_ok());
+
+        let mut analyzer = Analyzer::from(&circuit);
+        let inputs = BTreeMap::from([(
+            CellLocation {
+                kind: CellKind::Instance,
+                region: None,
+                column: 0,
+                row: 0,
+            },
+            Fr::from(5),
+        )]);
+        let output_status = analyzer
+            .analyze_determined_cells(&inputs, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::DeterminedCells));
+        // `x` is copied to the public input, while any `a`, `b` summing to it are valid.
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("determined cell: cell A-0-2-0"));
+        assert!(analyzer.log()[0].contains("always equals 5"));
+    }
+
+    #[test]
+    fn solve_existing_test() {
+        let variables = BTreeSet::from(["x".to_owned()]);
+        let model = Analyzer::<Fr>::solve_existing(
+            "src/test/smt/square_root.smt2",
+            &variables,
+            &CommandSolver::cvc5(),
+        )
+        .unwrap();
+        assert!(model.sat.eq(&Satisfiability::Satisfiable));
+        assert!(model.result["x"].value.element.eq("9"));
+    }
+
+    #[test]
+    fn parse_field_value_syntaxes_test() {
+        for output in [
+            "sat\n((x #f9m11))\n",
+            "sat\n((x #f-2m11))\n",
+            "sat\n((x (as ff9 F)))\n",
+            "sat\n((x (_ ff9 11)))\n",
+            "sat\n((x (_ ff9)))\n",
+            "sat\n((x 9))\n",
+        ] {
+            let model = smt_parser::extract_model_response(output.to_owned()).unwrap();
+            assert!(model.sat.eq(&Satisfiability::Satisfiable));
+            assert_eq!(model.result["x"].value.element, "9", "{}", output);
+        }
+    }
+
+    #[test]
+    fn validate_smt_test() {
+        assert!(smt_validator::validate_file("src/test/smt/square_root.smt2").is_ok());
+
+        let error = smt_validator::validate_file("src/test/smt/malformed.smt2").unwrap_err();
+        assert_eq!(
+            error.root_cause().to_string(),
+            "line 8: `ff.add` expects at least 2 operand(s), found 1"
+        );
+
+        assert_eq!(
+            smt_validator::validate("(declare-fun x () F)\n(assert (= x y))"),
+            Err(SyntaxError {
+                line: 2,
+                message: "`y` is used before being declared".to_owned(),
+            })
+        );
+        assert_eq!(
+            smt_validator::validate("(declare-fun x () F)\n(assert (= x\n  x)"),
+            Err(SyntaxError {
+                line: 2,
+                message: "unclosed parenthesis".to_owned(),
+            })
+        );
+        assert_eq!(
+            smt_validator::validate("(assert (= () (as ff0 F)))"),
+            Err(SyntaxError {
+                line: 1,
+                message: "empty term".to_owned(),
+            })
+        );
+    }
+
+    #[derive(Debug)]
+    struct DelayedSolver(Duration, &'static str);
+
+    impl Solver for DelayedSolver {
+        fn solve(&self, _smt: &str) -> anyhow::Result<String> {
+            thread::sleep(self.0);
+            Ok(self.1.to_owned())
+        }
+    }
+
+    #[test]
+    fn solve_with_portfolio_test() {
+        let portfolio = PortfolioSolver::default()
+            .with_solver(DelayedSolver(Duration::from_secs(2), "unsat\n"))
+            .with_solver(DelayedSolver(Duration::from_millis(10), "unknown\n"))
+            .with_solver(DelayedSolver(
+                Duration::from_millis(50),
+                "sat\n((x #f9m11))\n",
+            ));
+        let variables = BTreeSet::from(["x".to_owned()]);
+        let model =
+            Analyzer::<Fr>::solve_existing("src/test/smt/square_root.smt2", &variables, &portfolio)
+                .unwrap();
+        assert!(model.sat.eq(&Satisfiability::Satisfiable));
+        assert!(model.result["x"].value.element.eq("9"));
+    }
+
+    /// Records how many solvers are running at once.
+    #[derive(Debug)]
+    struct TrackedSolver(DelayedSolver, Arc<AtomicUsize>, Arc<AtomicUsize>);
+
+    impl Solver for TrackedSolver {
+        fn solve(&self, smt: &str) -> anyhow::Result<String> {
+            let running = self.1.fetch_add(1, Ordering::SeqCst) + 1;
+            self.2.fetch_max(running, Ordering::SeqCst);
+            let output = self.0.solve(smt);
+            self.1.fetch_sub(1, Ordering::SeqCst);
+            output
+        }
+    }
+
+    #[test]
+    fn solve_with_serial_portfolio_test() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let tracked = |delay, output| {
+            TrackedSolver(DelayedSolver(delay, output), running.clone(), peak.clone())
+        };
+        let portfolio = PortfolioSolver::default()
+            .with_solver(tracked(Duration::from_millis(10), "unknown\n"))
+            .with_solver(tracked(Duration::from_millis(100), "unsat\n"))
+            .with_solver(tracked(Duration::from_millis(10), "sat\n((x #f9m11))\n"));
+
+        // In parallel, the fastest conclusive solver wins.
+        let smt = std::fs::read_to_string("src/test/smt/square_root.smt2").unwrap();
+        assert!(portfolio.solve(&smt).unwrap().starts_with("sat"));
+        assert!(peak.load(Ordering::SeqCst).eq(&3));
+
+        // Serially, the first conclusive solver in order wins, as if they were run one after the other.
+        peak.store(0, Ordering::SeqCst);
+        let portfolio = portfolio.with_parallelism(Some(1));
+        assert!(portfolio.solve(&smt).unwrap().eq("unsat\n"));
+        assert!(peak.load(Ordering::SeqCst).eq(&1));
+    }
+
+    /// Never answers, and records when it is cancelled.
+    #[derive(Debug)]
+    struct StallingSolver(Arc<AtomicBool>);
+
+    impl Solver for StallingSolver {
+        fn solve(&self, _smt: &str) -> anyhow::Result<String> {
+            unreachable!("the watchdog only runs cancellable solves")
+        }
+
+        fn solve_cancellable(&self, _smt: &str, cancel: &AtomicBool) -> anyhow::Result<String> {
+            while !cancel.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            self.0.store(true, Ordering::SeqCst);
+            Err(anyhow::anyhow!("cancelled"))
+        }
+    }
+
+    #[test]
+    fn solve_with_watchdog_test() {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let watchdog = WatchdogSolver::new(
+            StallingSolver(cancelled.clone()),
+            Duration::from_millis(100),
+        )
+        .with_restart(DelayedSolver(
+            Duration::from_millis(10),
+            "sat\n((x #f9m11))\n",
+        ));
+        let variables = BTreeSet::from(["x".to_owned()]);
+        let model =
+            Analyzer::<Fr>::solve_existing("src/test/smt/square_root.smt2", &variables, &watchdog)
+                .unwrap();
+        assert!(model.sat.eq(&Satisfiability::Satisfiable));
+        assert!(model.result["x"].value.element.eq("9"));
+        assert!(watchdog.restarts().eq(&1));
+        // The stalled attempt is cancelled, shortly after the timeout fires.
+        thread::sleep(Duration::from_millis(50));
+        assert!(cancelled.load(Ordering::SeqCst));
+
+        let watchdog = WatchdogSolver::new(StallingSolver(cancelled), Duration::from_millis(10));
+        assert!(watchdog.solve("(check-sat)").is_err());
+        assert!(watchdog.restarts().eq(&0));
+    }
+
+    #[test]
+    fn analyze_properties_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let mut analyzer = Analyzer::from(&circuit);
+        // b0 is boolean.
+        analyzer.add_property(
+            "b0_is_bit",
+            "(or (= A-0-0-0 (as ff0 F)) (= A-0-0-0 (as ff1 F)))",
+        );
+        // b0 can also be 0.
+        analyzer.add_property("b0_is_one", "(= A-0-0-0 (as ff1 F))");
+        analyzer.analyze_properties(prover.fixed, &prime).unwrap();
+        assert!(analyzer.log().len().eq(&1));
+        assert!(analyzer.log()[0].contains("\"b0_is_one\""));
+        assert!(analyzer.log()[0].contains("A-0-0-0 = 0"));
+    }
+
+    #[test]
+    fn negate_each_node_type_test() {
+        let terms = [
+            ("(as ff5 F)", NodeType::Constant),
+            ("A-0-0-0", NodeType::Advice),
+            ("I-0-0", NodeType::Instance),
+            ("F-0-0-0", NodeType::Fixed),
+            ("ff.neg A-0-0-0", NodeType::Negated),
+            ("ff.mul A-0-0-0 I-0-0", NodeType::Mult),
+            ("ff.add A-0-0-0 F-0-0-0", NodeType::Add),
+            ("ff.mul (as ff2 F) A-0-0-0", NodeType::Scaled),
+            ("ff.add A-0-0-0 (as ff1 F)", NodeType::Poly),
+        ];
+        let mut script = "(set-logic QF_FF)\n(define-sort F () (_ FiniteField 11))\n".to_owned();
+        for var in ["A-0-0-0", "I-0-0", "F-0-0-0"] {
+            script.push_str(&format!("(declare-fun {} () F)\n", var));
+        }
+        for (term, node_type) in terms {
+            let negated = format!("ff.neg {}", smt::wrap_operand(term.to_owned(), &node_type));
+            let operand = smt::wrap_operand(negated, &NodeType::Negated);
+            script.push_str(&format!("(assert (= {} {}))\n", operand, operand));
+        }
+        script.push_str("(check-sat)\n");
+        let output = CommandSolver::cvc5().solve(&script).unwrap();
+        assert!(output.trim().eq("sat"));
+    }
+
+    #[test]
+    fn command_solver_pipes_test() {
+        // `cat` echoes the script, filling its output pipe long before the whole script is written.
+        let script = "(assert true)\n".repeat(100_000);
+        let echo = CommandSolver {
+            program: "cat".to_owned(),
+            args: vec![],
+        };
+        assert!(echo.solve(&script).unwrap().eq(&script));
+
+        // `sleep` never reads the script, so writing it blocks until the solver is cancelled.
+        let stalled = CommandSolver {
+            program: "sleep".to_owned(),
+            args: vec!["10".to_owned()],
+        };
+        let cancel = Arc::new(AtomicBool::new(false));
+        let canceller = {
+            let cancel = cancel.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                cancel.store(true, Ordering::SeqCst);
+            })
+        };
+        let started = Instant::now();
+        let error = stalled.solve_cancellable(&script, &cancel).unwrap_err();
+        assert!(error.to_string().contains("was cancelled"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        canceller.join().unwrap();
+    }
+
+    #[test]
+    fn solver_missing_finite_fields_test() {
+        let stderr = "(error \"Parse Error: <stdin>:5.17: Unknown logic 'QF_FF'\")\n";
+        let error =
+            check_finite_field_support(stderr, || Some("This is cvc5 version 1.0.0".to_owned()))
+                .unwrap_err();
+        assert!(error.eq(&AnalyzerError::SolverMissingFeature {
+            feature: "finite fields",
+            solver_version: Some("This is cvc5 version 1.0.0".to_owned()),
+        }));
+        assert!(error.to_string().contains("does not support finite fields"));
+
+        assert!(check_finite_field_support("sat\n((x #f9m11))\n", || None).is_ok());
+
+        // The solver rejects the logic and exits before reading the whole script.
+        let solver = CommandSolver {
+            program: "sh".to_owned(),
+            args: vec![
+                "-c".to_owned(),
+                "echo '(error \"Unknown logic QF_FF\")'".to_owned(),
+            ],
+        };
+        let script = "(assert true)\n".repeat(100_000);
+        let error = solver.solve(&script).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AnalyzerError>(),
+            Some(AnalyzerError::SolverMissingFeature {
+                feature: "finite fields",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn emitted_smt_is_deterministic_test() {
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let emit = || {
+            let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+                sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+            let mut analyzer = Analyzer::from(&circuit).with_solver(CannedSolver("unsat\n"));
+
+            let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            let mut specified_instance_cols = BTreeMap::new();
+            for var in instance_cols.iter() {
+                specified_instance_cols.insert(var.0.clone(), 1);
+            }
+            let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Specific,
+                verification_input: VerificationInput {
+                    instances_string: specified_instance_cols,
+                    iterations: 1,
+                },
+            };
+            let k: u32 = 11;
+            let prover: MockProver<Fr> =
+                MockProver::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+            analyzer
+                .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+                .unwrap();
+            std::fs::read("src/output/out.smt2").unwrap()
+        };
+        assert!(emit().eq(&emit()));
+    }
+
+    #[test]
+    fn dry_encode_test() {
+        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let k: u32 = 11;
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+        analyzer.dry_encode(prover.fixed, "11").unwrap();
+
+        // A reordering of declarations, as iterating over a HashMap would produce, is reported.
+        let error = Analyzer::<Fr>::compare_encodings(
+            "(declare-fun A-0-0-0 () F)\n(declare-fun A-0-1-0 () F)\n",
+            "(declare-fun A-0-1-0 () F)\n(declare-fun A-0-0-0 () F)\n",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("line 1 differs"));
+        assert!(Analyzer::<Fr>::compare_encodings("(check-sat)\n", "(check-sat)\n").is_ok());
+    }
+
+    /// Encodes `circuit` (see `Analyzer::encode_circuit`) and compares the SMT to the checked-in `golden_path`.
+    ///
+    /// The fixed columns are the ones laid out by `MockProver` for `2^k` rows and the public inputs `instances`. Run
+    /// the tests with `UPDATE_GOLDEN=1` to overwrite the golden file instead, after an intended change of the
+    /// encoding.
+    fn assert_smt_matches<C: Circuit<Fr>>(
+        circuit: &C,
+        k: u32,
+        instances: Vec<Vec<Fr>>,
+        golden_path: &str,
+    ) {
+        let prover = MockProver::<Fr>::run(k, circuit, instances).unwrap();
+        let mut analyzer = Analyzer::from(circuit);
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let file_name = std::path::Path::new(golden_path).file_name().unwrap();
+        let smt_file_path = std::path::Path::new("src/output/").join(file_name);
+        let mut smt_file = std::fs::File::create(&smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        let smt = std::fs::read_to_string(&smt_file_path).unwrap();
+
+        if std::env::var("UPDATE_GOLDEN").is_ok() {
+            std::fs::write(golden_path, &smt).unwrap();
+            return;
+        }
+        let golden = std::fs::read_to_string(golden_path).unwrap();
+        let mismatch = golden
+            .lines()
+            .zip(smt.lines())
+            .position(|(expected, actual)| expected != actual)
+            .unwrap_or_else(|| golden.lines().count().min(smt.lines().count()));
+        assert!(
+            golden.eq(&smt),
+            "The encoding differs from {} on line {} ({:?} vs {:?}), run with UPDATE_GOLDEN=1 if the change is intended.",
+            golden_path,
+            mismatch + 1,
+            golden.lines().nth(mismatch).unwrap_or("<end of file>"),
+            smt.lines().nth(mismatch).unwrap_or("<end of file>")
+        );
+    }
+
+    #[test]
+    fn golden_smt_test() {
+        assert_smt_matches(
+            &sample_circuits::static_checks::gateless::GatelessCircuit::<Fr>::default(),
+            5,
+            vec![],
+            "src/test/smt/golden/gateless.smt2",
+        );
+        assert_smt_matches(
+            &sample_circuits::static_checks::off_by_one_selector::OffByOneSelectorCircuit::<Fr>::default(),
+            5,
+            vec![],
+            "src/test/smt/golden/off_by_one_selector.smt2",
+        );
+        // Copies between regions, and cells bound to public inputs.
+        assert_smt_matches(
+            &sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData),
+            5,
+            vec![vec![Fr::from(1), Fr::from(1), Fr::from(55)]],
+            "src/test/smt/golden/fibonacci.smt2",
+        );
+        // A lookup into a fixed table, padded with its first row up to the usable rows.
+        assert_smt_matches(
+            &sample_circuits::lookup_circuits::lookup_underconstrained::MyCircuit::<Fr>(
+                PhantomData,
+            ),
+            6,
+            vec![vec![Fr::from(1), Fr::from(1), Fr::from(3)]],
+            "src/test/smt/golden/lookup_underconstrained.smt2",
+        );
+    }
+
+    /// Forwards to cvc5, keeping every formula it was asked to solve.
+    #[derive(Debug)]
+    struct TranscriptSolver(Arc<Mutex<Vec<String>>>);
+
+    impl Solver for TranscriptSolver {
+        fn solve(&self, smt: &str) -> anyhow::Result<String> {
+            self.0.lock().unwrap().push(smt.to_owned());
+            CommandSolver::cvc5().solve(smt)
+        }
+    }
+
+    #[test]
+    fn analyze_underconstrained_boundary_values_first_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let instance = instance_cols.keys().next().unwrap().clone();
+        let formulas = Arc::new(Mutex::new(vec![]));
+        analyzer = analyzer
+            .with_solver(TranscriptSolver(formulas.clone()))
+            .with_boundary_values_first()
+            .with_quiet(true);
+        let analyzer_input = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 4,
+            },
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+
+        // The first iterations each pin the public input to a boundary value, `-1` admitting no witness.
+        let pinned = format!("(assert ( = {} (as ff", instance);
+        let boundary_values: Vec<String> = formulas
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|formula| {
+                let last = formula
+                    .lines()
+                    .filter(|line| line.starts_with("(assert"))
+                    .last()?;
+                last.strip_prefix(pinned.as_str())?
+                    .strip_suffix(" F)))")
+                    .map(str::to_owned)
+            })
+            .collect();
+        assert!(boundary_values.eq(&["0", "1", "-1"]));
+    }
+
+    #[test]
+    fn analyze_underconstrained_with_output_columns_test() {
+        let circuit = sample_circuits::copy_constraint::free_sum::FreeSumCircuit::<Fr>::default();
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let k: u32 = 5;
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![vec![Fr::from(5)]]).unwrap();
+        let analyze = |output_column: usize| {
+            let mut analyzer = Analyzer::from(&circuit)
+                .with_output_columns(BTreeSet::from([output_column]))
+                .with_quiet(true);
+            let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+            let analyzer_input = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: instance_cols,
+                    iterations: 2,
+                },
+            };
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input, prover.fixed.clone(), &prime)
+                .unwrap()
+                .output_status;
+            (output_status, analyzer.counterexample)
+        };
+
+        // `a` (column 0) can change with `b` for the same sum.
+        let (output_status, counterexample) = analyze(0);
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+        let (model, other_model) = counterexample.unwrap();
+        assert!(model.result["A-0-0-0"]
+            .value
+            .element
+            .ne(&other_model.result["A-0-0-0"].value.element));
+
+        // The sum `x` (column 2) is copied to the public input, so it cannot differ.
+        let (output_status, counterexample) = analyze(2);
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        assert!(counterexample.is_none());
+    }
+
+    #[test]
+    fn analyze_underconstrained_with_witness_bounds_test() {
+        let circuit = sample_circuits::copy_constraint::free_sum::FreeSumCircuit::<Fr>::default();
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_witness_bound(0, 8)
+            .with_witness_bound(1, 8);
+
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 5,
+            },
+        };
+        let k: u32 = 5;
+
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![vec![Fr::from(5)]]).unwrap();
+
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+
+        let (model, other_model) = analyzer.counterexample.as_ref().unwrap();
+        for model in [model, other_model] {
+            for cell in ["A-0-0-0", "A-0-1-0"] {
+                let value: u64 = model.result[cell].value.element.parse().unwrap();
+                assert!(value.lt(&256));
+            }
+        }
+    }
+
+    #[test]
+    fn witness_bound_wider_than_128_bits_test() {
+        let circuit = sample_circuits::copy_constraint::free_sum::FreeSumCircuit::<Fr>::default();
+        let smt = Arc::new(Mutex::new(String::new()));
+        let mut analyzer = Analyzer::from(&circuit)
+            .with_witness_bound(0, 130)
+            .with_solver(RecordingSolver(smt.clone()));
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let analyzer_input = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 1,
+            },
+        };
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(5)]]).unwrap();
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Overconstrained));
+
+        // The weights of the last bits do not fit in a u128.
+        let smt = smt.lock().unwrap();
+        for bit in [127, 128, 129] {
+            let weight = BigInt::from(2).pow(bit);
+            assert!(smt.contains(&format!("(ff.mul (as ff{} F) A-0-0-0-bit-{})", weight, bit)));
+        }
+        assert!(!smt.contains("A-0-0-0-bit-130"));
+    }
+
+    #[test]
+    fn analyze_underconstrained_single_lookup_test() {
+        let circuit =
+            sample_circuits::lookup_circuits::lookup_underconstrained::MyCircuit::<Fr>(PhantomData);
+        let mut analyzer = Analyzer::from(&circuit);
+
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 5,
+            },
+        };
+        let k = 11;
+
+        let a = Fr::from(1);
+        let b = Fr::from(1);
+        let out = Fr::from(21);
+
+        let public_input = vec![a, b, out];
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn analyze_underconstrained_multiple_lookup_test() {
+        let circuit =
+            sample_circuits::lookup_circuits::multiple_lookups::MyCircuit::<Fr>(PhantomData);
+        let mut analyzer = Analyzer::from(&circuit);
+
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: instance_cols,
+                iterations: 5,
+            },
+        };
+
+        let k = 11;
+
+        let a = Fr::from(1);
+        let b = Fr::from(1);
+        let out = Fr::from(21);
+
+        let public_input = vec![a, b, out];
+        let prover: MockProver<Fr> = MockProver::run(k, &circuit, vec![public_input]).unwrap();
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input, prover.fixed, &prime)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+    #[test]
+    fn analyze_not_underconstrained_lookup_test() {
+        let circuit =
+            sample_circuits::lookup_circuits::multiple_lookups::MyCircuit::<Fr>(PhantomData);
+        let mut analyzer = Analyzer::from(&circuit);
+
+        let instance_cols = analyzer.extract_instance_cols(analyzer.layouter.eq_table.clone());
+        assert!(instance_cols.len().eq(&1));
+        let mut specified_instance_cols = BTreeMap::new();
+        specified_instance_cols.insert("A-0-2-7".to_owned(), 21);
+        specified_instance_cols.insert("I-0-1".to_owned(), 1);
+        specified_instance_cols.insert("I-0-0".to_owned(), 1);
+
+        let modulus = bn256::fr::MODULUS_STR;
+        let without_prefix = modulus.trim_start_matches("0x");
+        let prime = BigInt::from_str_radix(without_prefix, 16)
+            .unwrap()
+            .to_string();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
             },
         };
         let k = 11;
@@ -605,4 +3336,76 @@ mod tests {
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
     }
+
+    #[test]
+    fn selector_definitions_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        let mut analyzer = Analyzer::from(&circuit);
+
+        std::fs::create_dir_all("src/output/").unwrap();
+        let smt_file_path = "src/output/selector_definitions.smt2";
+        let mut smt_file = std::fs::File::create(smt_file_path).unwrap();
+        let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+        analyzer.encode_circuit(&mut printer, prover.fixed).unwrap();
+        assert!(printer.definitions.contains("S-0-0-0"));
+        assert!(!printer.vars.contains_key("S-0-0-0"));
+        smt::write_end(&mut printer);
+
+        // The selector of the three gates is defined once, then referenced by name.
+        let smt = std::fs::read_to_string(smt_file_path).unwrap();
+        assert!(smt
+            .matches("(define-fun S-0-0-0 () F (as ff1 F))")
+            .count()
+            .eq(&1));
+        let references = smt
+            .lines()
+            .filter(|line| line.starts_with("(assert") && line.contains("S-0-0-0"))
+            .count();
+        assert!(references.eq(&3));
+        assert!(smt_validator::validate(&smt).is_ok());
+    }
+
+    #[derive(Debug)]
+    struct ColumnRowNames;
+
+    impl NameFormatter for ColumnRowNames {
+        fn cell_name(&self, cell: &CellRef) -> String {
+            format!("c{}_r{}", cell.column, cell.row)
+        }
+    }
+
+    #[test]
+    fn name_formatter_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuit::<Fr>::default(
+            );
+        let k = 5;
+        let prover = MockProver::<Fr>::run(k, &circuit, vec![vec![Fr::from(3)]]).unwrap();
+        std::fs::create_dir_all("src/output/").unwrap();
+
+        let encode = |analyzer: &mut Analyzer<Fr>, path: &str| {
+            let mut smt_file = std::fs::File::create(path).unwrap();
+            let mut printer = smt::write_start(&mut smt_file, "11".to_owned());
+            analyzer
+                .encode_circuit(&mut printer, prover.fixed.clone())
+                .unwrap();
+            smt::write_end(&mut printer);
+            std::fs::read_to_string(path).unwrap()
+        };
+
+        let mut analyzer = Analyzer::from(&circuit).with_name_formatter(ColumnRowNames);
+        let smt = encode(&mut analyzer, "src/output/name_formatter_custom.smt2");
+        assert!(smt.contains("(declare-fun c0_r0 () F)"));
+        assert!(!smt.contains("A-0-0-0"));
+
+        // Names that are not simple symbols are quoted.
+        let mut analyzer = Analyzer::from(&circuit).with_name_formatter(RegionNameFormatter);
+        let smt = encode(&mut analyzer, "src/output/name_formatter_region.smt2");
+        assert!(smt.contains("(declare-fun |The Region.advice[0]@row0| () F)"));
+        assert!(smt_validator::validate(&smt).is_ok());
+    }
 }
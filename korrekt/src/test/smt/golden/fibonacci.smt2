@@ -0,0 +1,66 @@
+(set-info :smt-lib-version 2.6)
+(set-info :category "crafted")
+(set-option :produce-models true)
+(set-option :incremental true)
+(set-logic QF_FF)
+(define-sort F () (_ FiniteField 11))
+(define-fun S-0-0-0 () F (as ff1 F))
+(declare-fun A-0-0-0 () F)
+(declare-fun A-0-1-0 () F)
+(declare-fun A-0-2-0 () F)
+(assert ( = (ff.mul S-0-0-0 (ff.add (ff.add A-0-0-0 A-0-1-0) (ff.neg A-0-2-0))) (as ff0 F)))
+(define-fun S-1-0-0 () F (as ff1 F))
+(declare-fun A-1-0-0 () F)
+(declare-fun A-1-1-0 () F)
+(declare-fun A-1-2-0 () F)
+(assert ( = (ff.mul S-1-0-0 (ff.add (ff.add A-1-0-0 A-1-1-0) (ff.neg A-1-2-0))) (as ff0 F)))
+(define-fun S-2-0-0 () F (as ff1 F))
+(declare-fun A-2-0-0 () F)
+(declare-fun A-2-1-0 () F)
+(declare-fun A-2-2-0 () F)
+(assert ( = (ff.mul S-2-0-0 (ff.add (ff.add A-2-0-0 A-2-1-0) (ff.neg A-2-2-0))) (as ff0 F)))
+(define-fun S-3-0-0 () F (as ff1 F))
+(declare-fun A-3-0-0 () F)
+(declare-fun A-3-1-0 () F)
+(declare-fun A-3-2-0 () F)
+(assert ( = (ff.mul S-3-0-0 (ff.add (ff.add A-3-0-0 A-3-1-0) (ff.neg A-3-2-0))) (as ff0 F)))
+(define-fun S-4-0-0 () F (as ff1 F))
+(declare-fun A-4-0-0 () F)
+(declare-fun A-4-1-0 () F)
+(declare-fun A-4-2-0 () F)
+(assert ( = (ff.mul S-4-0-0 (ff.add (ff.add A-4-0-0 A-4-1-0) (ff.neg A-4-2-0))) (as ff0 F)))
+(define-fun S-5-0-0 () F (as ff1 F))
+(declare-fun A-5-0-0 () F)
+(declare-fun A-5-1-0 () F)
+(declare-fun A-5-2-0 () F)
+(assert ( = (ff.mul S-5-0-0 (ff.add (ff.add A-5-0-0 A-5-1-0) (ff.neg A-5-2-0))) (as ff0 F)))
+(define-fun S-6-0-0 () F (as ff1 F))
+(declare-fun A-6-0-0 () F)
+(declare-fun A-6-1-0 () F)
+(declare-fun A-6-2-0 () F)
+(assert ( = (ff.mul S-6-0-0 (ff.add (ff.add A-6-0-0 A-6-1-0) (ff.neg A-6-2-0))) (as ff0 F)))
+(define-fun S-7-0-0 () F (as ff1 F))
+(declare-fun A-7-0-0 () F)
+(declare-fun A-7-1-0 () F)
+(declare-fun A-7-2-0 () F)
+(assert ( = (ff.mul S-7-0-0 (ff.add (ff.add A-7-0-0 A-7-1-0) (ff.neg A-7-2-0))) (as ff0 F)))
+(assert ( = (ff.add A-1-0-0 (ff.neg A-0-1-0)) (as ff0 F)))
+(assert ( = (ff.add A-1-1-0 (ff.neg A-0-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-2-0-0 (ff.neg A-0-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-2-1-0 (ff.neg A-1-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-3-0-0 (ff.neg A-1-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-3-1-0 (ff.neg A-2-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-4-0-0 (ff.neg A-2-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-4-1-0 (ff.neg A-3-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-5-0-0 (ff.neg A-3-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-5-1-0 (ff.neg A-4-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-6-0-0 (ff.neg A-4-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-6-1-0 (ff.neg A-5-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-7-0-0 (ff.neg A-5-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-7-1-0 (ff.neg A-6-2-0)) (as ff0 F)))
+(declare-fun I-0-0 () F)
+(assert ( = (ff.add I-0-0 (ff.neg A-0-0-0)) (as ff0 F)))
+(declare-fun I-0-1 () F)
+(assert ( = (ff.add I-0-1 (ff.neg A-0-1-0)) (as ff0 F)))
+(declare-fun I-0-2 () F)
+(assert ( = (ff.add A-7-2-0 (ff.neg I-0-2)) (as ff0 F)))
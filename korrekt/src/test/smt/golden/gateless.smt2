@@ -0,0 +1,6 @@
+(set-info :smt-lib-version 2.6)
+(set-info :category "crafted")
+(set-option :produce-models true)
+(set-option :incremental true)
+(set-logic QF_FF)
+(define-sort F () (_ FiniteField 11))
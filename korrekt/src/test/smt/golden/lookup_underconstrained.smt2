@@ -0,0 +1,44 @@
+(set-info :smt-lib-version 2.6)
+(set-info :category "crafted")
+(set-option :produce-models true)
+(set-option :incremental true)
+(set-logic QF_FF)
+(define-sort F () (_ FiniteField 11))
+(define-fun S-0-0-0 () F (as ff1 F))
+(declare-fun A-0-0-0 () F)
+(declare-fun A-0-1-0 () F)
+(declare-fun A-0-2-0 () F)
+(assert ( = (ff.mul S-0-0-0 (ff.add (ff.add A-0-0-0 A-0-1-0) (ff.neg A-0-2-0))) (as ff0 F)))
+(define-fun S-0-0-1 () F (as ff0 F))
+(declare-fun A-0-0-1 () F)
+(declare-fun A-0-1-1 () F)
+(declare-fun A-0-2-1 () F)
+(assert ( = (ff.mul S-0-0-1 (ff.add (ff.add A-0-0-1 A-0-1-1) (ff.neg A-0-2-1))) (as ff0 F)))
+(define-fun S-0-0-2 () F (as ff1 F))
+(declare-fun A-0-0-2 () F)
+(declare-fun A-0-1-2 () F)
+(declare-fun A-0-2-2 () F)
+(assert ( = (ff.mul S-0-0-2 (ff.add (ff.add A-0-0-2 A-0-1-2) (ff.neg A-0-2-2))) (as ff0 F)))
+(define-fun S-0-0-3 () F (as ff0 F))
+(declare-fun A-0-0-3 () F)
+(declare-fun A-0-1-3 () F)
+(declare-fun A-0-2-3 () F)
+(assert ( = (ff.mul S-0-0-3 (ff.add (ff.add A-0-0-3 A-0-1-3) (ff.neg A-0-2-3))) (as ff0 F)))
+(define-fun S-0-1-0 () F (as ff0 F))
+(assert (or (and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff1 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff2 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff3 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff4 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff5 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff1 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff3 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff2 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff5 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff4 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff2 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff3 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff1 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff6 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff7 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff3 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff2 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff1 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff7 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff6 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff4 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff5 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff6 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff7 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff1 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff5 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff1 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff4 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff2 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff7 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff3 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff6 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff4 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff1 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff5 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))(and ( = (ff.mul S-0-1-0 A-0-0-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-1-0) (as ff0 F))( = (ff.mul S-0-1-0 A-0-2-0) (as ff0 F)))))
+(define-fun S-0-1-1 () F (as ff0 F))
+(assert (or (and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff1 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff2 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff3 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff4 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff5 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff1 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff3 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff2 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff5 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff4 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff2 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff3 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff1 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff6 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff7 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff3 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff2 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff1 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff7 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff6 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff4 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff5 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff6 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff7 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff1 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff5 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff1 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff4 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff2 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff7 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff3 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff6 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff4 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff1 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff5 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))(and ( = (ff.mul S-0-1-1 A-0-0-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-1-1) (as ff0 F))( = (ff.mul S-0-1-1 A-0-2-1) (as ff0 F)))))
+(define-fun S-0-1-2 () F (as ff0 F))
+(assert (or (and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff1 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff2 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff3 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff4 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff5 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff1 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff3 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff2 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff5 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff4 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff2 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff3 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff1 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff6 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff7 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff3 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff2 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff1 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff7 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff6 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff4 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff5 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff6 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff7 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff1 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff5 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff1 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff4 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff2 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff7 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff3 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff6 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff4 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff1 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff5 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))(and ( = (ff.mul S-0-1-2 A-0-0-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-1-2) (as ff0 F))( = (ff.mul S-0-1-2 A-0-2-2) (as ff0 F)))))
+(define-fun S-0-1-3 () F (as ff1 F))
+(assert (or (and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff1 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff2 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff3 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff4 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff5 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff1 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff3 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff2 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff5 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff4 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff2 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff3 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff1 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff6 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff7 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff3 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff2 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff1 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff7 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff6 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff4 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff5 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff6 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff7 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff1 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff5 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff1 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff4 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff2 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff7 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff3 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff6 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff4 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff1 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff5 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))(and ( = (ff.mul S-0-1-3 A-0-0-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-1-3) (as ff0 F))( = (ff.mul S-0-1-3 A-0-2-3) (as ff0 F)))))
+(assert ( = (ff.add A-0-0-2 (ff.neg A-0-1-0)) (as ff0 F)))
+(assert ( = (ff.add A-0-0-3 (ff.neg A-0-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-0-1-2 (ff.neg A-0-2-0)) (as ff0 F)))
+(assert ( = (ff.add A-0-1-3 (ff.neg A-0-2-2)) (as ff0 F)))
+(declare-fun I-0-0 () F)
+(assert ( = (ff.add I-0-0 (ff.neg A-0-0-0)) (as ff0 F)))
+(declare-fun I-0-1 () F)
+(assert ( = (ff.add I-0-1 (ff.neg A-0-1-0)) (as ff0 F)))
+(declare-fun I-0-2 () F)
+(assert ( = (ff.add A-0-2-3 (ff.neg I-0-2)) (as ff0 F)))
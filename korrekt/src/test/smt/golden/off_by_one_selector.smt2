@@ -0,0 +1,18 @@
+(set-info :smt-lib-version 2.6)
+(set-info :category "crafted")
+(set-option :produce-models true)
+(set-option :incremental true)
+(set-logic QF_FF)
+(define-sort F () (_ FiniteField 11))
+(define-fun S-0-0-0 () F (as ff1 F))
+(declare-fun A-0-1-0 () F)
+(declare-fun A-0-0--1 () F)
+(assert ( = (ff.mul S-0-0-0 (ff.add (ff.add A-0-1-0 (ff.neg A-0-0--1)) (ff.neg (as ff1 F)))) (as ff0 F)))
+(define-fun S-0-0-1 () F (as ff1 F))
+(declare-fun A-0-1-1 () F)
+(declare-fun A-0-0-0 () F)
+(assert ( = (ff.mul S-0-0-1 (ff.add (ff.add A-0-1-1 (ff.neg A-0-0-0)) (ff.neg (as ff1 F)))) (as ff0 F)))
+(define-fun S-0-0-2 () F (as ff0 F))
+(declare-fun A-0-1-2 () F)
+(declare-fun A-0-0-1 () F)
+(assert ( = (ff.mul S-0-0-2 (ff.add (ff.add A-0-1-2 (ff.neg A-0-0-1)) (ff.neg (as ff1 F)))) (as ff0 F)))
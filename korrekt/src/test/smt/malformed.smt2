@@ -0,0 +1,9 @@
+(set-info :smt-lib-version 2.6)
+(set-info :category "crafted")
+(set-option :produce-models true)
+(set-logic QF_FF)
+(define-sort F () (_ FiniteField 11))
+; an instance operand was emitted as an empty string
+(declare-fun A-0-0-0 () F)
+(assert (= (ff.add A-0-0-0 ) (as ff0 F)))
+(check-sat)
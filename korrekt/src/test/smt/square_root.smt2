@@ -0,0 +1,9 @@
+(set-info :smt-lib-version 2.6)
+(set-info :category "crafted")
+(set-option :produce-models true)
+(set-option :incremental true)
+(set-logic QF_FF)
+(define-sort F () (_ FiniteField 11))
+(declare-fun x () F)
+(assert (= (ff.mul x x) (as ff4 F)))
+(assert (not (= x (as ff2 F))))